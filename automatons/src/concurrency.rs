@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::{Automaton, Error, Product};
+
+type KeyLock = Arc<tokio::sync::Mutex<()>>;
+
+/// Executor that serializes automatons with the same concurrency key
+///
+/// Automatons that react to events for the same resource, e.g. two pushes to the same pull request,
+/// can interleave their writes when they run at the same time. Automatons can declare a
+/// [`concurrency_key`](Automaton::concurrency_key), and the [`ConcurrencyKeys`] execute automatons
+/// with the same key one after another, in the order in which they arrived. Automatons with
+/// different keys, or without a key, still run in parallel.
+///
+/// The executor only coordinates the automatons that it executes itself, so runtimes should share a
+/// single instance between all their workers.
+#[derive(Debug, Default)]
+pub struct ConcurrencyKeys {
+    locks: Mutex<HashMap<String, KeyLock>>,
+}
+
+impl ConcurrencyKeys {
+    /// Initializes an executor without any running automatons
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Executes the automaton once no other automaton with the same key is running.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, automaton)))]
+    pub async fn execute<A, P>(&self, automaton: &A) -> Result<P, Error>
+    where
+        A: Automaton<P> + Sync,
+        P: Product,
+    {
+        let key = match automaton.concurrency_key() {
+            Some(key) => key,
+            None => return automaton.execute().await,
+        };
+
+        // The reservation releases the key when it is dropped, even if the future is cancelled
+        // while it waits for the key or while the automaton is running.
+        let reservation = self.reserve(key);
+        let _guard = reservation.lock.lock().await;
+
+        automaton.execute().await
+    }
+
+    /// Returns the number of keys for which an automaton is running or waiting.
+    pub fn active_keys(&self) -> usize {
+        self.locks().len()
+    }
+
+    fn reserve(&self, key: String) -> Reservation<'_> {
+        let lock = self.locks().entry(key.clone()).or_default().clone();
+
+        Reservation {
+            keys: self,
+            key,
+            lock,
+        }
+    }
+
+    fn release(&self, key: &str, lock: &KeyLock) {
+        let mut locks = self.locks();
+
+        // The map holds one reference and this execution holds another. Any other reference
+        // belongs to an automaton that is still waiting for the key.
+        if Arc::strong_count(lock) <= 2 {
+            locks.remove(key);
+        }
+    }
+
+    fn locks(&self) -> std::sync::MutexGuard<'_, HashMap<String, KeyLock>> {
+        self.locks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Reference to the lock for a key, which releases the key when it is dropped
+struct Reservation<'a> {
+    keys: &'a ConcurrencyKeys,
+    key: String,
+    lock: KeyLock,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.keys.release(&self.key, &self.lock);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrencyKeys;
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ConcurrencyKeys>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ConcurrencyKeys>();
+    }
+}
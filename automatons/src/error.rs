@@ -26,20 +26,53 @@ use thiserror::Error;
 /// }
 /// ```
 ///
+/// # Matching
+///
+/// Automatons wrap the errors of their tasks in [`Error::Task`], and errors that are converted with
+/// `.context` end up in [`Error::Unknown`]. Use [`Error::inner`] to get to the original error before
+/// matching on its kind.
+///
+/// ```rust
+/// use anyhow::Context;
+/// use automatons::Error;
+///
+/// let error: Error = Err::<(), _>(Error::RateLimited("rate limit exceeded".into()))
+///     .context("failed to query repository")
+///     .unwrap_err()
+///     .into();
+///
+/// assert!(matches!(error.inner(), Error::RateLimited(_)));
+/// ```
+///
 /// anyhow: https://crates.io/crates/anyhow
 /// thiserror: https://crates.io/crates/thiserror
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("{0}")]
+    Cancelled(String),
+
     #[error("{0}")]
     Configuration(String),
 
+    #[error("{0}")]
+    Conflict(String),
+
     #[cfg(feature = "sqlx")]
     #[error("{0}")]
     Database(#[from] sqlx::Error),
 
+    #[error("{0}")]
+    DryRun(String),
+
+    #[error("GitHub responded with status {status}: {message}")]
+    GitHub { status: u16, message: String },
+
     #[error("failed to find resource at {0}")]
     NotFound(String),
 
+    #[error("{0}")]
+    RateLimited(String),
+
     #[cfg(feature = "reqwest")]
     #[error(transparent)]
     Request(#[from] reqwest::Error),
@@ -47,9 +80,77 @@ pub enum Error {
     #[error("{0}")]
     Serialization(String),
 
+    #[error("task {name} failed at step {step}: {source}")]
+    Task {
+        name: String,
+        step: usize,
+        source: Box<Error>,
+    },
+
+    #[error("{0}")]
+    Timeout(String),
+
+    #[error("{0}")]
+    Unauthorized(String),
+
     #[error("{0}")]
     UnsupportedEvent(String),
 
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
 }
+
+impl Error {
+    /// Returns the error that caused this error.
+    ///
+    /// Unwraps the errors of failed tasks, and errors of this type that were given context with
+    /// [`anyhow`]. Other errors are returned as they are.
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Task { source, .. } => source.inner(),
+            Error::Unknown(error) => match error.downcast_ref::<Error>() {
+                Some(error) => error.inner(),
+                None => self,
+            },
+            _ => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::Error;
+
+    #[test]
+    fn inner_unwraps_task_errors() {
+        let error = Error::Task {
+            name: "query repository".into(),
+            step: 1,
+            source: Box::new(Error::NotFound("/repos/devxbots/automatons".into())),
+        };
+
+        assert!(matches!(error.inner(), Error::NotFound(_)));
+    }
+
+    #[test]
+    fn inner_unwraps_context() {
+        let error: Error = Err::<(), _>(Error::GitHub {
+            status: 502,
+            message: "Bad Gateway".into(),
+        })
+        .context("failed to query repository")
+        .unwrap_err()
+        .into();
+
+        assert!(matches!(error.inner(), Error::GitHub { status: 502, .. }));
+    }
+
+    #[test]
+    fn inner_returns_unknown_errors() {
+        let error = Error::Unknown(anyhow::anyhow!("something went wrong"));
+
+        assert!(matches!(error.inner(), Error::Unknown(_)));
+    }
+}
@@ -0,0 +1,118 @@
+use std::future::Future;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::{Error, Task, Transition};
+
+type Function<Output> =
+    Box<dyn FnOnce() -> BoxFuture<'static, Result<Transition<Output>, Error>> + Send + Sync>;
+
+/// Task that executes an async closure
+///
+/// Integrations implement their requests as plain structs that are initialized with `new` and run
+/// with `execute`, so that they can be used without an automaton. The [`FnTask`] turns such a call
+/// into a step of an automaton: the closure takes ownership of the data that the request needs,
+/// executes it, and returns the transition to the next task.
+///
+/// # Example
+///
+/// ```rust
+/// use automatons::{Error, FnTask, Transition};
+///
+/// async fn count_repositories(owner: String) -> Result<usize, Error> {
+///     Ok(owner.len())
+/// }
+///
+/// fn count(owner: String) -> FnTask<usize> {
+///     FnTask::new("count repositories", move || async move {
+///         let count = count_repositories(owner).await?;
+///
+///         Ok(Transition::Complete(count))
+///     })
+/// }
+/// ```
+pub struct FnTask<Output> {
+    name: String,
+    function: Option<Function<Output>>,
+}
+
+impl<Output> FnTask<Output> {
+    /// Initializes a task that executes the closure once.
+    pub fn new<F, Fut>(name: &str, function: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Transition<Output>, Error>> + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            function: Some(Box::new(move || function().boxed())),
+        }
+    }
+}
+
+#[async_trait]
+impl<Output> Task<Output> for FnTask<Output>
+where
+    Output: Send + 'static,
+{
+    async fn execute(&mut self) -> Result<Transition<Output>, Error> {
+        let function = self.function.take().ok_or_else(|| {
+            Error::Configuration(format!("task {} was already executed", self.name))
+        })?;
+
+        function().await
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Task, Transition};
+
+    use super::FnTask;
+
+    #[tokio::test]
+    async fn execute_returns_transition() {
+        let value = 21;
+        let mut task = FnTask::new("double", move || async move {
+            Ok(Transition::Complete(value * 2))
+        });
+
+        let transition = task.execute().await.unwrap();
+
+        assert!(matches!(transition, Transition::Complete(42)));
+    }
+
+    #[tokio::test]
+    async fn execute_fails_when_executed_twice() {
+        let mut task = FnTask::new("noop", || async { Ok(Transition::Complete(())) });
+
+        task.execute().await.unwrap();
+
+        assert!(matches!(task.execute().await, Err(Error::Configuration(_))));
+    }
+
+    #[test]
+    fn name_returns_given_name() {
+        let task = FnTask::new("noop", || async { Ok(Transition::Complete(())) });
+
+        assert_eq!("noop", task.name());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<FnTask<u64>>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<FnTask<u64>>();
+    }
+}
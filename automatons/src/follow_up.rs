@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::time::{sleep_until, Instant};
+
+use crate::Error;
+
+/// Request to execute an automaton again after a delay
+///
+/// Automatons sometimes have to wait for something that happens outside of their control, e.g. a
+/// CI build that takes a few minutes to finish. Instead of blocking a worker, a task can schedule a
+/// [`FollowUp`] and complete. The follow-up carries the context that the automaton needs to pick up
+/// where it left off, e.g. the pull request and the commit that it was checking, and names the
+/// automaton that it re-enters.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FollowUp<Context> {
+    automaton: String,
+    context: Context,
+    delay: Duration,
+}
+
+impl<Context> FollowUp<Context> {
+    /// Initializes a follow-up for the automaton that is due immediately
+    pub fn new(automaton: &str, context: Context) -> Self {
+        Self {
+            automaton: automaton.into(),
+            context,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Sets the time to wait before the follow-up is due.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the name of the automaton that the follow-up re-enters.
+    pub fn automaton(&self) -> &str {
+        &self.automaton
+    }
+
+    /// Returns the context that the automaton passed to the follow-up.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Consumes the follow-up and returns its context.
+    pub fn into_context(self) -> Context {
+        self.context
+    }
+}
+
+/// Handle that tasks use to schedule follow-ups
+///
+/// The handle can be cloned cheaply and passed into automatons, e.g. next to the client for an
+/// integration. Follow-ups are delivered by the [`FollowUpReceiver`] that was created with the
+/// handle once their delay has passed.
+#[derive(Debug)]
+pub struct FollowUps<Context> {
+    sender: UnboundedSender<(Instant, FollowUp<Context>)>,
+}
+
+impl<Context> FollowUps<Context> {
+    /// Initializes a handle and the receiver that delivers its follow-ups
+    pub fn new() -> (Self, FollowUpReceiver<Context>) {
+        let (sender, receiver) = unbounded_channel();
+
+        let receiver = FollowUpReceiver {
+            receiver,
+            pending: Vec::new(),
+            closed: false,
+        };
+
+        (Self { sender }, receiver)
+    }
+
+    /// Schedules the follow-up.
+    ///
+    /// Returns [`Error::Cancelled`] if the receiver has been dropped, e.g. because the runtime is
+    /// shutting down.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn schedule(&self, follow_up: FollowUp<Context>) -> Result<(), Error> {
+        let due = Instant::now() + follow_up.delay;
+
+        self.sender
+            .send((due, follow_up))
+            .map_err(|_| Error::Cancelled("follow-up receiver has been dropped".into()))
+    }
+}
+
+impl<Context> Clone for FollowUps<Context> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// Receiver that delivers follow-ups once they are due
+///
+/// Runtimes receive follow-ups in the order in which they become due, and execute the automaton
+/// that they name with their context. Follow-ups are held in memory, so those that are still
+/// pending when the receiver is dropped are lost.
+#[derive(Debug)]
+pub struct FollowUpReceiver<Context> {
+    receiver: UnboundedReceiver<(Instant, FollowUp<Context>)>,
+    pending: Vec<(Instant, FollowUp<Context>)>,
+    closed: bool,
+}
+
+impl<Context> FollowUpReceiver<Context> {
+    /// Waits for the next follow-up that is due.
+    ///
+    /// Returns `None` once all [`FollowUps`] handles have been dropped and no follow-up is pending.
+    pub async fn recv(&mut self) -> Option<FollowUp<Context>> {
+        loop {
+            let next = self
+                .pending
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, (due, _))| *due)
+                .map(|(index, (due, _))| (index, *due));
+
+            match (next, self.closed) {
+                (Some((index, due)), true) => {
+                    sleep_until(due).await;
+                    return Some(self.pending.swap_remove(index).1);
+                }
+                (Some((index, due)), false) => {
+                    tokio::select! {
+                        _ = sleep_until(due) => return Some(self.pending.swap_remove(index).1),
+                        scheduled = self.receiver.recv() => self.push(scheduled),
+                    }
+                }
+                (None, true) => return None,
+                (None, false) => {
+                    let scheduled = self.receiver.recv().await;
+                    self.push(scheduled);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of follow-ups that have been received but are not due yet.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn push(&mut self, scheduled: Option<(Instant, FollowUp<Context>)>) {
+        match scheduled {
+            Some(scheduled) => self.pending.push(scheduled),
+            None => self.closed = true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::Error;
+
+    use super::{FollowUp, FollowUpReceiver, FollowUps};
+
+    #[tokio::test]
+    async fn recv_returns_follow_ups_in_order_of_due_time() {
+        let (follow_ups, mut receiver) = FollowUps::new();
+
+        follow_ups
+            .schedule(FollowUp::new("checks", "later").delay(Duration::from_millis(20)))
+            .unwrap();
+        follow_ups
+            .schedule(FollowUp::new("checks", "sooner").delay(Duration::from_millis(10)))
+            .unwrap();
+
+        assert_eq!("sooner", *receiver.recv().await.unwrap().context());
+        assert_eq!("later", *receiver.recv().await.unwrap().context());
+    }
+
+    #[tokio::test]
+    async fn recv_delivers_pending_follow_ups_after_handles_are_dropped() {
+        let (follow_ups, mut receiver) = FollowUps::new();
+
+        follow_ups
+            .schedule(FollowUp::new("checks", 42).delay(Duration::from_millis(10)))
+            .unwrap();
+        drop(follow_ups);
+
+        assert_eq!(42, receiver.recv().await.unwrap().into_context());
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[test]
+    fn schedule_fails_without_receiver() {
+        let (follow_ups, receiver) = FollowUps::new();
+        drop(receiver);
+
+        let error = follow_ups
+            .schedule(FollowUp::new("checks", ()))
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Cancelled(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<FollowUp<String>>();
+        assert_send::<FollowUps<String>>();
+        assert_send::<FollowUpReceiver<String>>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<FollowUp<String>>();
+        assert_sync::<FollowUps<String>>();
+        assert_sync::<FollowUpReceiver<String>>();
+    }
+}
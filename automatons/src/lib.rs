@@ -1,11 +1,23 @@
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
 
+pub use crate::concurrency::ConcurrencyKeys;
 pub use crate::error::Error;
+pub use crate::fn_task::FnTask;
+pub use crate::follow_up::{FollowUp, FollowUpReceiver, FollowUps};
+pub use crate::parallel::Parallel;
+pub use crate::report::{ExecutionReport, TaskOutcome, TaskReport};
 pub use crate::task::{Task, Transition};
 
+mod concurrency;
 mod error;
+mod fn_task;
+mod follow_up;
+mod parallel;
+mod report;
 mod task;
 
 /// Trait for the output of an automaton
@@ -30,12 +42,33 @@ pub trait Automaton<P: Product>: Debug {
     ///
     /// When a tasks returns `Transition::Complete` to end the execution of the automaton, a final
     /// task is executed. This can be useful to perform any teardown actions, for example to remove
-    /// resources that were created in a previous step. By default, a "noop" task is executed that
-    /// performs no action.
+    /// resources that were created in a previous step. By default, automatons have no complete
+    /// task.
     fn complete_task(&self) -> Option<Box<dyn Task<P>>> {
         None
     }
 
+    /// Returns the deadline for the execution of the automaton.
+    ///
+    /// The deadline limits the total time that the automaton can take to execute its tasks. If the
+    /// deadline is exceeded, the task that is currently executing is aborted, and the complete task
+    /// is executed to clean up like it is after a cancellation. The complete task is not limited by
+    /// the deadline, only by its own timeout. The automaton then returns [`Error::Timeout`]. By
+    /// default, automatons have no deadline.
+    fn deadline(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the key that serializes the execution of the automaton.
+    ///
+    /// Automatons that modify the same resource, e.g. a pull request, should not run at the same
+    /// time. Runtimes that execute automatons through [`ConcurrencyKeys`] run automatons with the
+    /// same key one after another, e.g. `checks:devxbots/automatons#42`. By default, automatons have
+    /// no key and can always run in parallel.
+    fn concurrency_key(&self) -> Option<String> {
+        None
+    }
+
     /// Executes the automaton.
     ///
     /// Automatons execute a series of tasks. When started, the automaton first initializes a new
@@ -45,53 +78,116 @@ pub trait Automaton<P: Product>: Debug {
     /// automaton shuts down.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     async fn execute(&self) -> Result<P, Error> {
-        let mut automaton_output;
-        let mut task = self.initial_task();
-
-        loop {
-            task = match task.execute().await? {
-                Transition::Next(task) => task,
-                Transition::Complete(output) => {
-                    automaton_output = output;
-                    break;
+        self.execute_with_cancellation(CancellationToken::new())
+            .await
+    }
+
+    /// Executes the automaton until it completes or is cancelled.
+    ///
+    /// Runtimes can use the cancellation token to abort an automaton that is in flight, for
+    /// example when they shut down or when a newer event supersedes the one that started the
+    /// automaton. When the token is cancelled, the task that is currently executing is aborted
+    /// and the task returned by the `complete_task` method is executed to clean up. The automaton
+    /// then returns [`Error::Cancelled`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    async fn execute_with_cancellation(&self, cancellation: CancellationToken) -> Result<P, Error> {
+        let (result, _report) = self.execute_with_report(cancellation).await;
+        result
+    }
+
+    /// Executes the automaton and reports on the tasks that it executed.
+    ///
+    /// The [`ExecutionReport`] records the name, duration, and outcome of every task that the
+    /// automaton executed, in order. Runtimes can use it to log what an automaton did, or to
+    /// surface it to users, e.g. in the output of a check run. The report is returned alongside
+    /// the result, so that it is available even when the execution failed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    async fn execute_with_report(
+        &self,
+        cancellation: CancellationToken,
+    ) -> (Result<P, Error>, ExecutionReport) {
+        let mut report = ExecutionReport::default();
+
+        let deadline = self.deadline();
+        let deadline_exceeded = async move {
+            match deadline {
+                Some(deadline) => {
+                    tokio::time::sleep(deadline).await;
+                    deadline
                 }
+                None => std::future::pending().await,
             }
-        }
+        };
+
+        let execution = async {
+            let mut automaton_output = tokio::select! {
+                output = run(self.initial_task(), &mut report) => Ok(output?),
+                _ = cancellation.cancelled() => {
+                    Err(Error::Cancelled("automaton was cancelled".into()))
+                }
+                deadline = deadline_exceeded => Err(Error::Timeout(format!(
+                    "automaton exceeded its deadline of {:?}",
+                    deadline
+                ))),
+            };
 
-        if let Some(mut complete_task) = self.complete_task() {
-            if let Transition::Complete(output) = complete_task.execute().await? {
-                automaton_output = output;
+            if let Some(mut complete_task) = self.complete_task() {
+                if let Transition::Complete(output) =
+                    execute_and_record(complete_task.as_mut(), &mut report).await?
+                {
+                    if automaton_output.is_ok() {
+                        automaton_output = Ok(output);
+                    }
+                }
             }
-        }
 
-        Ok(automaton_output)
+            automaton_output
+        };
+
+        let result = execution.await;
+
+        (result, report)
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
-struct NoopTask;
-
-#[async_trait]
-impl Task<()> for NoopTask {
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    async fn execute(&mut self) -> Result<Transition<()>, Error> {
-        Ok(Transition::Complete(()))
+/// Executes a chain of tasks until one of them completes.
+async fn run<Output>(
+    mut task: Box<dyn Task<Output>>,
+    report: &mut ExecutionReport,
+) -> Result<Output, Error> {
+    loop {
+        task = match execute_and_record(task.as_mut(), report).await? {
+            Transition::Next(task) => task,
+            Transition::Complete(output) => return Ok(output),
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::NoopTask;
+/// Executes a single task and records its outcome in the report.
+///
+/// Errors are wrapped with the name of the task and its step in the automaton, starting at 1.
+async fn execute_and_record<Output>(
+    task: &mut dyn Task<Output>,
+    report: &mut ExecutionReport,
+) -> Result<Transition<Output>, Error> {
+    let started_at = Instant::now();
+    let result = execute_task(task).await;
 
-    #[test]
-    fn trait_send() {
-        fn assert_send<T: Send>() {}
-        assert_send::<NoopTask>();
-    }
+    report.record(TaskReport::new(task.name(), started_at.elapsed(), &result));
+
+    result.map_err(|error| Error::Task {
+        name: task.name().into(),
+        step: report.tasks().len(),
+        source: Box::new(error),
+    })
+}
 
-    #[test]
-    fn trait_sync() {
-        fn assert_sync<T: Sync>() {}
-        assert_sync::<NoopTask>();
+/// Executes a single task within its timeout.
+async fn execute_task<Output>(task: &mut dyn Task<Output>) -> Result<Transition<Output>, Error> {
+    match task.timeout() {
+        Some(timeout) => tokio::time::timeout(timeout, task.execute())
+            .await
+            .map_err(|_| Error::Timeout(format!("task exceeded its timeout of {:?}", timeout)))?,
+        None => task.execute().await,
     }
 }
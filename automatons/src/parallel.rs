@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use futures::future::try_join_all;
+
+use crate::{run, Error, ExecutionReport, Task, Transition};
+
+type Join<Branch, Output> =
+    Box<dyn FnOnce(Vec<Branch>) -> Result<Transition<Output>, Error> + Send + Sync>;
+
+/// Task that executes several branches concurrently
+///
+/// Automatons execute their tasks one after another. When an automaton needs to perform several
+/// independent units of work, for example listing the check suites of multiple repositories, these
+/// can be executed as concurrent branches. Each branch is a chain of tasks that runs until one of
+/// its tasks returns `Transition::Complete`. Once all branches have completed, their outputs are
+/// passed in order to the join function, which returns the transition to the next task.
+///
+/// If any branch fails, the remaining branches are dropped and the error is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use automatons::{Error, Parallel, Task, Transition};
+///
+/// fn fork(branches: Vec<Box<dyn Task<u64>>>) -> Parallel<u64, u64> {
+///     Parallel::new(branches, |outputs| Ok(Transition::Complete(outputs.iter().sum())))
+/// }
+/// ```
+pub struct Parallel<Branch, Output> {
+    branches: Vec<Box<dyn Task<Branch>>>,
+    join: Option<Join<Branch, Output>>,
+}
+
+impl<Branch, Output> Parallel<Branch, Output> {
+    /// Initializes a task that executes the given branches concurrently and then joins them.
+    pub fn new<F>(branches: Vec<Box<dyn Task<Branch>>>, join: F) -> Self
+    where
+        F: FnOnce(Vec<Branch>) -> Result<Transition<Output>, Error> + Send + Sync + 'static,
+    {
+        Self {
+            branches,
+            join: Some(Box::new(join)),
+        }
+    }
+}
+
+#[async_trait]
+impl<Branch, Output> Task<Output> for Parallel<Branch, Output>
+where
+    Branch: Send + 'static,
+    Output: Send + 'static,
+{
+    async fn execute(&mut self) -> Result<Transition<Output>, Error> {
+        let join = self
+            .join
+            .take()
+            .ok_or_else(|| Error::Configuration("parallel task was already executed".into()))?;
+
+        let branches = std::mem::take(&mut self.branches);
+        // The branches are reported as part of the parallel task, not as individual tasks.
+        let outputs = try_join_all(
+            branches
+                .into_iter()
+                .map(|branch| async move { run(branch, &mut ExecutionReport::default()).await }),
+        )
+        .await?;
+
+        join(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use crate::{Error, Task, Transition};
+
+    use super::Parallel;
+
+    struct Double(u64);
+
+    #[async_trait]
+    impl Task<u64> for Double {
+        async fn execute(&mut self) -> Result<Transition<u64>, Error> {
+            Ok(Transition::Complete(self.0 * 2))
+        }
+    }
+
+    struct Fail;
+
+    #[async_trait]
+    impl Task<u64> for Fail {
+        async fn execute(&mut self) -> Result<Transition<u64>, Error> {
+            Err(Error::NotFound("branch".into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_joins_outputs_in_order() {
+        let branches: Vec<Box<dyn Task<u64>>> = vec![Box::new(Double(1)), Box::new(Double(2))];
+        let mut parallel = Parallel::new(branches, |outputs| Ok(Transition::Complete(outputs)));
+
+        let transition = parallel.execute().await.unwrap();
+
+        assert!(matches!(transition, Transition::Complete(outputs) if outputs == vec![2, 4]));
+    }
+
+    #[tokio::test]
+    async fn execute_fails_when_a_branch_fails() {
+        let branches: Vec<Box<dyn Task<u64>>> = vec![Box::new(Double(1)), Box::new(Fail)];
+        let mut parallel = Parallel::new(branches, |outputs| Ok(Transition::Complete(outputs)));
+
+        assert!(matches!(
+            parallel.execute().await,
+            Err(Error::Task { source, .. }) if matches!(*source, Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Parallel<u64, u64>>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Parallel<u64, u64>>();
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use crate::{Error, Transition};
+
+/// Outcome of a task
+///
+/// Tasks either transition to the next task, complete the automaton, or fail with an error. Since
+/// errors cannot be cloned, the report keeps the error's message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TaskOutcome {
+    /// The task transitioned to the next task.
+    Next,
+
+    /// The task completed the automaton.
+    Complete,
+
+    /// The task failed with the given error.
+    Failed(String),
+}
+
+/// Report of a single task
+///
+/// The report records the name of a task, how long it took to execute, and its outcome.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TaskReport {
+    name: String,
+    duration: Duration,
+    outcome: TaskOutcome,
+}
+
+/// Report of an automaton's execution
+///
+/// The execution report lists every task that an automaton executed, in the order in which they
+/// were executed. Tasks that were aborted, e.g. because the automaton was cancelled, are not part
+/// of the report.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct ExecutionReport {
+    tasks: Vec<TaskReport>,
+}
+
+impl TaskReport {
+    pub(crate) fn new<Output>(
+        name: &str,
+        duration: Duration,
+        result: &Result<Transition<Output>, Error>,
+    ) -> Self {
+        let outcome = match result {
+            Ok(Transition::Next(_)) => TaskOutcome::Next,
+            Ok(Transition::Complete(_)) => TaskOutcome::Complete,
+            Err(error) => TaskOutcome::Failed(error.to_string()),
+        };
+
+        Self {
+            name: name.into(),
+            duration,
+            outcome,
+        }
+    }
+
+    /// Returns the task's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the duration of the task's execution.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the task's outcome.
+    pub fn outcome(&self) -> &TaskOutcome {
+        &self.outcome
+    }
+}
+
+impl ExecutionReport {
+    pub(crate) fn record(&mut self, task: TaskReport) {
+        self.tasks.push(task);
+    }
+
+    /// Returns the reports of the executed tasks.
+    pub fn tasks(&self) -> &Vec<TaskReport> {
+        &self.tasks
+    }
+
+    /// Returns the total duration of the executed tasks.
+    pub fn duration(&self) -> Duration {
+        self.tasks.iter().map(TaskReport::duration).sum()
+    }
+}
+
+impl Display for TaskOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskOutcome::Next => write!(f, "next"),
+            TaskOutcome::Complete => write!(f, "complete"),
+            TaskOutcome::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+impl Display for TaskReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:?}) {}", self.name, self.duration, self.outcome)
+    }
+}
+
+impl Display for ExecutionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, task) in self.tasks.iter().enumerate() {
+            writeln!(f, "{}. {}", index + 1, task)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{Error, Transition};
+
+    use super::{ExecutionReport, TaskOutcome, TaskReport};
+
+    #[test]
+    fn task_report_records_failure() {
+        let result: Result<Transition<()>, Error> = Err(Error::NotFound("/".into()));
+
+        let report = TaskReport::new("task", Duration::from_secs(1), &result);
+
+        assert_eq!(
+            &TaskOutcome::Failed("failed to find resource at /".into()),
+            report.outcome()
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        let mut report = ExecutionReport::default();
+        report.record(TaskReport::new(
+            "task",
+            Duration::from_secs(1),
+            &Ok(Transition::Complete(())),
+        ));
+
+        assert_eq!("1. task (1s) complete\n", report.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ExecutionReport>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ExecutionReport>();
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 
 use crate::Error;
@@ -17,6 +19,39 @@ pub enum Transition<Output> {
     Complete(Output),
 }
 
+impl<Output> Transition<Output> {
+    /// Transition to one of two tasks depending on a condition.
+    ///
+    /// Branching keeps conditional logic out of the tasks that perform the work. A task can check
+    /// a condition, e.g. whether a check run failed, and then branch into either a remediation
+    /// task or a task that skips the remediation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use automatons::{Task, Transition};
+    ///
+    /// fn remediate_if_failed(
+    ///     failed: bool,
+    ///     remediate: Box<dyn Task<()>>,
+    ///     skip: Box<dyn Task<()>>,
+    /// ) -> Transition<()> {
+    ///     Transition::branch(failed, remediate, skip)
+    /// }
+    /// ```
+    pub fn branch(
+        condition: bool,
+        if_true: Box<dyn Task<Output>>,
+        if_false: Box<dyn Task<Output>>,
+    ) -> Self {
+        if condition {
+            Transition::Next(if_true)
+        } else {
+            Transition::Next(if_false)
+        }
+    }
+}
+
 /// Executable task
 ///
 /// Automatons execute a series of tasks. Each task should only perform a single, logical step and
@@ -35,4 +70,22 @@ pub trait Task<Output>: Send + Sync {
     /// [`Transition`], which tells the engine whether to continue, handle an unexpected failure, or
     /// return early since there is no more work to be done.
     async fn execute(&mut self) -> Result<Transition<Output>, Error>;
+
+    /// Returns the name of the task.
+    ///
+    /// The name identifies the task in the [`ExecutionReport`](crate::ExecutionReport) of an
+    /// automaton, and in the [`Error::Task`] that wraps the errors that the task returns. By
+    /// default, the name of the task's type is used.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the maximum duration of the task's execution.
+    ///
+    /// Tasks that interact with external resources can hang, for example when an API does not
+    /// respond. If a timeout is set, the engine aborts the task when it exceeds the timeout and
+    /// returns [`Error::Timeout`]. By default, tasks have no timeout.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
 }
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+
+use automatons::{Automaton, Error, Product, Task, Transition};
+
+#[tokio::test]
+async fn remediates_failed_check() -> Result<(), Error> {
+    let automaton = CheckAndRemediate { failed: true };
+    let outcome = automaton.execute().await?;
+
+    assert_eq!("remediated", outcome.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn skips_remediation_of_successful_check() -> Result<(), Error> {
+    let automaton = CheckAndRemediate { failed: false };
+    let outcome = automaton.execute().await?;
+
+    assert_eq!("skipped", outcome.0);
+
+    Ok(())
+}
+
+// Product
+struct Outcome(&'static str);
+impl Product for Outcome {}
+
+// Automaton
+#[derive(Debug)]
+struct CheckAndRemediate {
+    failed: bool,
+}
+
+// Task
+struct Check {
+    failed: bool,
+}
+
+// Task
+struct Remediate;
+
+// Task
+struct Skip;
+
+impl Automaton<Outcome> for CheckAndRemediate {
+    fn initial_task(&self) -> Box<dyn Task<Outcome>> {
+        Box::new(Check {
+            failed: self.failed,
+        })
+    }
+}
+
+#[async_trait]
+impl Task<Outcome> for Check {
+    async fn execute(&mut self) -> Result<Transition<Outcome>, Error> {
+        Ok(Transition::branch(
+            self.failed,
+            Box::new(Remediate),
+            Box::new(Skip),
+        ))
+    }
+}
+
+#[async_trait]
+impl Task<Outcome> for Remediate {
+    async fn execute(&mut self) -> Result<Transition<Outcome>, Error> {
+        Ok(Transition::Complete(Outcome("remediated")))
+    }
+}
+
+#[async_trait]
+impl Task<Outcome> for Skip {
+    async fn execute(&mut self) -> Result<Transition<Outcome>, Error> {
+        Ok(Transition::Complete(Outcome("skipped")))
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use automatons::{Automaton, Error, Product, Task, Transition};
+
+#[tokio::test]
+async fn cancellation_runs_complete_task() {
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    let automaton = Hanging {
+        cleaned_up: cleaned_up.clone(),
+    };
+
+    let cancellation = CancellationToken::new();
+    let trigger = cancellation.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        trigger.cancel();
+    });
+
+    let error = automaton
+        .execute_with_cancellation(cancellation)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Cancelled(_)));
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+// Product
+#[derive(Debug)]
+struct Nothing;
+impl Product for Nothing {}
+
+// Automaton
+#[derive(Debug)]
+struct Hanging {
+    cleaned_up: Arc<AtomicBool>,
+}
+
+// Task
+struct Hang;
+
+// Task
+struct CleanUp {
+    cleaned_up: Arc<AtomicBool>,
+}
+
+impl Automaton<Nothing> for Hanging {
+    fn initial_task(&self) -> Box<dyn Task<Nothing>> {
+        Box::new(Hang)
+    }
+
+    fn complete_task(&self) -> Option<Box<dyn Task<Nothing>>> {
+        Some(Box::new(CleanUp {
+            cleaned_up: self.cleaned_up.clone(),
+        }))
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for Hang {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        std::future::pending::<()>().await;
+        Ok(Transition::Complete(Nothing))
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for CleanUp {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        self.cleaned_up.store(true, Ordering::SeqCst);
+        Ok(Transition::Complete(Nothing))
+    }
+}
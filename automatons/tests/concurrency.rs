@@ -0,0 +1,150 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use automatons::{Automaton, ConcurrencyKeys, Error, Product, Task, Transition};
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+#[tokio::test]
+async fn serializes_automatons_with_same_key() -> Result<(), Error> {
+    let keys = ConcurrencyKeys::new();
+    let log = Log::default();
+
+    let first = Writer::new("first", Some("pull-request#1"), &log);
+    let second = Writer::new("second", Some("pull-request#1"), &log);
+
+    let (first, second) = tokio::join!(keys.execute(&first), keys.execute(&second));
+    first?;
+    second?;
+
+    assert_eq!(
+        vec![
+            "first started",
+            "first done",
+            "second started",
+            "second done"
+        ],
+        *log.lock().unwrap()
+    );
+    assert_eq!(0, keys.active_keys());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn runs_automatons_with_different_keys_in_parallel() -> Result<(), Error> {
+    let keys = ConcurrencyKeys::new();
+    let log = Log::default();
+
+    let first = Writer::new("first", Some("pull-request#1"), &log);
+    let second = Writer::new("second", Some("pull-request#2"), &log);
+
+    let (first, second) = tokio::join!(keys.execute(&first), keys.execute(&second));
+    first?;
+    second?;
+
+    assert_eq!(
+        vec!["first started", "second started"],
+        log.lock().unwrap()[..2]
+    );
+    assert_eq!(0, keys.active_keys());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn releases_key_of_cancelled_automatons() -> Result<(), Error> {
+    let keys = ConcurrencyKeys::new();
+    let log = Log::default();
+
+    let first = Writer::new("first", Some("pull-request#1"), &log);
+    let second = Writer::new("second", Some("pull-request#1"), &log);
+
+    let (running, waiting) = tokio::join!(
+        tokio::time::timeout(Duration::from_millis(5), keys.execute(&first)),
+        tokio::time::timeout(Duration::from_millis(5), keys.execute(&second)),
+    );
+    assert!(running.is_err() && waiting.is_err());
+    assert_eq!(0, keys.active_keys());
+
+    keys.execute(&first).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn runs_automatons_without_key_in_parallel() -> Result<(), Error> {
+    let keys = ConcurrencyKeys::new();
+    let log = Log::default();
+
+    let first = Writer::new("first", None, &log);
+    let second = Writer::new("second", None, &log);
+
+    let (first, second) = tokio::join!(keys.execute(&first), keys.execute(&second));
+    first?;
+    second?;
+
+    assert_eq!("second started", log.lock().unwrap()[1]);
+
+    Ok(())
+}
+
+// Product
+#[derive(Debug)]
+struct Nothing;
+impl Product for Nothing {}
+
+// Automaton
+#[derive(Debug)]
+struct Writer {
+    name: String,
+    key: Option<String>,
+    log: Log,
+}
+
+impl Writer {
+    fn new(name: &str, key: Option<&str>, log: &Log) -> Self {
+        Self {
+            name: name.into(),
+            key: key.map(String::from),
+            log: log.clone(),
+        }
+    }
+}
+
+impl Automaton<Nothing> for Writer {
+    fn initial_task(&self) -> Box<dyn Task<Nothing>> {
+        Box::new(Write {
+            name: self.name.clone(),
+            log: self.log.clone(),
+        })
+    }
+
+    fn concurrency_key(&self) -> Option<String> {
+        self.key.clone()
+    }
+}
+
+// Tasks
+struct Write {
+    name: String,
+    log: Log,
+}
+
+#[async_trait]
+impl Task<Nothing> for Write {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} started", self.name));
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        self.log.lock().unwrap().push(format!("{} done", self.name));
+
+        Ok(Transition::Complete(Nothing))
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use automatons::{Automaton, Error, FollowUp, FollowUps, Product, Task, Transition};
+
+#[tokio::test]
+async fn follow_up_reenters_automaton_with_context() -> Result<(), Error> {
+    let (follow_ups, mut receiver) = FollowUps::new();
+
+    let mut automaton = WaitForBuild {
+        attempt: 1,
+        follow_ups: follow_ups.clone(),
+    };
+    drop(follow_ups);
+
+    let mut outcomes = vec![automaton.execute().await?];
+
+    while let Some(follow_up) = receiver.recv().await {
+        assert_eq!("wait-for-build", follow_up.automaton());
+
+        automaton = WaitForBuild {
+            attempt: follow_up.into_context(),
+            follow_ups: automaton.follow_ups.clone(),
+        };
+
+        outcomes.push(automaton.execute().await?);
+
+        if matches!(outcomes.last(), Some(Build::Finished)) {
+            break;
+        }
+    }
+
+    assert_eq!(
+        vec![Build::Running, Build::Running, Build::Finished],
+        outcomes
+    );
+    assert_eq!(0, receiver.pending());
+
+    Ok(())
+}
+
+// Product
+#[derive(Eq, PartialEq, Debug)]
+enum Build {
+    Running,
+    Finished,
+}
+
+impl Product for Build {}
+
+// Automaton
+#[derive(Debug)]
+struct WaitForBuild {
+    attempt: u32,
+    follow_ups: FollowUps<u32>,
+}
+
+impl Automaton<Build> for WaitForBuild {
+    fn initial_task(&self) -> Box<dyn Task<Build>> {
+        Box::new(CheckBuild {
+            attempt: self.attempt,
+            follow_ups: self.follow_ups.clone(),
+        })
+    }
+}
+
+// Tasks
+struct CheckBuild {
+    attempt: u32,
+    follow_ups: FollowUps<u32>,
+}
+
+#[async_trait]
+impl Task<Build> for CheckBuild {
+    async fn execute(&mut self) -> Result<Transition<Build>, Error> {
+        if self.attempt == 3 {
+            return Ok(Transition::Complete(Build::Finished));
+        }
+
+        self.follow_ups.schedule(
+            FollowUp::new("wait-for-build", self.attempt + 1).delay(Duration::from_millis(10)),
+        )?;
+
+        Ok(Transition::Complete(Build::Running))
+    }
+}
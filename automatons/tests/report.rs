@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use automatons::{Automaton, Error, Product, Task, TaskOutcome, Transition};
+
+#[tokio::test]
+async fn report_lists_executed_tasks() {
+    let automaton = Reported;
+
+    let (result, report) = automaton
+        .execute_with_report(CancellationToken::new())
+        .await;
+
+    assert!(result.is_ok());
+
+    let outcomes: Vec<&TaskOutcome> = report.tasks().iter().map(|task| task.outcome()).collect();
+    assert_eq!(vec![&TaskOutcome::Next, &TaskOutcome::Complete], outcomes);
+    assert!(report.tasks()[0].name().ends_with("First"));
+}
+
+// Product
+#[derive(Debug)]
+struct Nothing;
+impl Product for Nothing {}
+
+// Automaton
+#[derive(Debug)]
+struct Reported;
+
+// Task
+struct First;
+
+// Task
+struct Second;
+
+impl Automaton<Nothing> for Reported {
+    fn initial_task(&self) -> Box<dyn Task<Nothing>> {
+        Box::new(First)
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for First {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        Ok(Transition::Next(Box::new(Second)))
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for Second {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        Ok(Transition::Complete(Nothing))
+    }
+}
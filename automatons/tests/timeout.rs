@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use automatons::{Automaton, Error, Product, Task, Transition};
+
+#[tokio::test]
+async fn task_timeout() {
+    let automaton = Sleepy {
+        task_timeout: Some(Duration::from_millis(10)),
+        deadline: None,
+        cleaned_up: Arc::default(),
+    };
+
+    let error = automaton.execute().await.unwrap_err();
+
+    match error {
+        Error::Task { step, source, .. } => {
+            assert_eq!(1, step);
+            assert!(matches!(*source, Error::Timeout(_)));
+        }
+        _ => panic!("expected the task's error to be wrapped"),
+    }
+}
+
+#[tokio::test]
+async fn automaton_deadline() {
+    let automaton = Sleepy {
+        task_timeout: None,
+        deadline: Some(Duration::from_millis(10)),
+        cleaned_up: Arc::default(),
+    };
+
+    let error = automaton.execute().await.unwrap_err();
+
+    assert!(matches!(error, Error::Timeout(_)));
+}
+
+#[tokio::test]
+async fn automaton_deadline_runs_complete_task() {
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    let automaton = Sleepy {
+        task_timeout: None,
+        deadline: Some(Duration::from_millis(10)),
+        cleaned_up: cleaned_up.clone(),
+    };
+
+    let error = automaton.execute().await.unwrap_err();
+
+    assert!(matches!(error, Error::Timeout(_)));
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn completes_within_timeout() -> Result<(), Error> {
+    let automaton = Sleepy {
+        task_timeout: Some(Duration::from_secs(10)),
+        deadline: Some(Duration::from_secs(10)),
+        cleaned_up: Arc::default(),
+    };
+
+    automaton.execute().await?;
+
+    Ok(())
+}
+
+// Product
+#[derive(Debug)]
+struct Nothing;
+impl Product for Nothing {}
+
+// Automaton
+#[derive(Debug)]
+struct Sleepy {
+    task_timeout: Option<Duration>,
+    deadline: Option<Duration>,
+    cleaned_up: Arc<AtomicBool>,
+}
+
+// Task
+struct Sleep {
+    timeout: Option<Duration>,
+}
+
+// Task
+struct CleanUp {
+    cleaned_up: Arc<AtomicBool>,
+}
+
+impl Automaton<Nothing> for Sleepy {
+    fn initial_task(&self) -> Box<dyn Task<Nothing>> {
+        Box::new(Sleep {
+            timeout: self.task_timeout,
+        })
+    }
+
+    fn complete_task(&self) -> Option<Box<dyn Task<Nothing>>> {
+        Some(Box::new(CleanUp {
+            cleaned_up: self.cleaned_up.clone(),
+        }))
+    }
+
+    fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for Sleep {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        Ok(Transition::Complete(Nothing))
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[async_trait]
+impl Task<Nothing> for CleanUp {
+    async fn execute(&mut self) -> Result<Transition<Nothing>, Error> {
+        self.cleaned_up.store(true, Ordering::SeqCst);
+        Ok(Transition::Complete(Nothing))
+    }
+}
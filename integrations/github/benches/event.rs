@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use automatons_github::event::{deserialize_event, GitHubEventKind};
+
+fn bench_event(c: &mut Criterion, kind: GitHubEventKind, payload: &[u8]) {
+    c.bench_function(&format!("deserialize {} event", kind), |b| {
+        b.iter(|| deserialize_event(black_box(&kind), black_box(payload)).unwrap())
+    });
+}
+
+fn deserialize_events(c: &mut Criterion) {
+    bench_event(
+        c,
+        GitHubEventKind::CheckRun,
+        include_bytes!("../tests/fixtures/event/check_run.completed.json"),
+    );
+    bench_event(
+        c,
+        GitHubEventKind::DiscussionComment,
+        include_bytes!("../tests/fixtures/event/discussion_comment.created.json"),
+    );
+    // Unsupported events are deserialized into a raw JSON value.
+    bench_event(
+        c,
+        GitHubEventKind::Push,
+        include_bytes!("../tests/fixtures/event/check_run.completed.json"),
+    );
+}
+
+criterion_group!(benches, deserialize_events);
+criterion_main!(benches);
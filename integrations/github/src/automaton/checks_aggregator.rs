@@ -0,0 +1,537 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+
+use automatons::{Automaton, Error, Product, Task, Transition};
+
+use crate::client::GitHubClient;
+use crate::event::{CheckRunAction, CheckRunEvent};
+use crate::resource::{
+    AppId, CheckRun, CheckRunConclusion, CheckRunId, CheckRunName, CheckRunStatus, GitSha, Login,
+    RepositoryName, RepositoryScoped,
+};
+use crate::task::{
+    CheckRunOutputBuilder, CreateCheckRun, CreateCheckRunArgs, ListCheckRunsForGitSha, Markdown,
+    UpdateCheckRun, UpdateCheckRunArgs,
+};
+
+/// Default name of the summary check run
+const DEFAULT_NAME: &str = "Checks";
+
+/// Aggregator for check runs
+///
+/// The [`ChecksAggregator`] reacts to `check_run.completed` events. It lists all check runs for
+/// the commit of the completed check run, and publishes a summary check run that reports how many
+/// of them succeeded, with links to each check run. Check runs that were re-run are only counted
+/// once, with their latest result.
+///
+/// The summary check run is created for the first event, and updated for every later event for the
+/// same commit.
+///
+/// The summary check run is completed once all other check runs have completed. Events for the
+/// summary check run itself are skipped, so that the automaton does not trigger itself. The summary
+/// check run is recognized by its name and by the GitHub App that the client authenticates as, so
+/// that check runs of other apps with the same name are still counted.
+#[derive(Clone, Debug)]
+pub struct ChecksAggregator {
+    github_client: GitHubClient,
+    event: CheckRunEvent,
+    name: CheckRunName,
+}
+
+/// Summary of the check runs for a commit
+///
+/// The [`ChecksSummary`] is the product of the [`ChecksAggregator`]. It contains the summary check
+/// run that was published, or `None` if the event was skipped.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ChecksSummary {
+    total: usize,
+    completed: usize,
+    succeeded: usize,
+    check_run: Option<CheckRun>,
+}
+
+impl ChecksAggregator {
+    /// Initializes the automaton for a check run event
+    pub fn new(github_client: GitHubClient, event: CheckRunEvent) -> Self {
+        Self {
+            github_client,
+            event,
+            name: CheckRunName::new(DEFAULT_NAME),
+        }
+    }
+
+    /// Sets the name of the summary check run.
+    pub fn name(mut self, name: CheckRunName) -> Self {
+        self.name = name;
+        self
+    }
+}
+
+impl Automaton<ChecksSummary> for ChecksAggregator {
+    fn initial_task(&self) -> Box<dyn Task<ChecksSummary>> {
+        Box::new(ListChecks {
+            github_client: self.github_client.clone(),
+            owner: self.event.owner_login().clone(),
+            repository: self.event.repository_name().clone(),
+            head_sha: self.event.check_run().head_sha().clone(),
+            action: self.event.action(),
+            trigger: self.event.check_run().clone(),
+            summary: Summary {
+                name: self.name.clone(),
+                app_id: self.github_client.app_id(),
+            },
+        })
+    }
+}
+
+impl ChecksSummary {
+    /// Returns the number of check runs for the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of check runs that have completed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+
+    /// Returns the number of check runs that have succeeded.
+    ///
+    /// Check runs that finished as neutral or were skipped count as succeeded.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn succeeded(&self) -> usize {
+        self.succeeded
+    }
+
+    /// Returns the summary check run, or `None` if the event was skipped.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn check_run(&self) -> &Option<CheckRun> {
+        &self.check_run
+    }
+
+    fn new(check_runs: &[CheckRun]) -> Self {
+        let completed = check_runs
+            .iter()
+            .filter(|check_run| check_run.status().is_terminal())
+            .count();
+
+        let succeeded = check_runs
+            .iter()
+            .filter(|check_run| {
+                check_run
+                    .conclusion()
+                    .is_some_and(|conclusion| !conclusion.is_failure())
+            })
+            .count();
+
+        Self {
+            total: check_runs.len(),
+            completed,
+            succeeded,
+            check_run: None,
+        }
+    }
+}
+
+impl Product for ChecksSummary {}
+
+struct ListChecks {
+    github_client: GitHubClient,
+    owner: Login,
+    repository: RepositoryName,
+    head_sha: GitSha,
+    action: CheckRunAction,
+    trigger: CheckRun,
+    summary: Summary,
+}
+
+/// Identity of the summary check run
+///
+/// Check runs are only unique by name within the same app, so the summary check run is identified
+/// by its name and by the app that created it. Clients that authenticate with an access token do
+/// not know their app, and fall back to the name.
+#[derive(Clone, Debug)]
+struct Summary {
+    name: CheckRunName,
+    app_id: Option<AppId>,
+}
+
+impl Summary {
+    fn matches(&self, check_run: &CheckRun) -> bool {
+        check_run.name() == &self.name
+            && self
+                .app_id
+                .is_none_or(|app_id| check_run.app().id() == app_id)
+    }
+}
+
+#[async_trait]
+impl Task<ChecksSummary> for ListChecks {
+    async fn execute(&mut self) -> Result<Transition<ChecksSummary>, Error> {
+        if !matches!(self.action, CheckRunAction::Completed) || self.summary.matches(&self.trigger)
+        {
+            return Ok(Transition::Complete(ChecksSummary::default()));
+        }
+
+        let check_runs = ListCheckRunsForGitSha::new(
+            &self.github_client,
+            &self.owner,
+            &self.repository,
+            &self.head_sha,
+        )
+        .execute()
+        .await?;
+
+        Ok(Transition::Next(Box::new(PublishSummary {
+            github_client: self.github_client.clone(),
+            owner: self.owner.clone(),
+            repository: self.repository.clone(),
+            head_sha: self.head_sha.clone(),
+            name: self.summary.name.clone(),
+            summary_check_run: summary_check_run(&check_runs, &self.summary),
+            check_runs: latest_check_runs(check_runs, &self.summary),
+        })))
+    }
+
+    fn name(&self) -> &str {
+        "list checks"
+    }
+}
+
+struct PublishSummary {
+    github_client: GitHubClient,
+    owner: Login,
+    repository: RepositoryName,
+    head_sha: GitSha,
+    name: CheckRunName,
+    summary_check_run: Option<CheckRunId>,
+    check_runs: Vec<CheckRun>,
+}
+
+#[async_trait]
+impl Task<ChecksSummary> for PublishSummary {
+    async fn execute(&mut self) -> Result<Transition<ChecksSummary>, Error> {
+        let mut summary = ChecksSummary::new(&self.check_runs);
+
+        let (status, conclusion) = if summary.completed < summary.total {
+            (CheckRunStatus::InProgress, None)
+        } else if summary.succeeded == summary.total {
+            (CheckRunStatus::Completed, Some(CheckRunConclusion::Success))
+        } else {
+            (CheckRunStatus::Completed, Some(CheckRunConclusion::Failure))
+        };
+
+        let output = output(&summary, &self.check_runs).build();
+
+        let check_run = match self.summary_check_run {
+            Some(check_run_id) => {
+                let check_run_args = UpdateCheckRunArgs {
+                    check_run_id,
+                    name: None,
+                    details_url: None,
+                    external_id: None,
+                    started_at: None,
+                    status: Some(status),
+                    conclusion,
+                    completed_at: None,
+                    output: Some(output),
+                };
+
+                UpdateCheckRun::new(
+                    &self.github_client,
+                    &self.owner,
+                    &self.repository,
+                    &check_run_args,
+                )
+                .execute()
+                .await?
+            }
+            None => {
+                let check_run_args = CreateCheckRunArgs {
+                    name: self.name.clone(),
+                    head_sha: self.head_sha.clone(),
+                    details_url: None,
+                    external_id: None,
+                    status: Some(status),
+                    started_at: None,
+                    conclusion,
+                    completed_at: None,
+                    output: Some(output),
+                };
+
+                CreateCheckRun::new(
+                    &self.github_client,
+                    &self.owner,
+                    &self.repository,
+                    &check_run_args,
+                )
+                .execute()
+                .await?
+            }
+        };
+
+        summary.check_run = Some(check_run);
+
+        Ok(Transition::Complete(summary))
+    }
+
+    fn name(&self) -> &str {
+        "publish summary"
+    }
+}
+
+/// Returns the id of the latest summary check run, or `None` if it has not been created yet.
+fn summary_check_run(check_runs: &[CheckRun], summary: &Summary) -> Option<CheckRunId> {
+    check_runs
+        .iter()
+        .filter(|check_run| summary.matches(check_run))
+        .map(|check_run| check_run.id())
+        .max_by_key(|check_run_id| check_run_id.get())
+}
+
+/// Returns the latest check run for each app and name, except for the summary check run.
+fn latest_check_runs(check_runs: Vec<CheckRun>, summary: &Summary) -> Vec<CheckRun> {
+    let mut latest: BTreeMap<(AppId, String), CheckRun> = BTreeMap::new();
+
+    for check_run in check_runs {
+        if summary.matches(&check_run) {
+            continue;
+        }
+
+        let key = (check_run.app().id(), check_run.name().get().to_string());
+        match latest.get(&key) {
+            Some(existing) if existing.id().get() > check_run.id().get() => {}
+            _ => {
+                latest.insert(key, check_run);
+            }
+        }
+    }
+
+    latest.into_values().collect()
+}
+
+fn output(summary: &ChecksSummary, check_runs: &[CheckRun]) -> CheckRunOutputBuilder {
+    let title = format!("{}/{} checks succeeded", summary.succeeded, summary.total);
+
+    let rows: Vec<Vec<String>> = check_runs
+        .iter()
+        .map(|check_run| {
+            let result = match check_run.conclusion() {
+                Some(conclusion) => conclusion.to_string(),
+                None => check_run.status().to_string(),
+            };
+
+            vec![
+                format!("[{}]({})", check_run.name(), check_run.html_url()),
+                result,
+            ]
+        })
+        .collect();
+
+    let mut markdown = Markdown::new().paragraph(&title);
+    if !rows.is_empty() {
+        markdown = markdown.table(&["Check", "Result"], &rows);
+    }
+
+    CheckRunOutputBuilder::new(&title).summary(markdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+    use serde_json::Value;
+
+    use automatons::Automaton;
+
+    use crate::event::CheckRunEvent;
+    use crate::resource::{AppId, CheckRun, CheckRunName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{
+        latest_check_runs, output, summary_check_run, ChecksAggregator, ChecksSummary, Summary,
+    };
+
+    fn event() -> CheckRunEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/check_run.completed.json"
+        ))
+        .unwrap()
+    }
+
+    fn check_run() -> CheckRun {
+        serde_json::from_str(include_str!("../../tests/fixtures/resource/check_run.json")).unwrap()
+    }
+
+    fn summary(name: &str) -> Summary {
+        Summary {
+            name: CheckRunName::new(name),
+            app_id: Some(AppId::new(15368)),
+        }
+    }
+
+    #[tokio::test]
+    async fn automaton_publishes_summary() {
+        let _token_mock = mock_installation_access_tokens();
+        let _check_suites_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/commits/24275d56a48c2dc73ae191524e20b19c41676b4f/check-suites",
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "check_suites": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/check_suite.json")
+        ))
+        .create();
+        let _check_runs_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/check-suites/7663255123/check-runs",
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "check_runs": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/check_run.json")
+        ))
+        .create();
+        let _create_mock = mock("POST", "/repos/devxbots/automatons/check-runs")
+            .with_status(201)
+            .with_body(include_str!("../../tests/fixtures/resource/check_run.json"))
+            .create();
+
+        let automaton = ChecksAggregator::new(github_client(), event());
+
+        let summary = automaton.execute().await.unwrap();
+
+        assert_eq!(1, summary.total());
+        assert_eq!(1, summary.succeeded());
+        assert!(summary.check_run().is_some());
+    }
+
+    #[tokio::test]
+    async fn automaton_updates_existing_summary() {
+        let mut summary_check_run: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/check_run.json"))
+                .unwrap();
+        summary_check_run["id"] = 4.into();
+        summary_check_run["name"] = "Checks".into();
+        summary_check_run["app"]["id"] = 1.into();
+
+        let _token_mock = mock_installation_access_tokens();
+        let _check_suites_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/commits/24275d56a48c2dc73ae191524e20b19c41676b4f/check-suites",
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "check_suites": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/check_suite.json")
+        ))
+        .create();
+        let _check_runs_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/check-suites/7663255123/check-runs",
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 2, "check_runs": [{}, {}] }}"#,
+            include_str!("../../tests/fixtures/resource/check_run.json"),
+            summary_check_run
+        ))
+        .create();
+        let create_mock = mock("POST", "/repos/devxbots/automatons/check-runs")
+            .expect(0)
+            .create();
+        let update_mock = mock("PATCH", "/repos/devxbots/automatons/check-runs/4")
+            .with_status(200)
+            .with_body(summary_check_run.to_string())
+            .create();
+
+        let automaton = ChecksAggregator::new(github_client(), event());
+
+        let summary = automaton.execute().await.unwrap();
+
+        assert_eq!(1, summary.total());
+        update_mock.assert();
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn automaton_skips_own_check_run() {
+        let mut event: Value = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/check_run.completed.json"
+        ))
+        .unwrap();
+        event["check_run"]["app"]["id"] = 1.into();
+        let event: CheckRunEvent = serde_json::from_value(event).unwrap();
+
+        let automaton =
+            ChecksAggregator::new(github_client(), event).name(CheckRunName::new("Run tests"));
+
+        let summary = automaton.execute().await.unwrap();
+
+        assert!(summary.check_run().is_none());
+    }
+
+    #[test]
+    fn summary_matches_name_and_app() {
+        assert!(summary("Run tests").matches(&check_run()));
+        assert!(!summary("Checks").matches(&check_run()));
+    }
+
+    #[test]
+    fn summary_does_not_match_other_apps() {
+        let summary = Summary {
+            name: CheckRunName::new("Run tests"),
+            app_id: Some(AppId::new(1)),
+        };
+
+        assert!(!summary.matches(&check_run()));
+    }
+
+    #[test]
+    fn latest_check_runs_skips_summary() {
+        let check_runs = latest_check_runs(vec![check_run()], &summary("Run tests"));
+
+        assert!(check_runs.is_empty());
+    }
+
+    #[test]
+    fn summary_check_run_returns_latest_summary() {
+        let check_runs = vec![check_run()];
+
+        assert_eq!(
+            Some(check_runs[0].id()),
+            summary_check_run(&check_runs, &summary("Run tests"))
+        );
+        assert_eq!(None, summary_check_run(&check_runs, &summary("Checks")));
+    }
+
+    #[test]
+    fn output_counts_succeeded_checks() {
+        let check_runs = vec![check_run()];
+        let summary = ChecksSummary::new(&check_runs);
+
+        let output = output(&summary, &check_runs).build();
+
+        assert_eq!("1/1 checks succeeded", output.title.get());
+        assert!(output
+            .summary
+            .get()
+            .contains("[Run tests](https://github.com/devxbots/automatons/runs/7669942377"));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ChecksAggregator>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ChecksAggregator>();
+    }
+}
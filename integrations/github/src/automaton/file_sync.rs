@@ -0,0 +1,539 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use automatons::{Automaton, Error, Product, Task, Transition};
+
+use crate::client::GitHubClient;
+use crate::resource::{GitRef, LabelName, Login, Repository, RepositoryFullName, RepositoryName};
+use crate::task::{
+    ChangesetArgs, FileChange, GetFile, ListInstallationRepositories, ProposeChange,
+    ProposeChangeArgs, ProposedChange,
+};
+
+/// Default name of the branch that changes are proposed on
+const DEFAULT_BRANCH: &str = "sync-files";
+
+/// Default title of the pull requests and commits
+const DEFAULT_TITLE: &str = "Synchronize files";
+
+/// Synchronizer for files across repositories
+///
+/// The [`FileSync`] keeps a set of files, e.g. a `LICENSE`, a `CODEOWNERS` file, or workflow
+/// templates, in sync across all repositories of an installation. It reads the files from the
+/// default branch of a source repository, and compares them with the files on the default branch
+/// of every other repository that the installation can access. When a file is missing or differs,
+/// the automaton proposes a pull request that writes the files from the source repository.
+///
+/// The pull requests are opened from the same branch in every repository. Repositories in which the
+/// branch already exists are skipped, since a pull request for an earlier drift is still pending.
+/// Archived and disabled repositories are skipped as well.
+#[derive(Clone, Debug)]
+pub struct FileSync {
+    github_client: GitHubClient,
+    owner: Login,
+    repository: RepositoryName,
+    paths: Vec<String>,
+    branch: GitRef,
+    title: String,
+    body: Option<String>,
+    labels: Vec<LabelName>,
+}
+
+/// Result of synchronizing files across repositories
+///
+/// The [`FileSyncReport`] is the product of the [`FileSync`] automaton. It lists the repositories
+/// that were already in sync, the repositories that still have a pending pull request, and the
+/// changes that were proposed.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct FileSyncReport {
+    up_to_date: Vec<RepositoryFullName>,
+    pending: Vec<RepositoryFullName>,
+    proposed: Vec<ProposedChange>,
+}
+
+impl FileSync {
+    /// Initializes the automaton for the files in the source repository
+    pub fn new(
+        github_client: GitHubClient,
+        owner: Login,
+        repository: RepositoryName,
+        paths: Vec<String>,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            paths,
+            branch: GitRef::new(DEFAULT_BRANCH),
+            title: DEFAULT_TITLE.into(),
+            body: None,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the branch that changes are proposed on.
+    pub fn branch(mut self, branch: GitRef) -> Self {
+        self.branch = branch;
+        self
+    }
+
+    /// Sets the title of the pull requests, which is also used as the commit message.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the body of the pull requests.
+    pub fn body(mut self, body: &str) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Adds the label to the pull requests.
+    pub fn label(mut self, label: LabelName) -> Self {
+        self.labels.push(label);
+        self
+    }
+}
+
+impl Automaton<FileSyncReport> for FileSync {
+    fn initial_task(&self) -> Box<dyn Task<FileSyncReport>> {
+        Box::new(ReadSourceFiles {
+            config: self.clone(),
+        })
+    }
+}
+
+impl FileSyncReport {
+    /// Returns the repositories in which all files were in sync.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn up_to_date(&self) -> &Vec<RepositoryFullName> {
+        &self.up_to_date
+    }
+
+    /// Returns the repositories that were skipped, because their branch already exists.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pending(&self) -> &Vec<RepositoryFullName> {
+        &self.pending
+    }
+
+    /// Returns the changes that were proposed for repositories that drifted.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn proposed(&self) -> &Vec<ProposedChange> {
+        &self.proposed
+    }
+}
+
+impl Product for FileSyncReport {}
+
+/// File that is synchronized, with its content in the source repository
+#[derive(Clone, Debug)]
+struct SourceFile {
+    path: String,
+    content: Vec<u8>,
+}
+
+struct ReadSourceFiles {
+    config: FileSync,
+}
+
+#[async_trait]
+impl Task<FileSyncReport> for ReadSourceFiles {
+    async fn execute(&mut self) -> Result<Transition<FileSyncReport>, Error> {
+        let config = &self.config;
+        let mut files = Vec::with_capacity(config.paths.len());
+
+        for path in &config.paths {
+            let file = GetFile::new(
+                &config.github_client,
+                &config.owner,
+                &config.repository,
+                path,
+            )
+            .execute()
+            .await?;
+
+            files.push(SourceFile {
+                path: path.clone(),
+                content: file.content().to_vec(),
+            });
+        }
+
+        Ok(Transition::Next(Box::new(ListRepositories {
+            config: self.config.clone(),
+            files,
+        })))
+    }
+
+    fn name(&self) -> &str {
+        "read source files"
+    }
+}
+
+struct ListRepositories {
+    config: FileSync,
+    files: Vec<SourceFile>,
+}
+
+#[async_trait]
+impl Task<FileSyncReport> for ListRepositories {
+    async fn execute(&mut self) -> Result<Transition<FileSyncReport>, Error> {
+        let repositories = ListInstallationRepositories::new(&self.config.github_client)
+            .execute()
+            .await?
+            .into_iter()
+            .filter(|repository| self.is_target(repository))
+            .collect();
+
+        Ok(Transition::Next(Box::new(SyncRepositories {
+            config: self.config.clone(),
+            files: self.files.clone(),
+            repositories,
+        })))
+    }
+
+    fn name(&self) -> &str {
+        "list repositories"
+    }
+}
+
+impl ListRepositories {
+    fn is_target(&self, repository: &Repository) -> bool {
+        let is_source = repository.owner().login() == &self.config.owner
+            && repository.name() == &self.config.repository;
+
+        !is_source
+            && !repository.archived().unwrap_or(false)
+            && !repository.disabled().unwrap_or(false)
+    }
+}
+
+struct SyncRepositories {
+    config: FileSync,
+    files: Vec<SourceFile>,
+    repositories: Vec<Repository>,
+}
+
+#[async_trait]
+impl Task<FileSyncReport> for SyncRepositories {
+    async fn execute(&mut self) -> Result<Transition<FileSyncReport>, Error> {
+        let mut report = FileSyncReport::default();
+
+        for repository in &self.repositories {
+            let owner = repository.owner().login();
+            let name = repository.name();
+
+            let changes = self.drift(owner, name).await?;
+            if changes.is_empty() {
+                report.up_to_date.push(repository.full_name().clone());
+                continue;
+            }
+
+            if self.branch_exists(owner, name).await? {
+                report.pending.push(repository.full_name().clone());
+                continue;
+            }
+
+            let change_args = ProposeChangeArgs {
+                changeset: ChangesetArgs {
+                    base: GitRef::new(repository.default_branch()),
+                    branch: self.config.branch.clone(),
+                    message: self.config.title.clone(),
+                    author: None,
+                    changes,
+                },
+                title: self.config.title.clone(),
+                body: self.config.body.clone(),
+                draft: false,
+                labels: self.config.labels.clone(),
+                reviewers: Vec::new(),
+                team_reviewers: Vec::new(),
+            };
+
+            let proposed_change =
+                ProposeChange::new(&self.config.github_client, owner, name, &change_args)
+                    .execute()
+                    .await?;
+
+            report.proposed.push(proposed_change);
+        }
+
+        Ok(Transition::Complete(report))
+    }
+
+    fn name(&self) -> &str {
+        "sync repositories"
+    }
+}
+
+impl SyncRepositories {
+    /// Returns the changes that bring the files in the repository in sync with the source.
+    async fn drift(
+        &self,
+        owner: &Login,
+        repository: &RepositoryName,
+    ) -> Result<Vec<FileChange>, Error> {
+        let mut changes = Vec::new();
+
+        for file in &self.files {
+            let is_current =
+                match GetFile::new(&self.config.github_client, owner, repository, &file.path)
+                    .execute()
+                    .await
+                {
+                    Ok(current) => current.content() == file.content.as_slice(),
+                    Err(Error::NotFound(_)) => false,
+                    Err(error) => return Err(error),
+                };
+
+            if !is_current {
+                changes.push(FileChange::Write {
+                    path: file.path.clone(),
+                    content: file.content.clone(),
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
+    async fn branch_exists(
+        &self,
+        owner: &Login,
+        repository: &RepositoryName,
+    ) -> Result<bool, Error> {
+        let url = format!(
+            "/repos/{}/{}/git/ref/heads/{}",
+            owner.get(),
+            repository.get(),
+            self.config.branch.get()
+        );
+
+        match self.config.github_client.get::<Value>(&url).await {
+            Ok(_) => Ok(true),
+            Err(Error::NotFound(_)) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher, Mock};
+
+    use automatons::{Automaton, Error};
+
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::installation::mock_list_installation_repositories;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::FileSync;
+
+    const SOURCE_LICENSE: &str = "TUlUIExpY2Vuc2U=";
+
+    const REFERENCE: &str = r#"{
+      "ref": "refs/heads/main",
+      "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+      "url": "https://api.github.com/repos/devxbots/automatons/git/refs/heads/main",
+      "object": {
+        "type": "commit",
+        "sha": "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+        "url": "https://api.github.com/repos/devxbots/automatons/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0"
+      }
+    }"#;
+
+    fn mock_file(repository: &str, status: usize, content: &str) -> Mock {
+        let body = if status == 200 {
+            format!(
+                r#"{{
+                  "type": "file",
+                  "encoding": "base64",
+                  "size": 11,
+                  "name": "LICENSE",
+                  "path": "LICENSE",
+                  "content": "{content}",
+                  "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                  "url": "https://api.github.com/repos/{repository}/contents/LICENSE",
+                  "git_url": "https://api.github.com/repos/{repository}/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+                  "html_url": "https://github.com/{repository}/blob/main/LICENSE",
+                  "download_url": "https://raw.githubusercontent.com/{repository}/main/LICENSE"
+                }}"#
+            )
+        } else {
+            r#"{ "message": "Not Found" }"#.into()
+        };
+
+        mock(
+            "GET",
+            format!("/repos/{}/contents/LICENSE", repository).as_str(),
+        )
+        .with_status(status)
+        .with_body(body)
+        .create()
+    }
+
+    fn mock_sync_branch(status: usize) -> Mock {
+        mock("GET", "/repos/devxbots/automatons/git/ref/heads/sync-files")
+            .with_status(status)
+            .with_body(r#"{ "message": "Not Found" }"#)
+            .create()
+    }
+
+    fn automaton() -> FileSync {
+        FileSync::new(
+            github_client(),
+            Login::new("devxbots"),
+            RepositoryName::new("templates"),
+            vec!["LICENSE".into()],
+        )
+    }
+
+    #[tokio::test]
+    async fn automaton_proposes_change_for_drift() {
+        let _token_mock = mock_installation_access_tokens();
+        let _source_mock = mock_file("devxbots/templates", 200, SOURCE_LICENSE);
+        let _repositories_mock = mock_list_installation_repositories();
+        let _target_mock = mock_file("devxbots/automatons", 404, "");
+        let _branch_mock = mock_sync_branch(404);
+        let _base_mock = mock("GET", "/repos/devxbots/automatons/git/ref/heads/main")
+            .with_status(200)
+            .with_body(REFERENCE)
+            .create();
+        let _commit_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+        )
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/git_commit.json"
+        ))
+        .create();
+        let _base_tree_mock = mock(
+            "GET",
+            "/repos/devxbots/automatons/git/trees/827efc6d56897b048c772eb4087f854f46256132",
+        )
+        .match_query(Matcher::UrlEncoded("recursive".into(), "1".into()))
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/tree.json"))
+        .create();
+        let blob_mock = mock("POST", "/repos/devxbots/automatons/git/blobs")
+            .match_body(Matcher::PartialJsonString(format!(
+                r#"{{"content":"{}"}}"#,
+                SOURCE_LICENSE
+            )))
+            .with_status(201)
+            .with_body(
+                r#"{
+                  "url": "https://api.github.com/repos/devxbots/automatons/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15",
+                  "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"
+                }"#,
+            )
+            .create();
+        let _tree_mock = mock("POST", "/repos/devxbots/automatons/git/trees")
+            .with_status(201)
+            .with_body(include_str!("../../tests/fixtures/resource/tree.json"))
+            .create();
+        let _create_commit_mock = mock("POST", "/repos/devxbots/automatons/git/commits")
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/git_commit.json"
+            ))
+            .create();
+        let reference_mock = mock("POST", "/repos/devxbots/automatons/git/refs")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"ref":"refs/heads/sync-files"}"#.into(),
+            ))
+            .with_status(201)
+            .with_body(REFERENCE)
+            .create();
+        let pull_request_mock = mock("POST", "/repos/devxbots/automatons/pulls")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"title":"Synchronize files","head":"sync-files","base":"main"}"#.into(),
+            ))
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/pull_request.json"
+            ))
+            .create();
+
+        let report = automaton().execute().await.unwrap();
+
+        assert_eq!(1, report.proposed().len());
+        assert!(report.up_to_date().is_empty());
+        blob_mock.assert();
+        reference_mock.assert();
+        pull_request_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn automaton_skips_repositories_in_sync() {
+        let _token_mock = mock_installation_access_tokens();
+        let _source_mock = mock_file("devxbots/templates", 200, SOURCE_LICENSE);
+        let _repositories_mock = mock_list_installation_repositories();
+        let _target_mock = mock_file("devxbots/automatons", 200, SOURCE_LICENSE);
+
+        let report = automaton().execute().await.unwrap();
+
+        assert_eq!("devxbots/automatons", report.up_to_date()[0].get());
+        assert!(report.proposed().is_empty());
+    }
+
+    #[tokio::test]
+    async fn automaton_skips_repositories_with_pending_branch() {
+        let _token_mock = mock_installation_access_tokens();
+        let _source_mock = mock_file("devxbots/templates", 200, SOURCE_LICENSE);
+        let _repositories_mock = mock_list_installation_repositories();
+        let _target_mock = mock_file("devxbots/automatons", 200, "T3V0ZGF0ZWQ=");
+        let _branch_mock = mock_sync_branch(200);
+
+        let report = automaton().execute().await.unwrap();
+
+        assert_eq!("devxbots/automatons", report.pending()[0].get());
+        assert!(report.proposed().is_empty());
+    }
+
+    #[tokio::test]
+    async fn automaton_skips_source_repository() {
+        let _token_mock = mock_installation_access_tokens();
+        let _source_mock = mock_file("devxbots/automatons", 200, SOURCE_LICENSE);
+        let _repositories_mock = mock_list_installation_repositories();
+
+        let automaton = FileSync::new(
+            github_client(),
+            Login::new("devxbots"),
+            RepositoryName::new("automatons"),
+            vec!["LICENSE".into()],
+        );
+
+        let report = automaton.execute().await.unwrap();
+
+        assert!(report.up_to_date().is_empty());
+        assert!(report.pending().is_empty());
+        assert!(report.proposed().is_empty());
+    }
+
+    #[tokio::test]
+    async fn automaton_fails_without_source_file() {
+        let _token_mock = mock_installation_access_tokens();
+        let _source_mock = mock_file("devxbots/templates", 404, "");
+
+        let error = automaton().execute().await.unwrap_err();
+
+        assert!(
+            matches!(error, Error::Task { source, .. } if matches!(*source, Error::NotFound(_)))
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<FileSync>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<FileSync>();
+    }
+}
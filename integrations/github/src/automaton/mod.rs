@@ -0,0 +1,10 @@
+//! Automatons for GitHub
+//!
+//! The GitHub integration ships automatons that combine its events and tasks into complete
+//! workflows. They can be used as they are, or serve as templates for custom automatons.
+
+pub use self::checks_aggregator::{ChecksAggregator, ChecksSummary};
+pub use self::file_sync::{FileSync, FileSyncReport};
+
+mod checks_aggregator;
+mod file_sync;
@@ -0,0 +1,304 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use automatons::Error;
+
+use crate::resource::{InstallationId, RepositoryFullName};
+
+/// Outcome of an audited request
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "outcome", content = "message", rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// GitHub accepted the request.
+    Succeeded,
+
+    /// The request failed with the given error.
+    Failed(String),
+
+    /// The request was skipped, because the client is in dry-run mode.
+    DryRun,
+}
+
+/// Record of a mutating request to GitHub
+///
+/// The client records every `POST`, `PATCH`, `PUT`, and `DELETE` request that it sends, together
+/// with the installation that it authenticated as and the automaton that it acted for. The
+/// endpoint contains the ids of the resources that were changed, e.g. the number of a pull request.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct AuditRecord {
+    timestamp: DateTime<Utc>,
+    method: String,
+    endpoint: String,
+    repository: Option<RepositoryFullName>,
+    installation_id: Option<InstallationId>,
+    automaton: Option<String>,
+    #[serde(flatten)]
+    outcome: AuditOutcome,
+}
+
+impl AuditRecord {
+    /// Initializes a record for a request that was sent just now
+    pub fn new(
+        method: &str,
+        endpoint: &str,
+        installation_id: Option<InstallationId>,
+        automaton: Option<&str>,
+        outcome: AuditOutcome,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            method: method.into(),
+            endpoint: endpoint.into(),
+            repository: repository(endpoint),
+            installation_id,
+            automaton: automaton.map(String::from),
+            outcome,
+        }
+    }
+
+    /// Returns the time at which the request completed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the HTTP method of the request.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns the endpoint of the request, e.g. `/repos/devxbots/automatons/pulls/1`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the repository that the request changed, if the endpoint belongs to a repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> Option<&RepositoryFullName> {
+        self.repository.as_ref()
+    }
+
+    /// Returns the installation that the client authenticated as.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation_id(&self) -> Option<InstallationId> {
+        self.installation_id
+    }
+
+    /// Returns the automaton that the client acted for.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn automaton(&self) -> Option<&str> {
+        self.automaton.as_deref()
+    }
+
+    /// Returns whether the request succeeded.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn outcome(&self) -> &AuditOutcome {
+        &self.outcome
+    }
+}
+
+/// Sink for audit records
+///
+/// Operators that need to answer who changed a resource, e.g. the branch protection of a
+/// repository, can configure the client with an [`AuditSink`]. The sink receives a record for every
+/// mutating request and can write it to a log, a database, or a queue.
+///
+/// A failure to record a request is traced, but does not fail the request, since GitHub has
+/// already applied the change.
+#[async_trait]
+pub trait AuditSink: Debug + Send + Sync {
+    /// Records the mutating request.
+    async fn record(&self, record: AuditRecord) -> Result<(), Error>;
+}
+
+/// Sink that keeps audit records in memory
+///
+/// The [`MemoryAuditSink`] is useful in tests, and for short-lived processes that report the
+/// changes they made when they exit.
+#[derive(Debug, Default)]
+pub struct MemoryAuditSink {
+    records: Mutex<Vec<AuditRecord>>,
+}
+
+impl MemoryAuditSink {
+    /// Initializes an empty sink
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the records in the order in which they were recorded.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.records.lock().clone()
+    }
+}
+
+#[async_trait]
+impl AuditSink for MemoryAuditSink {
+    async fn record(&self, record: AuditRecord) -> Result<(), Error> {
+        self.records.lock().push(record);
+        Ok(())
+    }
+}
+
+/// Extracts the repository from endpoints like `/repos/{owner}/{repo}/...`.
+fn repository(endpoint: &str) -> Option<RepositoryFullName> {
+    let mut segments = endpoint.strip_prefix("/repos/")?.split(['/', '?']);
+
+    let owner = segments.next().filter(|owner| !owner.is_empty())?;
+    let name = segments.next().filter(|name| !name.is_empty())?;
+
+    Some(RepositoryFullName::new(&format!("{}/{}", owner, name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockito::mock;
+    use serde_json::Value;
+
+    use crate::client::{GitHubClient, PrivateKey};
+    use crate::resource::{AppId, InstallationId, RepositoryFullName};
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{repository, AuditOutcome, AuditRecord, MemoryAuditSink};
+
+    fn github_client(sink: Arc<MemoryAuditSink>) -> GitHubClient {
+        GitHubClient::builder(
+            mockito::server_url().into(),
+            AppId::new(1),
+            PrivateKey::new(include_str!("../../tests/fixtures/private-key.pem")),
+            InstallationId::new(1),
+        )
+        .audit_sink(sink)
+        .build()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn client_records_mutating_requests() {
+        let _token_mock = mock_installation_access_tokens();
+        let _label_mock = mock("POST", "/repos/devxbots/automatons/issues/1/labels")
+            .with_status(200)
+            .with_body("[]")
+            .create();
+
+        let sink = Arc::new(MemoryAuditSink::new());
+        let client = github_client(sink.clone()).acting_as("file-sync");
+
+        let _: Value = client
+            .post(
+                "/repos/devxbots/automatons/issues/1/labels",
+                Some(vec!["bug"]),
+            )
+            .await
+            .unwrap();
+
+        let records = sink.records();
+        let record = records.first().unwrap();
+
+        assert_eq!(1, records.len());
+        assert_eq!("POST", record.method());
+        assert_eq!(
+            Some(&RepositoryFullName::new("devxbots/automatons")),
+            record.repository()
+        );
+        assert_eq!(Some(InstallationId::new(1)), record.installation_id());
+        assert_eq!(Some("file-sync"), record.automaton());
+        assert_eq!(&AuditOutcome::Succeeded, record.outcome());
+    }
+
+    #[tokio::test]
+    async fn client_records_failed_requests() {
+        let _token_mock = mock_installation_access_tokens();
+        let _protection_mock = mock("PUT", "/repos/devxbots/forbidden/branches/main/protection")
+            .with_status(403)
+            .with_body(r#"{ "message": "Resource not accessible by integration" }"#)
+            .create();
+
+        let sink = Arc::new(MemoryAuditSink::new());
+        let client = github_client(sink.clone());
+
+        let result: Result<Value, _> = client
+            .put(
+                "/repos/devxbots/forbidden/branches/main/protection",
+                None::<Value>,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(matches!(
+            sink.records()[0].outcome(),
+            AuditOutcome::Failed(message) if message.contains("Resource not accessible")
+        ));
+    }
+
+    #[tokio::test]
+    async fn client_does_not_record_reads() {
+        let _token_mock = mock_installation_access_tokens();
+        let _repository_mock = mock("GET", "/repos/devxbots/automatons")
+            .with_status(200)
+            .with_body_from_file("tests/fixtures/resource/repository.json")
+            .create();
+
+        let sink = Arc::new(MemoryAuditSink::new());
+        let client = github_client(sink.clone());
+
+        let _: Value = client.get("/repos/devxbots/automatons").await.unwrap();
+
+        assert!(sink.records().is_empty());
+    }
+
+    #[test]
+    fn repository_from_endpoint() {
+        assert_eq!(
+            Some(RepositoryFullName::new("devxbots/automatons")),
+            repository("/repos/devxbots/automatons/check-runs/4")
+        );
+        assert_eq!(
+            Some(RepositoryFullName::new("devxbots/automatons")),
+            repository("/repos/devxbots/automatons?per_page=100")
+        );
+        assert_eq!(None, repository("/app/installations/1/access_tokens"));
+        assert_eq!(None, repository("/repos/devxbots"));
+    }
+
+    #[test]
+    fn record_serializes_outcome() {
+        let record = AuditRecord::new(
+            "PATCH",
+            "/repos/devxbots/automatons/check-runs/4",
+            None,
+            None,
+            AuditOutcome::Failed("Validation Failed".into()),
+        );
+
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!("failed", json["outcome"]);
+        assert_eq!("Validation Failed", json["message"]);
+        assert_eq!("devxbots/automatons", json["repository"]);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<AuditRecord>();
+        assert_send::<MemoryAuditSink>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<AuditRecord>();
+        assert_sync::<MemoryAuditSink>();
+    }
+}
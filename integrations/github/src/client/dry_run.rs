@@ -0,0 +1,72 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use automatons::Error;
+
+/// Synthesizes the response to a mutating request that was not sent.
+///
+/// The client does not invent resources that GitHub never created, e.g. the id of a check run.
+/// Requests whose response has the shape of their body, e.g. adding labels to an issue, respond
+/// with the body, and requests without a response respond with an empty response. All other
+/// requests return [`Error::DryRun`], so that callers can tell a skipped request from a real one.
+pub(super) fn synthesize<T>(method: &str, endpoint: &str, body: Value) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    if let Ok(response) = serde_json::from_value(body) {
+        return Ok(response);
+    }
+
+    serde_json::from_value(Value::Null).map_err(|_| {
+        Error::DryRun(format!(
+            "skipped {} request to {} in dry run",
+            method, endpoint
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use automatons::Error;
+
+    use crate::resource::{CheckRun, Issue};
+
+    use super::synthesize;
+
+    #[test]
+    fn synthesize_echoes_body() {
+        let response: Value = synthesize(
+            "PUT",
+            "/repos/devxbots/automatons/issues/1/lock",
+            json!({ "lock_reason": "resolved" }),
+        )
+        .unwrap();
+
+        assert_eq!("resolved", response["lock_reason"]);
+    }
+
+    #[test]
+    fn synthesize_empty_response() {
+        let response: Option<Issue> = synthesize(
+            "POST",
+            "/repos/devxbots/automatons/hooks/1/pings",
+            Value::Null,
+        )
+        .unwrap();
+
+        assert!(response.is_none());
+    }
+
+    #[test]
+    fn synthesize_returns_dry_run_error_for_resources() {
+        let result: Result<CheckRun, Error> = synthesize(
+            "POST",
+            "/repos/devxbots/automatons/check-runs",
+            json!({ "name": "dry-run" }),
+        );
+
+        assert!(matches!(result, Err(Error::DryRun(_))));
+    }
+}
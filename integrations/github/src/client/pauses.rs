@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::event::GitHubEvent;
+use crate::resource::{InstallationId, RepositoryId};
+
+/// Scope in which automatons are paused
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseScope {
+    /// Pauses for all installations and repositories.
+    All,
+
+    /// Pauses for all repositories of the installation.
+    Installation(InstallationId),
+
+    /// Pauses for the repository.
+    Repository(RepositoryId),
+}
+
+/// Command that pauses or resumes automatons
+///
+/// Operators can send commands through an admin endpoint or a queue. The commands are serialized
+/// with the action as a tag, e.g. `{ "action": "pause", "automaton": "checks", "scope": "all" }`.
+/// Commands without an automaton apply to all automatons.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PauseCommand {
+    /// Pauses the automaton in the scope.
+    Pause {
+        /// The name of the automaton, or `None` for all automatons.
+        #[serde(default)]
+        automaton: Option<String>,
+
+        /// The scope in which the automaton is paused.
+        scope: PauseScope,
+    },
+
+    /// Resumes the automaton in the scope.
+    Resume {
+        /// The name of the automaton, or `None` for all automatons.
+        #[serde(default)]
+        automaton: Option<String>,
+
+        /// The scope in which the automaton is resumed.
+        scope: PauseScope,
+    },
+}
+
+/// Kill switch for automatons
+///
+/// Operators sometimes need to stop an automaton quickly, e.g. when it misbehaves in a repository
+/// or floods an installation with pull requests. The [`PauseRegistry`] records which automatons
+/// are paused, either globally or for specific installations and repositories, and is consulted
+/// before an automaton is executed for an event.
+///
+/// A pause only matches the exact scope that it was given, so resuming a repository does not lift a
+/// pause of its installation. The registry is held in memory and is lost when the process exits.
+#[derive(Debug, Default)]
+pub struct PauseRegistry {
+    pauses: Mutex<HashSet<(Option<String>, PauseScope)>>,
+}
+
+impl PauseRegistry {
+    /// Initializes a registry in which no automaton is paused
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pauses the automaton in the scope, or all automatons if `automaton` is `None`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn pause(&self, automaton: Option<&str>, scope: PauseScope) {
+        self.pauses
+            .lock()
+            .insert((automaton.map(String::from), scope));
+    }
+
+    /// Resumes the automaton in the scope, or all automatons if `automaton` is `None`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn resume(&self, automaton: Option<&str>, scope: PauseScope) {
+        self.pauses
+            .lock()
+            .remove(&(automaton.map(String::from), scope));
+    }
+
+    /// Applies a command from an admin endpoint or a queue.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn apply(&self, command: &PauseCommand) {
+        match command {
+            PauseCommand::Pause { automaton, scope } => self.pause(automaton.as_deref(), *scope),
+            PauseCommand::Resume { automaton, scope } => self.resume(automaton.as_deref(), *scope),
+        }
+    }
+
+    /// Returns the pauses that are in effect, ordered by automaton and scope.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn pauses(&self) -> Vec<(Option<String>, PauseScope)> {
+        let mut pauses: Vec<_> = self.pauses.lock().iter().cloned().collect();
+        pauses.sort();
+        pauses
+    }
+
+    /// Indicates whether the automaton is paused for the event.
+    ///
+    /// The automaton is paused if it or all automatons have been paused globally, for the
+    /// installation that sent the event, or for the repository of the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn is_paused(&self, automaton: &str, event: &GitHubEvent) -> bool {
+        let mut scopes = vec![PauseScope::All];
+        if let Some(installation_id) = event.installation_id() {
+            scopes.push(PauseScope::Installation(installation_id));
+        }
+        if let Some(repository_id) = event.repository_id() {
+            scopes.push(PauseScope::Repository(repository_id));
+        }
+
+        let pauses = self.pauses.lock();
+        let automatons = [Some(automaton.to_string()), None];
+
+        automatons.into_iter().any(|automaton| {
+            scopes
+                .iter()
+                .any(|scope| pauses.contains(&(automaton.clone(), *scope)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+
+    use super::{PauseCommand, PauseRegistry, PauseScope};
+
+    fn event() -> GitHubEvent {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+        deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap()
+    }
+
+    #[test]
+    fn is_paused_without_pauses() {
+        let registry = PauseRegistry::new();
+
+        assert!(!registry.is_paused("checks", &event()));
+    }
+
+    #[test]
+    fn pause_automaton_globally() {
+        let registry = PauseRegistry::new();
+
+        registry.pause(Some("checks"), PauseScope::All);
+
+        assert!(registry.is_paused("checks", &event()));
+        assert!(!registry.is_paused("file-sync", &event()));
+    }
+
+    #[test]
+    fn pause_all_automatons_for_repository() {
+        let registry = PauseRegistry::new();
+        let event = event();
+
+        registry.pause(None, PauseScope::Repository(event.repository_id().unwrap()));
+
+        assert!(registry.is_paused("checks", &event));
+        assert!(registry.is_paused("file-sync", &event));
+    }
+
+    #[test]
+    fn pause_automaton_for_installation() {
+        let registry = PauseRegistry::new();
+        let event = event();
+        let scope = PauseScope::Installation(event.installation_id().unwrap());
+
+        registry.pause(Some("checks"), scope);
+        assert!(registry.is_paused("checks", &event));
+
+        registry.resume(Some("checks"), scope);
+        assert!(!registry.is_paused("checks", &event));
+    }
+
+    #[test]
+    fn apply_commands() {
+        let registry = PauseRegistry::new();
+
+        let pause: PauseCommand =
+            serde_json::from_str(r#"{ "action": "pause", "automaton": "checks", "scope": "all" }"#)
+                .unwrap();
+        registry.apply(&pause);
+
+        assert_eq!(
+            vec![(Some("checks".to_string()), PauseScope::All)],
+            registry.pauses()
+        );
+
+        let resume: PauseCommand = serde_json::from_str(
+            r#"{ "action": "resume", "automaton": "checks", "scope": "all" }"#,
+        )
+        .unwrap();
+        registry.apply(&resume);
+
+        assert!(registry.pauses().is_empty());
+    }
+
+    #[test]
+    fn command_deserializes_scope_with_id() {
+        let command: PauseCommand =
+            serde_json::from_str(r#"{ "action": "pause", "scope": { "repository": 518377950 } }"#)
+                .unwrap();
+
+        assert!(matches!(
+            command,
+            PauseCommand::Pause {
+                automaton: None,
+                scope: PauseScope::Repository(id)
+            } if id.get() == 518377950
+        ));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PauseRegistry>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PauseRegistry>();
+    }
+}
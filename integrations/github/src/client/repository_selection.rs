@@ -0,0 +1,289 @@
+use std::collections::{HashMap, HashSet};
+
+use parking_lot::Mutex;
+
+use crate::event::{
+    GitHubEvent, InstallationAction, InstallationEvent, InstallationRepositoriesEvent,
+};
+use crate::resource::{InstallationId, RepositoryId, RepositorySelection};
+
+/// In-memory cache of the repositories that installations can access
+///
+/// Users can remove repositories from an installation at any time. GitHub might still deliver
+/// events for these repositories, for example when they were queued before the change, and every
+/// request that an automaton sends for them fails with a `404 Not Found`. The
+/// [`RepositorySelectionCache`] tracks the `installation_repositories` events that it is given, so
+/// that automatons can skip events for repositories that have been deselected.
+///
+/// Owners can also suspend an installation, after which it can no longer request access tokens.
+/// The cache tracks `installation` events as well, and skips all events of suspended installations
+/// until they are unsuspended.
+///
+/// The cache only knows about the changes that it has seen. Repositories that have not been
+/// removed from an installation are considered to be selected, and installations that have not
+/// been suspended are considered to be active.
+#[derive(Debug, Default)]
+pub struct RepositorySelectionCache {
+    deselected: Mutex<HashMap<InstallationId, HashSet<RepositoryId>>>,
+    suspended: Mutex<HashSet<InstallationId>>,
+}
+
+impl RepositorySelectionCache {
+    /// Initializes an empty cache
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the cache with the changes of an `installation_repositories` event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn apply(&self, event: &InstallationRepositoriesEvent) {
+        let installation_id = event.installation().id();
+        let mut deselected = self.deselected.lock();
+
+        if event.repository_selection() == RepositorySelection::All {
+            deselected.remove(&installation_id);
+            return;
+        }
+
+        let repositories = deselected.entry(installation_id).or_default();
+
+        for repository in event.repositories_added() {
+            repositories.remove(&repository.id());
+        }
+        for repository in event.repositories_removed() {
+            repositories.insert(repository.id());
+        }
+    }
+
+    /// Updates the cache with the changes of an `installation` event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn apply_installation(&self, event: &InstallationEvent) {
+        let installation_id = event.installation().id();
+        let mut suspended = self.suspended.lock();
+
+        match event.action() {
+            InstallationAction::Suspend => {
+                suspended.insert(installation_id);
+            }
+            InstallationAction::Unsuspend => {
+                suspended.remove(&installation_id);
+            }
+            InstallationAction::Created | InstallationAction::Deleted => {
+                suspended.remove(&installation_id);
+                self.deselected.lock().remove(&installation_id);
+            }
+            InstallationAction::NewPermissionsAccepted => {}
+        }
+    }
+
+    /// Updates the cache if the event is an `installation` or `installation_repositories` event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn observe(&self, event: &GitHubEvent) {
+        match event {
+            GitHubEvent::Installation(event) => self.apply_installation(event),
+            GitHubEvent::InstallationRepositories(event) => self.apply(event),
+            _ => {}
+        }
+    }
+
+    /// Indicates whether the installation has been suspended.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn is_suspended(&self, installation_id: InstallationId) -> bool {
+        self.suspended.lock().contains(&installation_id)
+    }
+
+    /// Indicates whether the installation can access the repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn is_selected(
+        &self,
+        installation_id: InstallationId,
+        repository_id: RepositoryId,
+    ) -> bool {
+        self.deselected
+            .lock()
+            .get(&installation_id)
+            .is_none_or(|repositories| !repositories.contains(&repository_id))
+    }
+
+    /// Indicates whether the installation that sent the event can access its repository.
+    ///
+    /// Events of suspended installations are never selected, with the exception of the
+    /// `installation` events that suspend and unsuspend them. Events without an installation or a
+    /// repository are always selected otherwise.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn is_event_selected(&self, event: &GitHubEvent) -> bool {
+        if let Some(installation_id) = event.installation_id() {
+            if self.is_suspended(installation_id) && !matches!(event, GitHubEvent::Installation(_))
+            {
+                return false;
+            }
+        }
+
+        match (event.installation_id(), event.repository_id()) {
+            (Some(installation_id), Some(repository_id)) => {
+                self.is_selected(installation_id, repository_id)
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+    use crate::resource::{InstallationId, RepositoryId};
+
+    use super::RepositorySelectionCache;
+
+    fn removed_event() -> GitHubEvent {
+        let payload =
+            include_bytes!("../../tests/fixtures/event/installation_repositories.removed.json");
+        deserialize_event(&GitHubEventKind::InstallationRepositories, payload).unwrap()
+    }
+
+    fn event_with_selection(action: &str, repository_selection: &str) -> GitHubEvent {
+        let mut payload: Value = serde_json::from_slice(include_bytes!(
+            "../../tests/fixtures/event/installation_repositories.removed.json"
+        ))
+        .unwrap();
+
+        payload["action"] = action.into();
+        payload["repository_selection"] = repository_selection.into();
+        if action == "added" {
+            payload["repositories_added"] = payload["repositories_removed"].take();
+            payload["repositories_removed"] = Value::Array(Vec::new());
+        }
+
+        deserialize_event(
+            &GitHubEventKind::InstallationRepositories,
+            &serde_json::to_vec(&payload).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn is_selected_without_changes() {
+        let cache = RepositorySelectionCache::new();
+
+        assert!(cache.is_selected(InstallationId::new(25802826), RepositoryId::new(518377950)));
+    }
+
+    #[test]
+    fn observe_removed_repositories() {
+        let cache = RepositorySelectionCache::new();
+
+        cache.observe(&removed_event());
+
+        assert!(!cache.is_selected(InstallationId::new(25802826), RepositoryId::new(518377950)));
+        assert!(cache.is_selected(InstallationId::new(25802826), RepositoryId::new(1)));
+        assert!(cache.is_selected(InstallationId::new(1), RepositoryId::new(518377950)));
+    }
+
+    #[test]
+    fn observe_added_repositories() {
+        let cache = RepositorySelectionCache::new();
+
+        cache.observe(&removed_event());
+        cache.observe(&event_with_selection("added", "selected"));
+
+        assert!(cache.is_selected(InstallationId::new(25802826), RepositoryId::new(518377950)));
+    }
+
+    #[test]
+    fn observe_all_repositories() {
+        let cache = RepositorySelectionCache::new();
+
+        cache.observe(&removed_event());
+        cache.observe(&event_with_selection("added", "all"));
+
+        assert!(cache.is_selected(InstallationId::new(25802826), RepositoryId::new(518377950)));
+    }
+
+    #[test]
+    fn is_event_selected() {
+        let cache = RepositorySelectionCache::new();
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+        let event = deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap();
+
+        let installation_id = event.installation_id().unwrap();
+        let repository_id = event.repository_id().unwrap();
+
+        assert!(cache.is_event_selected(&event));
+
+        let mut payload: Value = serde_json::from_slice(include_bytes!(
+            "../../tests/fixtures/event/installation_repositories.removed.json"
+        ))
+        .unwrap();
+        payload["installation"]["id"] = installation_id.get().into();
+        payload["repositories_removed"][0]["id"] = repository_id.get().into();
+        cache.observe(
+            &deserialize_event(
+                &GitHubEventKind::InstallationRepositories,
+                &serde_json::to_vec(&payload).unwrap(),
+            )
+            .unwrap(),
+        );
+
+        assert!(!cache.is_event_selected(&event));
+    }
+
+    fn installation_event(action: &str) -> GitHubEvent {
+        let mut payload: Value = serde_json::from_slice(include_bytes!(
+            "../../tests/fixtures/event/installation.suspend.json"
+        ))
+        .unwrap();
+
+        payload["action"] = action.into();
+
+        deserialize_event(
+            &GitHubEventKind::Installation,
+            &serde_json::to_vec(&payload).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn observe_suspended_installation() {
+        let cache = RepositorySelectionCache::new();
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+        let event = deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap();
+
+        cache.observe(&installation_event("suspend"));
+
+        assert!(cache.is_suspended(InstallationId::new(25802826)));
+        assert!(!cache.is_event_selected(&event));
+        assert!(cache.is_event_selected(&installation_event("unsuspend")));
+
+        cache.observe(&installation_event("unsuspend"));
+
+        assert!(!cache.is_suspended(InstallationId::new(25802826)));
+        assert!(cache.is_event_selected(&event));
+    }
+
+    #[test]
+    fn observe_deleted_installation() {
+        let cache = RepositorySelectionCache::new();
+
+        cache.observe(&removed_event());
+        cache.observe(&installation_event("suspend"));
+        cache.observe(&installation_event("deleted"));
+
+        assert!(!cache.is_suspended(InstallationId::new(25802826)));
+        assert!(cache.is_selected(InstallationId::new(25802826), RepositoryId::new(518377950)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RepositorySelectionCache>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RepositorySelectionCache>();
+    }
+}
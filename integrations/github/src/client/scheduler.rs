@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::{sleep_until, Instant};
+
+use automatons::Error;
+
+/// Scheduler that paces the requests of a client
+///
+/// GitHub throttles clients that send a burst of requests, for example when an automaton creates
+/// annotations for hundreds of check runs at once. The scheduler limits how many requests are in
+/// flight at the same time, and how many requests are started per second. Requests that exceed the
+/// limits wait until they can be sent, so that callers can start as many futures as they want.
+///
+/// Clones of a client share the same scheduler. By default, requests are not limited.
+#[derive(Clone, Debug, Default)]
+pub(super) struct RequestScheduler {
+    permits: Option<Arc<Semaphore>>,
+    pacing: Option<Pacing>,
+}
+
+#[derive(Clone, Debug)]
+struct Pacing {
+    interval: Duration,
+    next_slot: Arc<Mutex<Instant>>,
+}
+
+/// Permit to send a request
+///
+/// The permit counts towards the maximum number of concurrent requests until it is dropped.
+#[derive(Debug)]
+pub(super) struct RequestPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl RequestScheduler {
+    pub fn new(max_concurrency: Option<usize>, requests_per_second: Option<u32>) -> Self {
+        let permits = max_concurrency.map(|permits| Arc::new(Semaphore::new(permits)));
+
+        let pacing = requests_per_second.map(|requests_per_second| Pacing {
+            interval: Duration::from_secs(1) / requests_per_second,
+            next_slot: Arc::new(Mutex::new(Instant::now())),
+        });
+
+        Self { permits, pacing }
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) -> Result<RequestPermit, Error> {
+        let permit = match &self.permits {
+            Some(permits) => Some(permits.clone().acquire_owned().await.map_err(|_| {
+                Error::Unknown(anyhow::anyhow!("failed to acquire permit to send request"))
+            })?),
+            None => None,
+        };
+
+        if let Some(pacing) = &self.pacing {
+            let slot = {
+                let mut next_slot = pacing.next_slot.lock();
+
+                let slot = (*next_slot).max(Instant::now());
+                *next_slot = slot + pacing.interval;
+
+                slot
+            };
+
+            sleep_until(slot).await;
+        }
+
+        Ok(RequestPermit { _permit: permit })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::{timeout, Instant};
+
+    use super::RequestScheduler;
+
+    #[tokio::test]
+    async fn acquire_limits_concurrency() {
+        let scheduler = RequestScheduler::new(Some(1), None);
+
+        let permit = scheduler.acquire().await.unwrap();
+        let blocked = timeout(Duration::from_millis(10), scheduler.acquire()).await;
+        assert!(blocked.is_err());
+
+        drop(permit);
+        let unblocked = timeout(Duration::from_millis(10), scheduler.acquire()).await;
+        assert!(unblocked.is_ok());
+    }
+
+    #[tokio::test]
+    async fn acquire_paces_requests() {
+        let scheduler = RequestScheduler::new(None, Some(20));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            scheduler.acquire().await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn acquire_without_limits() {
+        let scheduler = RequestScheduler::default();
+
+        let first = scheduler.acquire().await;
+        let second = timeout(Duration::from_millis(10), scheduler.acquire()).await;
+
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RequestScheduler>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RequestScheduler>();
+    }
+}
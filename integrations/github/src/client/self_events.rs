@@ -0,0 +1,142 @@
+use tokio::sync::OnceCell;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::event::GitHubEvent;
+use crate::resource::{AccountType, App};
+use crate::task::GetApp;
+
+/// Guard against events that the GitHub App triggered itself
+///
+/// Automatons that comment on issues, update check runs, or edit other resources receive webhooks
+/// for their own changes. An automaton that reacts to these events can trigger itself in an
+/// endless loop. The [`SelfEventGuard`] compares the sender of an event with the app's bot user,
+/// and skips the events that the app triggered itself.
+///
+/// The app is fetched from GitHub the first time that it is needed, and then cached for the
+/// lifetime of the guard.
+#[derive(Debug)]
+pub struct SelfEventGuard {
+    github_client: GitHubClient,
+    app: OnceCell<App>,
+}
+
+impl SelfEventGuard {
+    /// Initializes a guard that fetches the app with the client
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self {
+            github_client,
+            app: OnceCell::new(),
+        }
+    }
+
+    /// Sets the app, so that the guard does not need to fetch it from GitHub.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn with_app(mut self, app: App) -> Self {
+        self.app = OnceCell::new_with(Some(app));
+        self
+    }
+
+    /// Returns the app, and fetches it from GitHub if it has not been fetched yet.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn app(&self) -> Result<&App, Error> {
+        self.app
+            .get_or_try_init(|| async { GetApp::new(&self.github_client).execute().await })
+            .await
+    }
+
+    /// Indicates whether the event was triggered by the app itself.
+    ///
+    /// Events without a sender were triggered by GitHub, and never by the app.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn is_self_event(&self, event: &GitHubEvent) -> Result<bool, Error> {
+        let sender = match event.sender() {
+            Some(sender) if sender.account_type() == AccountType::Bot => sender,
+            _ => return Ok(false),
+        };
+
+        Ok(sender.login() == &self.app().await?.bot_login())
+    }
+
+    /// Indicates whether the event should be handled, i.e. was not triggered by the app itself.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn is_event_selected(&self, event: &GitHubEvent) -> Result<bool, Error> {
+        Ok(!self.is_self_event(event).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+    use crate::resource::App;
+    use crate::testing::app::mock_get_app;
+    use crate::testing::client::github_client;
+
+    use super::SelfEventGuard;
+
+    fn app() -> App {
+        serde_json::from_str(include_str!("../../tests/fixtures/resource/app.json")).unwrap()
+    }
+
+    fn event_from(login: &str, account_type: &str) -> GitHubEvent {
+        let mut payload: Value = serde_json::from_slice(include_bytes!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+
+        payload["sender"]["login"] = login.into();
+        payload["sender"]["type"] = account_type.into();
+
+        deserialize_event(
+            &GitHubEventKind::PullRequest,
+            &serde_json::to_vec(&payload).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn is_self_event_fetches_app() {
+        let _app_mock = mock_get_app();
+
+        let guard = SelfEventGuard::new(github_client());
+
+        assert!(guard
+            .is_self_event(&event_from("devxbots-checkbot[bot]", "Bot"))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn is_event_selected_skips_self_events() {
+        let guard = SelfEventGuard::new(github_client()).with_app(app());
+
+        assert!(!guard
+            .is_event_selected(&event_from("devxbots-checkbot[bot]", "Bot"))
+            .await
+            .unwrap());
+        assert!(guard
+            .is_event_selected(&event_from("dependabot[bot]", "Bot"))
+            .await
+            .unwrap());
+        assert!(guard
+            .is_event_selected(&event_from("octocat", "User"))
+            .await
+            .unwrap());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SelfEventGuard>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SelfEventGuard>();
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::resource::{Account, BranchProtectionRule, Installation, Organization, Repository};
+
+/// Branch protection rule action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchProtectionRuleAction {
+    /// A branch protection rule was created.
+    Created,
+
+    /// A branch protection rule was deleted.
+    Deleted,
+
+    /// A branch protection rule was changed.
+    Edited,
+}
+
+/// Branch protection rule event
+///
+/// A branch protection rule event is sent when a branch protection rule is created, changed, or
+/// deleted. Changed rules contain the previous value of every setting that was changed, which
+/// makes it possible to detect and revert rules that were weakened.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BranchProtectionRuleEvent {
+    action: BranchProtectionRuleAction,
+    rule: BranchProtectionRule,
+    #[serde(default)]
+    changes: BTreeMap<String, BranchProtectionRuleChange>,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct BranchProtectionRuleChange {
+    from: Value,
+}
+
+impl BranchProtectionRuleEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> BranchProtectionRuleAction {
+        self.action
+    }
+
+    /// Returns the event's branch protection rule.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rule(&self) -> &BranchProtectionRule {
+        &self.rule
+    }
+
+    /// Returns the names of the settings that were changed, e.g. `admin_enforced`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn changed_settings(&self) -> Vec<&str> {
+        self.changes.keys().map(String::as_str).collect()
+    }
+
+    /// Returns the previous value of a setting, if it was changed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn previous_value(&self, setting: &str) -> Option<&Value> {
+        self.changes.get(setting).map(|change| &change.from)
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for BranchProtectionRuleAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            BranchProtectionRuleAction::Created => "created",
+            BranchProtectionRuleAction::Deleted => "deleted",
+            BranchProtectionRuleAction::Edited => "edited",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for BranchProtectionRuleEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {} ({})",
+            self.rule,
+            self.repository.full_name(),
+            self.action
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::{BranchProtectionRuleAction, BranchProtectionRuleEvent};
+
+    fn event() -> BranchProtectionRuleEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/branch_protection_rule.edited.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), BranchProtectionRuleAction::Edited));
+        assert!(!event.rule().admin_enforced());
+    }
+
+    #[test]
+    fn previous_value() {
+        let event = event();
+
+        assert_eq!(vec!["admin_enforced"], event.changed_settings());
+        assert_eq!(
+            Some(&Value::Bool(true)),
+            event.previous_value("admin_enforced")
+        );
+        assert!(event.previous_value("required_status_checks").is_none());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("main in devxbots/automatons (edited)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<BranchProtectionRuleEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<BranchProtectionRuleEvent>();
+    }
+}
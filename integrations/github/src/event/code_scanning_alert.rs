@@ -0,0 +1,157 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{
+    Account, CodeScanningAlert, GitRef, GitSha, Installation, Organization, Repository,
+};
+
+/// Code scanning alert action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeScanningAlertAction {
+    /// A previously created code scanning alert appeared in another branch.
+    AppearedInBranch,
+
+    /// Someone closed a code scanning alert.
+    ClosedByUser,
+
+    /// A code scanning alert was created in a repository.
+    Created,
+
+    /// A code scanning alert was fixed in a branch by a commit.
+    Fixed,
+
+    /// A previously fixed code scanning alert reappeared.
+    Reopened,
+
+    /// Someone reopened a code scanning alert.
+    ReopenedByUser,
+}
+
+/// Code scanning alert event
+///
+/// A code scanning alert event contains the action that occurred, the latest state of the alert,
+/// and the Git reference and commit for which the alert was reported.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CodeScanningAlertEvent {
+    action: CodeScanningAlertAction,
+    alert: CodeScanningAlert,
+    #[serde(rename = "ref")]
+    git_ref: GitRef,
+    commit_oid: GitSha,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl CodeScanningAlertEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> CodeScanningAlertAction {
+        self.action
+    }
+
+    /// Returns the event's alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn alert(&self) -> &CodeScanningAlert {
+        &self.alert
+    }
+
+    /// Returns the Git reference of the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn git_ref(&self) -> &GitRef {
+        &self.git_ref
+    }
+
+    /// Returns the SHA of the commit for which the alert was reported.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn commit_oid(&self) -> &GitSha {
+        &self.commit_oid
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for CodeScanningAlertAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CodeScanningAlertAction::AppearedInBranch => "appeared in branch",
+            CodeScanningAlertAction::ClosedByUser => "closed by user",
+            CodeScanningAlertAction::Created => "created",
+            CodeScanningAlertAction::Fixed => "fixed",
+            CodeScanningAlertAction::Reopened => "reopened",
+            CodeScanningAlertAction::ReopenedByUser => "reopened by user",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for CodeScanningAlertEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.alert, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeScanningAlertAction, CodeScanningAlertEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: CodeScanningAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/code_scanning_alert.created.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), CodeScanningAlertAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: CodeScanningAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/code_scanning_alert.created.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#3 (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningAlertEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningAlertEvent>();
+    }
+}
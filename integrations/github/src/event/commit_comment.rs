@@ -0,0 +1,122 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, CommitComment, Installation, Organization, Repository};
+
+/// Commit comment action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitCommentAction {
+    /// Someone commented on a commit.
+    Created,
+}
+
+/// Commit comment event
+///
+/// A commit comment event is sent when someone comments on a commit. It contains the comment and
+/// the repository of the commit. If the webhook was configured for an organization, or if the
+/// repository is owned by one, the organization is included in the payload. If the event is sent to
+/// a GitHub App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CommitCommentEvent {
+    action: CommitCommentAction,
+    comment: CommitComment,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl CommitCommentEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> CommitCommentAction {
+        self.action
+    }
+
+    /// Returns the event's comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn comment(&self) -> &CommitComment {
+        &self.comment
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for CommitCommentAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CommitCommentAction::Created => "created",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for CommitCommentEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.comment, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommitCommentAction, CommitCommentEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: CommitCommentEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/commit_comment.created.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), CommitCommentAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: CommitCommentEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/commit_comment.created.json"
+        ))
+        .unwrap();
+
+        assert_eq!("84173620 (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CommitCommentEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CommitCommentEvent>();
+    }
+}
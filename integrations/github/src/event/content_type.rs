@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use url::form_urlencoded;
+
+use automatons::Error;
+
+/// Content type of a webhook delivery
+///
+/// Webhooks on GitHub can be configured to deliver their payloads with one of two content types.
+/// With `application/json`, the JSON payload is sent as the body of the request. With
+/// `application/x-www-form-urlencoded`, the JSON payload is sent as a form parameter called
+/// `payload`. The content type of a delivery is sent in the `Content-Type` header.
+///
+/// Read more: https://docs.github.com/en/developers/webhooks-and-events/webhooks/creating-webhooks#content-type
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum WebhookContentType {
+    /// JSON payload in the request body
+    #[default]
+    Json,
+
+    /// JSON payload in the `payload` form parameter
+    Form,
+}
+
+impl WebhookContentType {
+    /// Returns the JSON payload from the body of a webhook delivery.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(body)))]
+    pub fn payload<'a>(&self, body: &'a [u8]) -> Result<Cow<'a, [u8]>, Error> {
+        match self {
+            WebhookContentType::Json => Ok(Cow::Borrowed(body)),
+            WebhookContentType::Form => form_urlencoded::parse(body)
+                .find(|(key, _)| key == "payload")
+                .map(|(_, payload)| Cow::Owned(payload.into_owned().into_bytes()))
+                .ok_or_else(|| {
+                    Error::Serialization("failed to find payload in form-encoded body".into())
+                }),
+        }
+    }
+}
+
+impl FromStr for WebhookContentType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Ignore parameters like the charset, e.g. `application/json; charset=utf-8`
+        let mime_type = s.split(';').next().unwrap_or_default().trim();
+
+        match mime_type.to_ascii_lowercase().as_str() {
+            "application/json" => Ok(WebhookContentType::Json),
+            "application/x-www-form-urlencoded" => Ok(WebhookContentType::Form),
+            _ => Err(Error::Serialization(format!(
+                "failed to handle unsupported content type {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for WebhookContentType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            WebhookContentType::Json => "application/json",
+            WebhookContentType::Form => "application/x-www-form-urlencoded",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use automatons::Error;
+
+    use super::WebhookContentType;
+
+    #[test]
+    fn from_str_with_charset() {
+        let content_type = WebhookContentType::from_str("application/json; charset=utf-8").unwrap();
+
+        assert_eq!(WebhookContentType::Json, content_type);
+    }
+
+    #[test]
+    fn from_str_form() {
+        let content_type =
+            WebhookContentType::from_str("application/x-www-form-urlencoded").unwrap();
+
+        assert_eq!(WebhookContentType::Form, content_type);
+    }
+
+    #[test]
+    fn from_str_unsupported() {
+        let error = WebhookContentType::from_str("text/plain").unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
+    #[test]
+    fn payload_json() {
+        let body = br#"{"action":"created"}"#;
+
+        let payload = WebhookContentType::Json.payload(body).unwrap();
+
+        assert_eq!(&body[..], &payload[..]);
+    }
+
+    #[test]
+    fn payload_form() {
+        let body = b"payload=%7B%22action%22%3A%22created%22%7D";
+
+        let payload = WebhookContentType::Form.payload(body).unwrap();
+
+        assert_eq!(br#"{"action":"created"}"#, &payload[..]);
+    }
+
+    #[test]
+    fn payload_form_without_payload() {
+        let error = WebhookContentType::Form
+            .payload(b"other=value")
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(
+            "application/x-www-form-urlencoded",
+            WebhookContentType::Form.to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WebhookContentType>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WebhookContentType>();
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{
+    Account, GitRef, GitRefType, Installation, Organization, PusherType, Repository,
+};
+
+/// Delete event
+///
+/// A delete event is sent when a branch or tag is deleted in a repository. The payload contains the
+/// reference and its type. If the webhook was configured for an organization, or if the repository
+/// is owned by one, the organization is included in the payload. If the event is sent to a GitHub
+/// App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeleteEvent {
+    #[serde(rename = "ref")]
+    git_ref: GitRef,
+    ref_type: GitRefType,
+    pusher_type: PusherType,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl DeleteEvent {
+    /// Returns the reference that was deleted.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn git_ref(&self) -> &GitRef {
+        &self.git_ref
+    }
+
+    /// Returns the type of the reference.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn ref_type(&self) -> GitRefType {
+        self.ref_type
+    }
+
+    /// Returns the type of the pusher.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pusher_type(&self) -> PusherType {
+        self.pusher_type
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for DeleteEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.ref_type, self.git_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::GitRefType;
+
+    use super::DeleteEvent;
+
+    #[test]
+    fn trait_deserialize() {
+        let event: DeleteEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/delete.json")).unwrap();
+
+        assert!(matches!(event.ref_type(), GitRefType::Branch));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: DeleteEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/delete.json")).unwrap();
+
+        assert_eq!("branch preview/new-docs", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DeleteEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DeleteEvent>();
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, DependabotAlert, Installation, Organization, Repository};
+
+/// Dependabot alert action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependabotAlertAction {
+    /// A Dependabot alert was automatically closed.
+    AutoDismissed,
+
+    /// A Dependabot alert, that had been automatically closed, was reopened.
+    AutoReopened,
+
+    /// A manifest file change introduced a vulnerable dependency, or a GitHub Security Advisory was
+    /// published and an existing dependency was found to be vulnerable.
+    Created,
+
+    /// A Dependabot alert was manually closed.
+    Dismissed,
+
+    /// A manifest file change removed a vulnerability.
+    Fixed,
+
+    /// A manifest file change introduced a vulnerable dependency that had previously been fixed.
+    Reintroduced,
+
+    /// A Dependabot alert was manually reopened.
+    Reopened,
+}
+
+/// Dependabot alert event
+///
+/// A Dependabot alert event contains the action that occurred, the latest state of the alert, and
+/// the repository in which the alert was opened. If the webhook was configured for an organization,
+/// or if the repository is owned by one, the organization is included in the payload. If the event
+/// is sent to a GitHub App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DependabotAlertEvent {
+    action: DependabotAlertAction,
+    alert: DependabotAlert,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl DependabotAlertEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> DependabotAlertAction {
+        self.action
+    }
+
+    /// Returns the event's alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn alert(&self) -> &DependabotAlert {
+        &self.alert
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for DependabotAlertAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DependabotAlertAction::AutoDismissed => "auto dismissed",
+            DependabotAlertAction::AutoReopened => "auto reopened",
+            DependabotAlertAction::Created => "created",
+            DependabotAlertAction::Dismissed => "dismissed",
+            DependabotAlertAction::Fixed => "fixed",
+            DependabotAlertAction::Reintroduced => "reintroduced",
+            DependabotAlertAction::Reopened => "reopened",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for DependabotAlertEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.alert, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DependabotAlertAction, DependabotAlertEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: DependabotAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/dependabot_alert.created.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), DependabotAlertAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: DependabotAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/dependabot_alert.created.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#2 (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DependabotAlertEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DependabotAlertEvent>();
+    }
+}
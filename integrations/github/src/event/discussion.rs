@@ -0,0 +1,179 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Discussion, Installation, Organization, Repository};
+
+/// Discussion action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionAction {
+    /// A comment on the discussion was marked as the answer.
+    Answered,
+
+    /// The discussion's category was changed.
+    CategoryChanged,
+
+    /// The discussion was closed.
+    Closed,
+
+    /// A discussion was created.
+    Created,
+
+    /// A discussion was deleted.
+    Deleted,
+
+    /// The discussion's title or body was edited.
+    Edited,
+
+    /// A label was added to the discussion.
+    Labeled,
+
+    /// The discussion was locked.
+    Locked,
+
+    /// The discussion was pinned.
+    Pinned,
+
+    /// The discussion was reopened.
+    Reopened,
+
+    /// The discussion was transferred to another repository.
+    Transferred,
+
+    /// A comment on the discussion was unmarked as the answer.
+    Unanswered,
+
+    /// A label was removed from the discussion.
+    Unlabeled,
+
+    /// The discussion was unlocked.
+    Unlocked,
+
+    /// The discussion was unpinned.
+    Unpinned,
+}
+
+/// Discussion event
+///
+/// A discussion event is sent when a discussion is created or changed. It contains the action that
+/// occurred, the discussion, and the repository in which the discussion takes place. If the webhook
+/// was configured for an organization, or if the repository is owned by one, the organization is
+/// included in the payload. If the event is sent to a GitHub App, the payload contains the
+/// installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DiscussionEvent {
+    action: DiscussionAction,
+    discussion: Discussion,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl DiscussionEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> DiscussionAction {
+        self.action
+    }
+
+    /// Returns the event's discussion.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn discussion(&self) -> &Discussion {
+        &self.discussion
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for DiscussionAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DiscussionAction::Answered => "answered",
+            DiscussionAction::CategoryChanged => "category changed",
+            DiscussionAction::Closed => "closed",
+            DiscussionAction::Created => "created",
+            DiscussionAction::Deleted => "deleted",
+            DiscussionAction::Edited => "edited",
+            DiscussionAction::Labeled => "labeled",
+            DiscussionAction::Locked => "locked",
+            DiscussionAction::Pinned => "pinned",
+            DiscussionAction::Reopened => "reopened",
+            DiscussionAction::Transferred => "transferred",
+            DiscussionAction::Unanswered => "unanswered",
+            DiscussionAction::Unlabeled => "unlabeled",
+            DiscussionAction::Unlocked => "unlocked",
+            DiscussionAction::Unpinned => "unpinned",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for DiscussionEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.discussion, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiscussionAction, DiscussionEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: DiscussionEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/discussion.created.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), DiscussionAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: DiscussionEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/discussion.created.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#90 (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DiscussionEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DiscussionEvent>();
+    }
+}
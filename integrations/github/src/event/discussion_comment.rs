@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{
+    Account, Discussion, DiscussionComment, Installation, Organization, Repository,
+};
+
+/// Discussion comment action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionCommentAction {
+    /// A comment on a discussion was created.
+    Created,
+
+    /// A comment on a discussion was deleted.
+    Deleted,
+
+    /// A comment on a discussion was edited.
+    Edited,
+}
+
+/// Discussion comment event
+///
+/// A discussion comment event is sent when a comment on a discussion is created, edited, or deleted.
+/// It contains the comment, the discussion, and the repository in which the discussion takes place.
+/// If the webhook was configured for an organization, or if the repository is owned by one, the
+/// organization is included in the payload. If the event is sent to a GitHub App, the payload
+/// contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DiscussionCommentEvent {
+    action: DiscussionCommentAction,
+    comment: DiscussionComment,
+    discussion: Discussion,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl DiscussionCommentEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> DiscussionCommentAction {
+        self.action
+    }
+
+    /// Returns the event's comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn comment(&self) -> &DiscussionComment {
+        &self.comment
+    }
+
+    /// Returns the event's discussion.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn discussion(&self) -> &Discussion {
+        &self.discussion
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for DiscussionCommentAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DiscussionCommentAction::Created => "created",
+            DiscussionCommentAction::Deleted => "deleted",
+            DiscussionCommentAction::Edited => "edited",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for DiscussionCommentEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} on {} ({})",
+            self.comment, self.discussion, self.action
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiscussionCommentAction, DiscussionCommentEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: DiscussionCommentEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/discussion_comment.created.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), DiscussionCommentAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: DiscussionCommentEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/discussion_comment.created.json"
+        ))
+        .unwrap();
+
+        assert_eq!("3661357 on #90 (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DiscussionCommentEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DiscussionCommentEvent>();
+    }
+}
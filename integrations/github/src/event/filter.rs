@@ -0,0 +1,364 @@
+use crate::event::GitHubEvent;
+use crate::resource::{AccountType, Field, GitRefType, LabelName, Login};
+
+/// Prefix of the full ref of a branch
+const BRANCH_PREFIX: &str = "refs/heads/";
+
+/// Filter for the events that an automaton reacts to
+///
+/// Most automatons only care about a subset of the events that a GitHub App receives, e.g. pull
+/// requests that target the default branch of a few repositories. The [`EventFilter`] bundles these
+/// guards so that they can be declared once when an automaton is registered, instead of being
+/// re-implemented at the start of its first task.
+///
+/// Every condition can be given more than once, in which case an event must match any of the given
+/// values. An event must match all conditions that have been given. Events that do not contain the
+/// information a condition needs, e.g. a branch for a star event, do not match that condition.
+///
+/// Repositories and branches are matched against glob patterns, in which `*` matches any number of
+/// characters and `?` matches a single character. Repositories are matched by their full name,
+/// e.g. `devxbots/*`, and branches by their name without the `refs/heads/` prefix.
+///
+/// ```
+/// use automatons_github::event::EventFilter;
+/// use automatons_github::resource::Login;
+///
+/// let filter = EventFilter::new()
+///     .organization(Login::new("devxbots"))
+///     .branch("main")
+///     .action("opened")
+///     .action("synchronize")
+///     .ignore_bots();
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct EventFilter {
+    repositories: Vec<String>,
+    organizations: Vec<Login>,
+    branches: Vec<String>,
+    actions: Vec<String>,
+    labels: Vec<LabelName>,
+    ignore_bots: bool,
+}
+
+impl EventFilter {
+    /// Initializes a filter that matches every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches events for repositories whose full name matches the glob pattern.
+    pub fn repository(mut self, pattern: &str) -> Self {
+        self.repositories.push(pattern.into());
+        self
+    }
+
+    /// Only matches events for repositories that are owned by the organization.
+    pub fn organization(mut self, login: Login) -> Self {
+        self.organizations.push(login);
+        self
+    }
+
+    /// Only matches events for branches whose name matches the glob pattern.
+    ///
+    /// The branch of a pull request event is its base branch. Create and delete events for tags
+    /// have no branch.
+    pub fn branch(mut self, pattern: &str) -> Self {
+        self.branches.push(pattern.into());
+        self
+    }
+
+    /// Only matches events with the action, e.g. `opened` or `review_requested`.
+    pub fn action(mut self, action: &str) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    /// Only matches events for pull requests that have the label.
+    pub fn label(mut self, label: LabelName) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Skips events that were triggered by bots, including the GitHub App itself.
+    pub fn ignore_bots(mut self) -> Self {
+        self.ignore_bots = true;
+        self
+    }
+
+    /// Indicates whether the event matches all conditions of the filter.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn matches(&self, event: &GitHubEvent) -> bool {
+        self.matches_sender(event)
+            && self.matches_repository(event)
+            && self.matches_organization(event)
+            && self.matches_branch(event)
+            && self.matches_action(event)
+            && self.matches_label(event)
+    }
+
+    fn matches_sender(&self, event: &GitHubEvent) -> bool {
+        !self.ignore_bots
+            || event
+                .sender()
+                .is_none_or(|sender| sender.account_type() != AccountType::Bot)
+    }
+
+    fn matches_repository(&self, event: &GitHubEvent) -> bool {
+        if self.repositories.is_empty() {
+            return true;
+        }
+
+        event.repository().is_some_and(|repository| {
+            self.repositories
+                .iter()
+                .any(|pattern| glob_matches(pattern, repository.full_name().get()))
+        })
+    }
+
+    fn matches_organization(&self, event: &GitHubEvent) -> bool {
+        if self.organizations.is_empty() {
+            return true;
+        }
+
+        let owner = event
+            .organization()
+            .map(|organization| organization.login())
+            .or_else(|| {
+                event
+                    .repository()
+                    .map(|repository| repository.owner())
+                    .filter(|owner| owner.account_type() == AccountType::Organization)
+                    .map(|owner| owner.login())
+            });
+
+        owner.is_some_and(|owner| self.organizations.contains(owner))
+    }
+
+    fn matches_branch(&self, event: &GitHubEvent) -> bool {
+        if self.branches.is_empty() {
+            return true;
+        }
+
+        branches(event).iter().any(|branch| {
+            self.branches
+                .iter()
+                .any(|pattern| glob_matches(pattern, branch))
+        })
+    }
+
+    fn matches_action(&self, event: &GitHubEvent) -> bool {
+        if self.actions.is_empty() {
+            return true;
+        }
+
+        event
+            .action()
+            .is_some_and(|action| self.actions.contains(&action))
+    }
+
+    fn matches_label(&self, event: &GitHubEvent) -> bool {
+        if self.labels.is_empty() {
+            return true;
+        }
+
+        let labels = match event {
+            GitHubEvent::PullRequest(event) => event.pull_request().labels(),
+            _ => return false,
+        };
+
+        labels
+            .iter()
+            .any(|label| self.labels.contains(label.name()))
+    }
+}
+
+fn branches(event: &GitHubEvent) -> Vec<&str> {
+    let branch = match event {
+        GitHubEvent::CheckRun(event) => match event.check_run().check_suite() {
+            Field::Full(check_suite) => check_suite.head_branch().get(),
+            Field::Minimal(_) => return Vec::new(),
+        },
+        GitHubEvent::Create(event) if event.ref_type() == GitRefType::Branch => {
+            event.git_ref().get()
+        }
+        GitHubEvent::Delete(event) if event.ref_type() == GitRefType::Branch => {
+            event.git_ref().get()
+        }
+        GitHubEvent::MergeGroup(event) => event.merge_group().base_ref().get(),
+        GitHubEvent::PullRequest(event) => event.pull_request().base().git_ref().get(),
+        GitHubEvent::Status(event) => return event.branches(),
+        _ => return Vec::new(),
+    };
+
+    vec![branch.strip_prefix(BRANCH_PREFIX).unwrap_or(branch)]
+}
+
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, position)) => {
+                    p = star + 1;
+                    t = position + 1;
+                    backtrack = Some((star, position + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+    use crate::resource::{LabelName, Login};
+
+    use super::{glob_matches, EventFilter};
+
+    fn pull_request_event() -> GitHubEvent {
+        let payload =
+            include_bytes!("../../tests/fixtures/event/pull_request.review_requested.json");
+        deserialize_event(&GitHubEventKind::PullRequest, payload).unwrap()
+    }
+
+    fn star_event() -> GitHubEvent {
+        let payload = include_bytes!("../../tests/fixtures/event/star.created.json");
+        deserialize_event(&GitHubEventKind::Star, payload).unwrap()
+    }
+
+    #[test]
+    fn matches_without_conditions() {
+        assert!(EventFilter::new().matches(&pull_request_event()));
+    }
+
+    #[test]
+    fn matches_repository() {
+        let event = pull_request_event();
+
+        assert!(EventFilter::new().repository("devxbots/*").matches(&event));
+        assert!(EventFilter::new()
+            .repository("github/*")
+            .repository("devxbots/automatons")
+            .matches(&event));
+        assert!(!EventFilter::new().repository("github/*").matches(&event));
+    }
+
+    #[test]
+    fn matches_organization() {
+        let event = pull_request_event();
+
+        assert!(EventFilter::new()
+            .organization(Login::new("devxbots"))
+            .matches(&event));
+        assert!(!EventFilter::new()
+            .organization(Login::new("github"))
+            .matches(&event));
+    }
+
+    #[test]
+    fn matches_branch() {
+        let event = pull_request_event();
+
+        assert!(EventFilter::new().branch("main").matches(&event));
+        assert!(!EventFilter::new().branch("release/*").matches(&event));
+        assert!(!EventFilter::new().branch("main").matches(&star_event()));
+    }
+
+    #[test]
+    fn matches_action() {
+        let event = pull_request_event();
+
+        assert!(EventFilter::new()
+            .action("opened")
+            .action("review_requested")
+            .matches(&event));
+        assert!(!EventFilter::new().action("opened").matches(&event));
+    }
+
+    #[test]
+    fn matches_label() {
+        let event = pull_request_event();
+
+        assert!(EventFilter::new()
+            .label(LabelName::new("dependencies"))
+            .matches(&event));
+        assert!(!EventFilter::new()
+            .label(LabelName::new("bug"))
+            .matches(&event));
+        assert!(!EventFilter::new()
+            .label(LabelName::new("dependencies"))
+            .matches(&star_event()));
+    }
+
+    #[test]
+    fn ignore_bots() {
+        let mut payload: Value = serde_json::from_slice(include_bytes!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+        let filter = EventFilter::new().ignore_bots();
+
+        assert!(filter.matches(&pull_request_event()));
+
+        payload["sender"]["type"] = "Bot".into();
+        let event = deserialize_event(
+            &GitHubEventKind::PullRequest,
+            &serde_json::to_vec(&payload).unwrap(),
+        )
+        .unwrap();
+
+        assert!(!filter.matches(&event));
+    }
+
+    #[test]
+    fn matches_all_conditions() {
+        let filter = EventFilter::new()
+            .repository("devxbots/automatons")
+            .action("opened");
+
+        assert!(!filter.matches(&pull_request_event()));
+    }
+
+    #[test]
+    fn glob_matches_patterns() {
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("main", "main"));
+        assert!(glob_matches("release/*", "release/1.0"));
+        assert!(glob_matches("v?.*", "v1.0"));
+        assert!(glob_matches("*/automatons", "devxbots/automatons"));
+        assert!(glob_matches("a*b*c", "aXbYbZc"));
+        assert!(!glob_matches("main", "mainline"));
+        assert!(!glob_matches("release/*", "main"));
+        assert!(!glob_matches("v?", "v10"));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<EventFilter>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<EventFilter>();
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository};
+
+/// Fork event
+///
+/// A fork event is sent when someone forks a repository. The payload contains the newly created
+/// fork as the `forkee`, and the repository that was forked. If the webhook was configured for an
+/// organization, or if the repository is owned by one, the organization is included in the payload.
+/// If the event is sent to a GitHub App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ForkEvent {
+    forkee: Repository,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl ForkEvent {
+    /// Returns the repository that was created by the fork.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn forkee(&self) -> &Repository {
+        &self.forkee
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for ForkEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.forkee.full_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForkEvent;
+
+    #[test]
+    fn trait_deserialize() {
+        let event: ForkEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/fork.json")).unwrap();
+
+        assert!(event.forkee().fork());
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: ForkEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/fork.json")).unwrap();
+
+        assert_eq!("octocat/automatons", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ForkEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ForkEvent>();
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, InstallationRepository};
+
+/// Installation action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallationAction {
+    /// Someone installed the app.
+    Created,
+
+    /// Someone uninstalled the app.
+    Deleted,
+
+    /// Someone accepted new permissions for the app.
+    NewPermissionsAccepted,
+
+    /// Someone suspended the installation.
+    Suspend,
+
+    /// Someone unsuspended the installation.
+    Unsuspend,
+}
+
+/// Installation event
+///
+/// An installation event is sent when a GitHub App is installed or uninstalled, when its new
+/// permissions are accepted, and when the installation is suspended or unsuspended. Suspended
+/// installations cannot request access tokens, so events for them should not be processed until
+/// they have been unsuspended.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstallationEvent {
+    action: InstallationAction,
+    installation: Installation,
+    #[serde(default)]
+    repositories: Vec<InstallationRepository>,
+    requester: Option<Account>,
+    sender: Account,
+}
+
+impl InstallationEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> InstallationAction {
+        self.action
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Installation {
+        &self.installation
+    }
+
+    /// Returns the repositories that the installation can access.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repositories(&self) -> &Vec<InstallationRepository> {
+        &self.repositories
+    }
+
+    /// Returns the user who requested the installation, if it needed an approval.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requester(&self) -> &Option<Account> {
+        &self.requester
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for InstallationAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            InstallationAction::Created => "created",
+            InstallationAction::Deleted => "deleted",
+            InstallationAction::NewPermissionsAccepted => "new permissions accepted",
+            InstallationAction::Suspend => "suspend",
+            InstallationAction::Unsuspend => "unsuspend",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for InstallationEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.installation, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InstallationAction, InstallationEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: InstallationEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/installation.suspend.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), InstallationAction::Suspend));
+        assert!(event.installation().is_suspended());
+        assert_eq!(1, event.repositories().len());
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: InstallationEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/installation.suspend.json"
+        ))
+        .unwrap();
+
+        assert_eq!("25802826 (suspend)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<InstallationEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<InstallationEvent>();
+    }
+}
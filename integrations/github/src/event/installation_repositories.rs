@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, InstallationRepository, RepositorySelection};
+
+/// Installation repositories action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallationRepositoriesAction {
+    /// Repositories were added to the installation.
+    Added,
+
+    /// Repositories were removed from the installation.
+    Removed,
+}
+
+/// Installation repositories event
+///
+/// An installation repositories event is sent when a user changes which repositories an
+/// installation can access. Removed repositories are no longer accessible to the app, and requests
+/// for them fail with a `404 Not Found`. When the user grants access to all repositories, the
+/// repository selection changes to `all`.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstallationRepositoriesEvent {
+    action: InstallationRepositoriesAction,
+    installation: Installation,
+    repository_selection: RepositorySelection,
+    repositories_added: Vec<InstallationRepository>,
+    repositories_removed: Vec<InstallationRepository>,
+    requester: Option<Account>,
+    sender: Account,
+}
+
+impl InstallationRepositoriesEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> InstallationRepositoriesAction {
+        self.action
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Installation {
+        &self.installation
+    }
+
+    /// Returns the installation's repository selection after the change.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository_selection(&self) -> RepositorySelection {
+        self.repository_selection
+    }
+
+    /// Returns the repositories that were added to the installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repositories_added(&self) -> &Vec<InstallationRepository> {
+        &self.repositories_added
+    }
+
+    /// Returns the repositories that were removed from the installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repositories_removed(&self) -> &Vec<InstallationRepository> {
+        &self.repositories_removed
+    }
+
+    /// Returns the user who requested the change, if it needed an approval.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requester(&self) -> &Option<Account> {
+        &self.requester
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for InstallationRepositoriesAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            InstallationRepositoriesAction::Added => "added",
+            InstallationRepositoriesAction::Removed => "removed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for InstallationRepositoriesEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.installation, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InstallationRepositoriesAction, InstallationRepositoriesEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: InstallationRepositoriesEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/installation_repositories.removed.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(
+            event.action(),
+            InstallationRepositoriesAction::Removed
+        ));
+        assert_eq!(
+            "devxbots/automatons",
+            event.repositories_removed()[0].full_name().get()
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: InstallationRepositoriesEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/installation_repositories.removed.json"
+        ))
+        .unwrap();
+
+        assert_eq!("25802826 (removed)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<InstallationRepositoriesEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<InstallationRepositoriesEvent>();
+    }
+}
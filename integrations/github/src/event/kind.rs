@@ -0,0 +1,414 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Kind of webhook event
+///
+/// GitHub sends the kind of a webhook event in the `X-GitHub-Event` header of the webhook request.
+/// The [`GitHubEventKind`] enumerates all documented webhook events, and preserves the name of
+/// events that are not (yet) known to this crate in the `Unknown` variant.
+///
+/// Read more: https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum GitHubEventKind {
+    /// Branch protection rule event
+    BranchProtectionRule,
+
+    /// Check run event
+    CheckRun,
+
+    /// Check suite event
+    CheckSuite,
+
+    /// Code scanning alert event
+    CodeScanningAlert,
+
+    /// Commit comment event
+    CommitComment,
+
+    /// Create event for branches and tags
+    Create,
+
+    /// Delete event for branches and tags
+    Delete,
+
+    /// Dependabot alert event
+    DependabotAlert,
+
+    /// Deploy key event
+    DeployKey,
+
+    /// Deployment event
+    Deployment,
+
+    /// Deployment protection rule event
+    DeploymentProtectionRule,
+
+    /// Deployment status event
+    DeploymentStatus,
+
+    /// Discussion event
+    Discussion,
+
+    /// Discussion comment event
+    DiscussionComment,
+
+    /// Fork event
+    Fork,
+
+    /// GitHub App authorization event
+    GitHubAppAuthorization,
+
+    /// Wiki event
+    Gollum,
+
+    /// Installation event
+    Installation,
+
+    /// Installation repositories event
+    InstallationRepositories,
+
+    /// Installation target event
+    InstallationTarget,
+
+    /// Issue comment event
+    IssueComment,
+
+    /// Issues event
+    Issues,
+
+    /// Label event
+    Label,
+
+    /// Marketplace purchase event
+    MarketplacePurchase,
+
+    /// Member event
+    Member,
+
+    /// Membership event
+    Membership,
+
+    /// Merge group event
+    MergeGroup,
+
+    /// Meta event
+    Meta,
+
+    /// Milestone event
+    Milestone,
+
+    /// Organization block event
+    OrgBlock,
+
+    /// Organization event
+    Organization,
+
+    /// Package event
+    Package,
+
+    /// Page build event
+    PageBuild,
+
+    /// Ping event
+    Ping,
+
+    /// Project event
+    Project,
+
+    /// Project card event
+    ProjectCard,
+
+    /// Project column event
+    ProjectColumn,
+
+    /// Projects (beta) item event
+    ProjectsV2Item,
+
+    /// Public event
+    Public,
+
+    /// Pull request event
+    PullRequest,
+
+    /// Pull request review event
+    PullRequestReview,
+
+    /// Pull request review comment event
+    PullRequestReviewComment,
+
+    /// Pull request review thread event
+    PullRequestReviewThread,
+
+    /// Push event
+    Push,
+
+    /// Registry package event
+    RegistryPackage,
+
+    /// Release event
+    Release,
+
+    /// Repository event
+    Repository,
+
+    /// Repository dispatch event
+    RepositoryDispatch,
+
+    /// Repository import event
+    RepositoryImport,
+
+    /// Repository vulnerability alert event
+    RepositoryVulnerabilityAlert,
+
+    /// Secret scanning alert event
+    SecretScanningAlert,
+
+    /// Secret scanning alert location event
+    SecretScanningAlertLocation,
+
+    /// Security advisory event
+    SecurityAdvisory,
+
+    /// Security and analysis event
+    SecurityAndAnalysis,
+
+    /// Sponsorship event
+    Sponsorship,
+
+    /// Star event
+    Star,
+
+    /// Status event
+    Status,
+
+    /// Team event
+    Team,
+
+    /// Team add event
+    TeamAdd,
+
+    /// Watch event
+    Watch,
+
+    /// Workflow dispatch event
+    WorkflowDispatch,
+
+    /// Workflow job event
+    WorkflowJob,
+
+    /// Workflow run event
+    WorkflowRun,
+
+    /// Event that is not known to this crate
+    Unknown(String),
+}
+
+impl GitHubEventKind {
+    /// Returns the name of the event as it is sent by GitHub.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn as_str(&self) -> &str {
+        match self {
+            GitHubEventKind::BranchProtectionRule => "branch_protection_rule",
+            GitHubEventKind::CheckRun => "check_run",
+            GitHubEventKind::CheckSuite => "check_suite",
+            GitHubEventKind::CodeScanningAlert => "code_scanning_alert",
+            GitHubEventKind::CommitComment => "commit_comment",
+            GitHubEventKind::Create => "create",
+            GitHubEventKind::Delete => "delete",
+            GitHubEventKind::DependabotAlert => "dependabot_alert",
+            GitHubEventKind::DeployKey => "deploy_key",
+            GitHubEventKind::Deployment => "deployment",
+            GitHubEventKind::DeploymentProtectionRule => "deployment_protection_rule",
+            GitHubEventKind::DeploymentStatus => "deployment_status",
+            GitHubEventKind::Discussion => "discussion",
+            GitHubEventKind::DiscussionComment => "discussion_comment",
+            GitHubEventKind::Fork => "fork",
+            GitHubEventKind::GitHubAppAuthorization => "github_app_authorization",
+            GitHubEventKind::Gollum => "gollum",
+            GitHubEventKind::Installation => "installation",
+            GitHubEventKind::InstallationRepositories => "installation_repositories",
+            GitHubEventKind::InstallationTarget => "installation_target",
+            GitHubEventKind::IssueComment => "issue_comment",
+            GitHubEventKind::Issues => "issues",
+            GitHubEventKind::Label => "label",
+            GitHubEventKind::MarketplacePurchase => "marketplace_purchase",
+            GitHubEventKind::Member => "member",
+            GitHubEventKind::Membership => "membership",
+            GitHubEventKind::MergeGroup => "merge_group",
+            GitHubEventKind::Meta => "meta",
+            GitHubEventKind::Milestone => "milestone",
+            GitHubEventKind::OrgBlock => "org_block",
+            GitHubEventKind::Organization => "organization",
+            GitHubEventKind::Package => "package",
+            GitHubEventKind::PageBuild => "page_build",
+            GitHubEventKind::Ping => "ping",
+            GitHubEventKind::Project => "project",
+            GitHubEventKind::ProjectCard => "project_card",
+            GitHubEventKind::ProjectColumn => "project_column",
+            GitHubEventKind::ProjectsV2Item => "projects_v2_item",
+            GitHubEventKind::Public => "public",
+            GitHubEventKind::PullRequest => "pull_request",
+            GitHubEventKind::PullRequestReview => "pull_request_review",
+            GitHubEventKind::PullRequestReviewComment => "pull_request_review_comment",
+            GitHubEventKind::PullRequestReviewThread => "pull_request_review_thread",
+            GitHubEventKind::Push => "push",
+            GitHubEventKind::RegistryPackage => "registry_package",
+            GitHubEventKind::Release => "release",
+            GitHubEventKind::Repository => "repository",
+            GitHubEventKind::RepositoryDispatch => "repository_dispatch",
+            GitHubEventKind::RepositoryImport => "repository_import",
+            GitHubEventKind::RepositoryVulnerabilityAlert => "repository_vulnerability_alert",
+            GitHubEventKind::SecretScanningAlert => "secret_scanning_alert",
+            GitHubEventKind::SecretScanningAlertLocation => "secret_scanning_alert_location",
+            GitHubEventKind::SecurityAdvisory => "security_advisory",
+            GitHubEventKind::SecurityAndAnalysis => "security_and_analysis",
+            GitHubEventKind::Sponsorship => "sponsorship",
+            GitHubEventKind::Star => "star",
+            GitHubEventKind::Status => "status",
+            GitHubEventKind::Team => "team",
+            GitHubEventKind::TeamAdd => "team_add",
+            GitHubEventKind::Watch => "watch",
+            GitHubEventKind::WorkflowDispatch => "workflow_dispatch",
+            GitHubEventKind::WorkflowJob => "workflow_job",
+            GitHubEventKind::WorkflowRun => "workflow_run",
+            GitHubEventKind::Unknown(name) => name,
+        }
+    }
+}
+
+impl From<&str> for GitHubEventKind {
+    fn from(name: &str) -> Self {
+        match name {
+            "branch_protection_rule" => GitHubEventKind::BranchProtectionRule,
+            "check_run" => GitHubEventKind::CheckRun,
+            "check_suite" => GitHubEventKind::CheckSuite,
+            "code_scanning_alert" => GitHubEventKind::CodeScanningAlert,
+            "commit_comment" => GitHubEventKind::CommitComment,
+            "create" => GitHubEventKind::Create,
+            "delete" => GitHubEventKind::Delete,
+            "dependabot_alert" => GitHubEventKind::DependabotAlert,
+            "deploy_key" => GitHubEventKind::DeployKey,
+            "deployment" => GitHubEventKind::Deployment,
+            "deployment_protection_rule" => GitHubEventKind::DeploymentProtectionRule,
+            "deployment_status" => GitHubEventKind::DeploymentStatus,
+            "discussion" => GitHubEventKind::Discussion,
+            "discussion_comment" => GitHubEventKind::DiscussionComment,
+            "fork" => GitHubEventKind::Fork,
+            "github_app_authorization" => GitHubEventKind::GitHubAppAuthorization,
+            "gollum" => GitHubEventKind::Gollum,
+            "installation" => GitHubEventKind::Installation,
+            "installation_repositories" => GitHubEventKind::InstallationRepositories,
+            "installation_target" => GitHubEventKind::InstallationTarget,
+            "issue_comment" => GitHubEventKind::IssueComment,
+            "issues" => GitHubEventKind::Issues,
+            "label" => GitHubEventKind::Label,
+            "marketplace_purchase" => GitHubEventKind::MarketplacePurchase,
+            "member" => GitHubEventKind::Member,
+            "membership" => GitHubEventKind::Membership,
+            "merge_group" => GitHubEventKind::MergeGroup,
+            "meta" => GitHubEventKind::Meta,
+            "milestone" => GitHubEventKind::Milestone,
+            "org_block" => GitHubEventKind::OrgBlock,
+            "organization" => GitHubEventKind::Organization,
+            "package" => GitHubEventKind::Package,
+            "page_build" => GitHubEventKind::PageBuild,
+            "ping" => GitHubEventKind::Ping,
+            "project" => GitHubEventKind::Project,
+            "project_card" => GitHubEventKind::ProjectCard,
+            "project_column" => GitHubEventKind::ProjectColumn,
+            "projects_v2_item" => GitHubEventKind::ProjectsV2Item,
+            "public" => GitHubEventKind::Public,
+            "pull_request" => GitHubEventKind::PullRequest,
+            "pull_request_review" => GitHubEventKind::PullRequestReview,
+            "pull_request_review_comment" => GitHubEventKind::PullRequestReviewComment,
+            "pull_request_review_thread" => GitHubEventKind::PullRequestReviewThread,
+            "push" => GitHubEventKind::Push,
+            "registry_package" => GitHubEventKind::RegistryPackage,
+            "release" => GitHubEventKind::Release,
+            "repository" => GitHubEventKind::Repository,
+            "repository_dispatch" => GitHubEventKind::RepositoryDispatch,
+            "repository_import" => GitHubEventKind::RepositoryImport,
+            "repository_vulnerability_alert" => GitHubEventKind::RepositoryVulnerabilityAlert,
+            "secret_scanning_alert" => GitHubEventKind::SecretScanningAlert,
+            "secret_scanning_alert_location" => GitHubEventKind::SecretScanningAlertLocation,
+            "security_advisory" => GitHubEventKind::SecurityAdvisory,
+            "security_and_analysis" => GitHubEventKind::SecurityAndAnalysis,
+            "sponsorship" => GitHubEventKind::Sponsorship,
+            "star" => GitHubEventKind::Star,
+            "status" => GitHubEventKind::Status,
+            "team" => GitHubEventKind::Team,
+            "team_add" => GitHubEventKind::TeamAdd,
+            "watch" => GitHubEventKind::Watch,
+            "workflow_dispatch" => GitHubEventKind::WorkflowDispatch,
+            "workflow_job" => GitHubEventKind::WorkflowJob,
+            "workflow_run" => GitHubEventKind::WorkflowRun,
+            unknown => GitHubEventKind::Unknown(unknown.into()),
+        }
+    }
+}
+
+impl FromStr for GitHubEventKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(GitHubEventKind::from(s))
+    }
+}
+
+impl Display for GitHubEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::GitHubEventKind;
+
+    #[test]
+    fn from_str_known_event() {
+        let kind = GitHubEventKind::from_str("check_run").unwrap();
+
+        assert_eq!(GitHubEventKind::CheckRun, kind);
+    }
+
+    #[test]
+    fn from_str_unknown_event() {
+        let kind = GitHubEventKind::from_str("future_event").unwrap();
+
+        assert_eq!(GitHubEventKind::Unknown("future_event".into()), kind);
+    }
+
+    #[test]
+    fn from_str_roundtrips_display() {
+        for name in [
+            "github_app_authorization",
+            "projects_v2_item",
+            "pull_request_review",
+        ] {
+            assert_eq!(name, GitHubEventKind::from(name).to_string());
+        }
+    }
+
+    #[test]
+    fn trait_display() {
+        let kind = GitHubEventKind::Unknown("future_event".into());
+
+        assert_eq!("future_event", kind.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GitHubEventKind>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GitHubEventKind>();
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository};
+
+/// Member action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberAction {
+    /// A collaborator was added to a repository.
+    Added,
+
+    /// The permissions of a collaborator were changed.
+    Edited,
+
+    /// A collaborator was removed from a repository.
+    Removed,
+}
+
+/// Member event
+///
+/// A member event is sent when a user is added as a collaborator to a repository, removed from
+/// it, or when their permissions change. If the repository is owned by an organization, the
+/// organization is included in the payload. If the event is sent to a GitHub App, the payload
+/// contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MemberEvent {
+    action: MemberAction,
+    member: Account,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl MemberEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> MemberAction {
+        self.action
+    }
+
+    /// Returns the collaborator who was added, edited, or removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn member(&self) -> &Account {
+        &self.member
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for MemberAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MemberAction::Added => "added",
+            MemberAction::Edited => "edited",
+            MemberAction::Removed => "removed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for MemberEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {} ({})",
+            self.member,
+            self.repository.full_name(),
+            self.action
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemberAction, MemberEvent};
+
+    fn event() -> MemberEvent {
+        serde_json::from_str(include_str!("../../tests/fixtures/event/member.added.json")).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), MemberAction::Added));
+        assert_eq!("octocat", event.member().login().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(
+            "octocat in devxbots/automatons (added)",
+            event().to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MemberEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MemberEvent>();
+    }
+}
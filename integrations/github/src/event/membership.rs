@@ -0,0 +1,125 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Team};
+
+/// Membership action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipAction {
+    /// A user was added to a team.
+    Added,
+
+    /// A user was removed from a team.
+    Removed,
+}
+
+/// Membership event
+///
+/// A membership event is sent when a user is added to or removed from a team. Despite its name,
+/// the event is not sent for changes to the membership in the organization, which trigger an
+/// [`OrganizationEvent`](crate::event::OrganizationEvent) instead.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MembershipEvent {
+    action: MembershipAction,
+    member: Account,
+    team: Team,
+    organization: Organization,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl MembershipEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> MembershipAction {
+        self.action
+    }
+
+    /// Returns the user who was added to or removed from the team.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn member(&self) -> &Account {
+        &self.member
+    }
+
+    /// Returns the event's team.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn team(&self) -> &Team {
+        &self.team
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for MembershipAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MembershipAction::Added => "added",
+            MembershipAction::Removed => "removed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for MembershipEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in {} ({})", self.member, self.team, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MembershipAction, MembershipEvent};
+
+    fn event() -> MembershipEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/membership.added.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), MembershipAction::Added));
+        assert_eq!("maintainers", event.team().slug().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("octocat in maintainers (added)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MembershipEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MembershipEvent>();
+    }
+}
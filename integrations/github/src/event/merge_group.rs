@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, MergeGroup, Organization, Repository};
+
+/// Merge group action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeGroupAction {
+    /// Checks were requested for the merge group's head commit.
+    ChecksRequested,
+
+    /// The merge group was merged or removed from the merge queue.
+    Destroyed,
+}
+
+/// Reason why a merge group was destroyed
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeGroupDestroyedReason {
+    /// The merge group was removed from the merge queue.
+    Dequeued,
+
+    /// The merge group became invalid, e.g. because the base branch changed.
+    Invalidated,
+
+    /// The merge group was merged into the base branch.
+    Merged,
+}
+
+/// Merge group event
+///
+/// A merge group event is sent when GitHub's merge queue requests checks for a merge group, and
+/// when the group is destroyed. Apps that report required checks must create check runs for the
+/// merge group's head commit, otherwise the merge queue cannot merge the group. If the event is
+/// sent to a GitHub App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MergeGroupEvent {
+    action: MergeGroupAction,
+    reason: Option<MergeGroupDestroyedReason>,
+    merge_group: MergeGroup,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl MergeGroupEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> MergeGroupAction {
+        self.action
+    }
+
+    /// Returns the reason why the merge group was destroyed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn reason(&self) -> Option<MergeGroupDestroyedReason> {
+        self.reason
+    }
+
+    /// Returns the event's merge group.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn merge_group(&self) -> &MergeGroup {
+        &self.merge_group
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for MergeGroupAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MergeGroupAction::ChecksRequested => "checks_requested",
+            MergeGroupAction::Destroyed => "destroyed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for MergeGroupDestroyedReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MergeGroupDestroyedReason::Dequeued => "dequeued",
+            MergeGroupDestroyedReason::Invalidated => "invalidated",
+            MergeGroupDestroyedReason::Merged => "merged",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for MergeGroupEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.merge_group.head_sha(), self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MergeGroupAction, MergeGroupDestroyedReason, MergeGroupEvent};
+
+    fn event() -> MergeGroupEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/merge_group.checks_requested.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), MergeGroupAction::ChecksRequested));
+        assert!(event.reason().is_none());
+        assert_eq!(
+            "d6fde92930d4715a2b49857d24b940956b26d2d3",
+            event.merge_group().base_sha().get()
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_destroyed() {
+        let mut json: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/merge_group.checks_requested.json"
+        ))
+        .unwrap();
+        json["action"] = "destroyed".into();
+        json["reason"] = "merged".into();
+
+        let event: MergeGroupEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!(Some(MergeGroupDestroyedReason::Merged), event.reason());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(
+            "ec26c3e57ca3a959ca5aad62de7213c562f8c821 (checks_requested)",
+            event().to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MergeGroupEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MergeGroupEvent>();
+    }
+}
@@ -5,15 +5,96 @@
 //! maps to a [webhook event](https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads)
 //! with its payload.
 //!
+//! # Compatibility
+//!
+//! GitHub adds fields to its payloads over time, and payloads that were queued or recorded before
+//! a change lack the new fields. Fields that are added to an existing event or resource must
+//! therefore be an [`Option`] or have `#[serde(default)]`, so that older payloads still
+//! deserialize. Unknown fields are ignored, so that newer payloads deserialize as well, but they
+//! are kept in the [`Preserved`] payload of the event and are serialized again when the event is
+//! passed on, e.g. through a queue.
+//!
+//! The fixtures in `tests/fixtures/event` contain the current payload of every supported event,
+//! and its subdirectories contain older versions of payloads, named by the date they were
+//! recorded. A test deserializes all of them, so that changes to the models cannot silently break
+//! older or newer payloads.
+//!
 //! [automatons]: https://github.com/devxbots/automatons
 
 use std::fmt::{Display, Formatter};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::resource::{
+    Account, Installation, InstallationId, Organization, Preserved, Repository, RepositoryId,
+};
 
+pub use self::branch_protection_rule::{BranchProtectionRuleAction, BranchProtectionRuleEvent};
 pub use self::check_run::{CheckRunAction, CheckRunEvent};
+pub use self::code_scanning_alert::{CodeScanningAlertAction, CodeScanningAlertEvent};
+pub use self::commit_comment::{CommitCommentAction, CommitCommentEvent};
+pub use self::content_type::WebhookContentType;
+pub use self::create::CreateEvent;
+pub use self::delete::DeleteEvent;
+pub use self::dependabot_alert::{DependabotAlertAction, DependabotAlertEvent};
+pub use self::discussion::{DiscussionAction, DiscussionEvent};
+pub use self::discussion_comment::{DiscussionCommentAction, DiscussionCommentEvent};
+pub use self::filter::EventFilter;
+pub use self::fork::ForkEvent;
+pub use self::installation::{InstallationAction, InstallationEvent};
+pub use self::installation_repositories::{
+    InstallationRepositoriesAction, InstallationRepositoriesEvent,
+};
+pub use self::kind::GitHubEventKind;
+pub use self::member::{MemberAction, MemberEvent};
+pub use self::membership::{MembershipAction, MembershipEvent};
+pub use self::merge_group::{MergeGroupAction, MergeGroupDestroyedReason, MergeGroupEvent};
+pub use self::org_block::{OrgBlockAction, OrgBlockEvent};
+pub use self::organization::{OrganizationAction, OrganizationEvent};
+pub use self::ping::PingEvent;
+pub use self::pull_request::{PullRequestAction, PullRequestEvent};
+pub use self::repository::{RepositoryAction, RepositoryEvent};
+pub use self::secret_scanning_alert::{SecretScanningAlertAction, SecretScanningAlertEvent};
+pub use self::star::{StarAction, StarEvent};
+pub use self::status::StatusEvent;
+pub use self::team::{TeamAction, TeamEvent};
+pub use self::waiters::{EventSubscription, EventWaiters};
+pub use self::watch::{WatchAction, WatchEvent};
 
+mod branch_protection_rule;
 mod check_run;
+mod code_scanning_alert;
+mod commit_comment;
+mod content_type;
+mod create;
+mod delete;
+mod dependabot_alert;
+mod discussion;
+mod discussion_comment;
+mod filter;
+mod fork;
+mod installation;
+mod installation_repositories;
+mod kind;
+mod member;
+mod membership;
+mod merge_group;
+mod org_block;
+mod organization;
+mod ping;
+mod pull_request;
+mod repository;
+mod repository_scoped;
+mod secret_scanning_alert;
+mod star;
+mod status;
+mod team;
+mod waiters;
+mod watch;
 
 /// Event on GitHub
 ///
@@ -25,37 +106,796 @@ mod check_run;
 ///
 /// Read more: https://docs.github.com/en/developers/webhooks-and-events/webhooks/about-webhooks
 ///
-/// The webhook payloads are inside a [`Box`], since their sizes vary greatly.
+/// The webhook payloads are inside a [`Box`], since their sizes vary greatly. Each payload is
+/// [`Preserved`], so that serializing the event returns the payload as GitHub sent it, including
+/// the fields that are not modelled by this crate.
 #[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum GitHubEvent {
+    /// Branch protection rule event
+    BranchProtectionRule(Box<Preserved<BranchProtectionRuleEvent>>),
+
     /// Check run event
-    CheckRun(Box<CheckRunEvent>),
+    CheckRun(Box<Preserved<CheckRunEvent>>),
+
+    /// Code scanning alert event
+    CodeScanningAlert(Box<Preserved<CodeScanningAlertEvent>>),
+
+    /// Commit comment event
+    CommitComment(Box<Preserved<CommitCommentEvent>>),
+
+    /// Create event
+    Create(Box<Preserved<CreateEvent>>),
+
+    /// Delete event
+    Delete(Box<Preserved<DeleteEvent>>),
+
+    /// Dependabot alert event
+    DependabotAlert(Box<Preserved<DependabotAlertEvent>>),
+
+    /// Discussion comment event
+    ///
+    /// Discussion comment events contain a discussion as well, so this variant must be tried
+    /// before [`GitHubEvent::Discussion`] when the event is deserialized without its kind.
+    DiscussionComment(Box<Preserved<DiscussionCommentEvent>>),
+
+    /// Discussion event
+    Discussion(Box<Preserved<DiscussionEvent>>),
+
+    /// Fork event
+    Fork(Box<Preserved<ForkEvent>>),
+
+    /// Installation repositories event
+    InstallationRepositories(Box<Preserved<InstallationRepositoriesEvent>>),
+
+    /// Member event
+    Member(Box<Preserved<MemberEvent>>),
+
+    /// Membership event
+    Membership(Box<Preserved<MembershipEvent>>),
+
+    /// Merge group event
+    MergeGroup(Box<Preserved<MergeGroupEvent>>),
+
+    /// Organization block event
+    OrgBlock(Box<Preserved<OrgBlockEvent>>),
+
+    /// Ping event
+    Ping(Box<Preserved<PingEvent>>),
+
+    /// Pull request event
+    PullRequest(Box<Preserved<PullRequestEvent>>),
+
+    /// Secret scanning alert event
+    SecretScanningAlert(Box<Preserved<SecretScanningAlertEvent>>),
+
+    /// Star event
+    Star(Box<Preserved<StarEvent>>),
+
+    /// Status event
+    Status(Box<Preserved<StatusEvent>>),
+
+    /// Team event
+    Team(Box<Preserved<TeamEvent>>),
+
+    /// Watch event
+    Watch(Box<Preserved<WatchEvent>>),
+
+    /// Repository event
+    ///
+    /// Repository events only require a repository and a sender, which many other events contain
+    /// as well, so this variant must be tried after all other events with a repository when the
+    /// event is deserialized without its kind.
+    Repository(Box<Preserved<RepositoryEvent>>),
+
+    /// Organization event
+    ///
+    /// Organization events only require an organization and a sender, which many other events
+    /// contain as well, so this variant must be tried last when the event is deserialized without
+    /// its kind.
+    Organization(Box<Preserved<OrganizationEvent>>),
+
+    /// Installation event
+    ///
+    /// Installation events only require an installation and a sender, and share their actions with
+    /// many other events, so this variant must be tried after all other events when the event is
+    /// deserialized without its kind.
+    Installation(Box<Preserved<InstallationEvent>>),
 
     /// Unsupported event
-    Unsupported,
+    ///
+    /// Events that are not (yet) modelled by this crate keep their name and raw payload, so that
+    /// they can still be passed on and handled generically.
+    Unsupported {
+        /// Name of the event
+        event: String,
+
+        /// Raw payload of the event
+        payload: Box<Value>,
+    },
+}
+
+impl Default for GitHubEvent {
+    fn default() -> Self {
+        GitHubEvent::Unsupported {
+            event: String::new(),
+            payload: Box::new(Value::Null),
+        }
+    }
+}
+
+impl GitHubEvent {
+    /// Returns the id of the installation that the event was sent to.
+    ///
+    /// Events that are sent to a GitHub App contain the installation of the app, which is needed
+    /// to authenticate requests to GitHub's API on behalf of the installation. Ping events and
+    /// events that were sent to repository or organization webhooks have no installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation_id(&self) -> Option<InstallationId> {
+        let installation = match self {
+            GitHubEvent::BranchProtectionRule(event) => event.installation().as_ref(),
+            GitHubEvent::CheckRun(event) => event.installation().as_ref(),
+            GitHubEvent::CodeScanningAlert(event) => event.installation().as_ref(),
+            GitHubEvent::CommitComment(event) => event.installation().as_ref(),
+            GitHubEvent::Create(event) => event.installation().as_ref(),
+            GitHubEvent::Delete(event) => event.installation().as_ref(),
+            GitHubEvent::DependabotAlert(event) => event.installation().as_ref(),
+            GitHubEvent::DiscussionComment(event) => event.installation().as_ref(),
+            GitHubEvent::Discussion(event) => event.installation().as_ref(),
+            GitHubEvent::Fork(event) => event.installation().as_ref(),
+            GitHubEvent::Installation(event) => Some(event.installation()),
+            GitHubEvent::InstallationRepositories(event) => Some(event.installation()),
+            GitHubEvent::Member(event) => event.installation().as_ref(),
+            GitHubEvent::Membership(event) => event.installation().as_ref(),
+            GitHubEvent::MergeGroup(event) => event.installation().as_ref(),
+            GitHubEvent::OrgBlock(event) => event.installation().as_ref(),
+            GitHubEvent::Organization(event) => event.installation().as_ref(),
+            GitHubEvent::Ping(_) => None,
+            GitHubEvent::PullRequest(event) => event.installation().as_ref(),
+            GitHubEvent::Repository(event) => event.installation().as_ref(),
+            GitHubEvent::SecretScanningAlert(event) => event.installation().as_ref(),
+            GitHubEvent::Star(event) => event.installation().as_ref(),
+            GitHubEvent::Status(event) => event.installation().as_ref(),
+            GitHubEvent::Team(event) => event.installation().as_ref(),
+            GitHubEvent::Watch(event) => event.installation().as_ref(),
+            GitHubEvent::Unsupported { payload, .. } => {
+                return payload
+                    .get("installation")
+                    .and_then(|installation| installation.get("id"))
+                    .and_then(Value::as_u64)
+                    .map(InstallationId::new);
+            }
+        };
+
+        installation.map(Installation::id)
+    }
+
+    /// Returns the id of the repository that the event belongs to.
+    ///
+    /// Events that are not about a single repository, e.g. installation events or pings for an
+    /// organization webhook, have no repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository_id(&self) -> Option<RepositoryId> {
+        if let GitHubEvent::Unsupported { payload, .. } = self {
+            return payload
+                .get("repository")
+                .and_then(|repository| repository.get("id"))
+                .and_then(Value::as_u64)
+                .map(RepositoryId::new);
+        }
+
+        self.repository().map(Repository::id)
+    }
+
+    /// Returns the repository that the event belongs to.
+    ///
+    /// Events that are not about a single repository have no repository. The repository of an
+    /// unsupported event is not deserialized, but its id can be read with
+    /// [`GitHubEvent::repository_id`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> Option<&Repository> {
+        let repository = match self {
+            GitHubEvent::BranchProtectionRule(event) => event.repository(),
+            GitHubEvent::CheckRun(event) => event.repository(),
+            GitHubEvent::CodeScanningAlert(event) => event.repository(),
+            GitHubEvent::CommitComment(event) => event.repository(),
+            GitHubEvent::Create(event) => event.repository(),
+            GitHubEvent::Delete(event) => event.repository(),
+            GitHubEvent::DependabotAlert(event) => event.repository(),
+            GitHubEvent::DiscussionComment(event) => event.repository(),
+            GitHubEvent::Discussion(event) => event.repository(),
+            GitHubEvent::Fork(event) => event.repository(),
+            GitHubEvent::Installation(_)
+            | GitHubEvent::InstallationRepositories(_)
+            | GitHubEvent::Membership(_)
+            | GitHubEvent::OrgBlock(_)
+            | GitHubEvent::Organization(_)
+            | GitHubEvent::Unsupported { .. } => return None,
+            GitHubEvent::Member(event) => event.repository(),
+            GitHubEvent::MergeGroup(event) => event.repository(),
+            GitHubEvent::Ping(event) => return event.repository().as_ref(),
+            GitHubEvent::PullRequest(event) => event.repository(),
+            GitHubEvent::Repository(event) => event.repository(),
+            GitHubEvent::SecretScanningAlert(event) => event.repository(),
+            GitHubEvent::Star(event) => event.repository(),
+            GitHubEvent::Status(event) => event.repository(),
+            GitHubEvent::Team(event) => return event.repository().as_ref(),
+            GitHubEvent::Watch(event) => event.repository(),
+        };
+
+        Some(repository)
+    }
+
+    /// Returns the organization that the event belongs to.
+    ///
+    /// Events only contain an organization if they happened in a repository or team that is owned
+    /// by an organization, or if they are about the organization itself.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> Option<&Organization> {
+        let organization = match self {
+            GitHubEvent::BranchProtectionRule(event) => event.organization(),
+            GitHubEvent::CheckRun(event) => event.organization(),
+            GitHubEvent::CodeScanningAlert(event) => event.organization(),
+            GitHubEvent::CommitComment(event) => event.organization(),
+            GitHubEvent::Create(event) => event.organization(),
+            GitHubEvent::Delete(event) => event.organization(),
+            GitHubEvent::DependabotAlert(event) => event.organization(),
+            GitHubEvent::DiscussionComment(event) => event.organization(),
+            GitHubEvent::Discussion(event) => event.organization(),
+            GitHubEvent::Fork(event) => event.organization(),
+            GitHubEvent::Installation(_)
+            | GitHubEvent::InstallationRepositories(_)
+            | GitHubEvent::Unsupported { .. } => return None,
+            GitHubEvent::Member(event) => event.organization(),
+            GitHubEvent::Membership(event) => return Some(event.organization()),
+            GitHubEvent::MergeGroup(event) => event.organization(),
+            GitHubEvent::OrgBlock(event) => return Some(event.organization()),
+            GitHubEvent::Organization(event) => return Some(event.organization()),
+            GitHubEvent::Ping(event) => event.organization(),
+            GitHubEvent::PullRequest(event) => event.organization(),
+            GitHubEvent::Repository(event) => event.organization(),
+            GitHubEvent::SecretScanningAlert(event) => event.organization(),
+            GitHubEvent::Star(event) => event.organization(),
+            GitHubEvent::Status(event) => event.organization(),
+            GitHubEvent::Team(event) => return Some(event.organization()),
+            GitHubEvent::Watch(event) => event.organization(),
+        };
+
+        organization.as_ref()
+    }
+
+    /// Returns the account that triggered the event.
+    ///
+    /// Pings and secret scanning alerts that were created by GitHub itself have no sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> Option<&Account> {
+        let sender = match self {
+            GitHubEvent::BranchProtectionRule(event) => event.sender(),
+            GitHubEvent::CheckRun(event) => event.sender(),
+            GitHubEvent::CodeScanningAlert(event) => event.sender(),
+            GitHubEvent::CommitComment(event) => event.sender(),
+            GitHubEvent::Create(event) => event.sender(),
+            GitHubEvent::Delete(event) => event.sender(),
+            GitHubEvent::DependabotAlert(event) => event.sender(),
+            GitHubEvent::DiscussionComment(event) => event.sender(),
+            GitHubEvent::Discussion(event) => event.sender(),
+            GitHubEvent::Fork(event) => event.sender(),
+            GitHubEvent::Installation(event) => event.sender(),
+            GitHubEvent::InstallationRepositories(event) => event.sender(),
+            GitHubEvent::Member(event) => event.sender(),
+            GitHubEvent::Membership(event) => event.sender(),
+            GitHubEvent::MergeGroup(event) => event.sender(),
+            GitHubEvent::OrgBlock(event) => event.sender(),
+            GitHubEvent::Organization(event) => event.sender(),
+            GitHubEvent::Ping(event) => return event.sender().as_ref(),
+            GitHubEvent::PullRequest(event) => event.sender(),
+            GitHubEvent::Repository(event) => event.sender(),
+            GitHubEvent::SecretScanningAlert(event) => return event.sender().as_ref(),
+            GitHubEvent::Star(event) => event.sender(),
+            GitHubEvent::Status(event) => event.sender(),
+            GitHubEvent::Team(event) => event.sender(),
+            GitHubEvent::Watch(event) => event.sender(),
+            GitHubEvent::Unsupported { .. } => return None,
+        };
+
+        Some(sender)
+    }
+
+    /// Returns the event's action as it is sent by GitHub, e.g. `review_requested`.
+    ///
+    /// Events that are not divided into actions, e.g. create and status events, have no action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> Option<String> {
+        match self {
+            GitHubEvent::BranchProtectionRule(event) => action_name(event.action()),
+            GitHubEvent::CheckRun(event) => action_name(event.action()),
+            GitHubEvent::CodeScanningAlert(event) => action_name(event.action()),
+            GitHubEvent::CommitComment(event) => action_name(event.action()),
+            GitHubEvent::Create(_)
+            | GitHubEvent::Delete(_)
+            | GitHubEvent::Fork(_)
+            | GitHubEvent::Ping(_)
+            | GitHubEvent::Status(_) => None,
+            GitHubEvent::DependabotAlert(event) => action_name(event.action()),
+            GitHubEvent::DiscussionComment(event) => action_name(event.action()),
+            GitHubEvent::Discussion(event) => action_name(event.action()),
+            GitHubEvent::Installation(event) => action_name(event.action()),
+            GitHubEvent::InstallationRepositories(event) => action_name(event.action()),
+            GitHubEvent::Member(event) => action_name(event.action()),
+            GitHubEvent::Membership(event) => action_name(event.action()),
+            GitHubEvent::MergeGroup(event) => action_name(event.action()),
+            GitHubEvent::OrgBlock(event) => action_name(event.action()),
+            GitHubEvent::Organization(event) => action_name(event.action()),
+            GitHubEvent::PullRequest(event) => action_name(event.action()),
+            GitHubEvent::Repository(event) => action_name(event.action()),
+            GitHubEvent::SecretScanningAlert(event) => action_name(event.action()),
+            GitHubEvent::Star(event) => action_name(event.action()),
+            GitHubEvent::Team(event) => action_name(event.action()),
+            GitHubEvent::Watch(event) => action_name(event.action()),
+            GitHubEvent::Unsupported { payload, .. } => payload
+                .get("action")
+                .and_then(Value::as_str)
+                .map(String::from),
+        }
+    }
 }
 
 impl Display for GitHubEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let string_representation = match self {
+            GitHubEvent::BranchProtectionRule(event) => {
+                format!("branch protection rule {}", event.action())
+            }
             GitHubEvent::CheckRun(event) => format!("check run {}", event.action()),
-            GitHubEvent::Unsupported => "unsupported".into(),
+            GitHubEvent::CodeScanningAlert(event) => {
+                format!("code scanning alert {}", event.action())
+            }
+            GitHubEvent::CommitComment(event) => format!("commit comment {}", event.action()),
+            GitHubEvent::Create(event) => format!("create {}", event.ref_type()),
+            GitHubEvent::Delete(event) => format!("delete {}", event.ref_type()),
+            GitHubEvent::DependabotAlert(event) => {
+                format!("dependabot alert {}", event.action())
+            }
+            GitHubEvent::DiscussionComment(event) => {
+                format!("discussion comment {}", event.action())
+            }
+            GitHubEvent::Discussion(event) => format!("discussion {}", event.action()),
+            GitHubEvent::Fork(_) => "fork".into(),
+            GitHubEvent::Installation(event) => format!("installation {}", event.action()),
+            GitHubEvent::InstallationRepositories(event) => {
+                format!("installation repositories {}", event.action())
+            }
+            GitHubEvent::Member(event) => format!("member {}", event.action()),
+            GitHubEvent::Membership(event) => format!("membership {}", event.action()),
+            GitHubEvent::MergeGroup(event) => format!("merge group {}", event.action()),
+            GitHubEvent::OrgBlock(event) => format!("org block {}", event.action()),
+            GitHubEvent::Organization(event) => format!("organization {}", event.action()),
+            GitHubEvent::Ping(_) => "ping".into(),
+            GitHubEvent::PullRequest(event) => format!("pull request {}", event.action()),
+            GitHubEvent::Repository(event) => format!("repository {}", event.action()),
+            GitHubEvent::SecretScanningAlert(event) => {
+                format!("secret scanning alert {}", event.action())
+            }
+            GitHubEvent::Star(event) => format!("star {}", event.action()),
+            GitHubEvent::Status(event) => format!("status {}", event.state()),
+            GitHubEvent::Team(event) => format!("team {}", event.action()),
+            GitHubEvent::Watch(event) => format!("watch {}", event.action()),
+            GitHubEvent::Unsupported { event, .. } => format!("unsupported {}", event),
         };
 
         write!(f, "{}", string_representation)
     }
 }
 
-impl Default for GitHubEvent {
-    fn default() -> Self {
-        GitHubEvent::Unsupported
-    }
+/// Deserialize the payload of a webhook event
+///
+/// GitHub sends the kind of a webhook event in the `X-GitHub-Event` header, which determines the
+/// type into which the payload is deserialized. Events that are not supported by this crate are
+/// returned as [`GitHubEvent::Unsupported`].
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(payload)))]
+pub fn deserialize_event(kind: &GitHubEventKind, payload: &[u8]) -> Result<GitHubEvent, Error> {
+    let event = match kind {
+        GitHubEventKind::BranchProtectionRule => {
+            GitHubEvent::BranchProtectionRule(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::CheckRun => GitHubEvent::CheckRun(deserialize_payload(kind, payload)?),
+        GitHubEventKind::CodeScanningAlert => {
+            GitHubEvent::CodeScanningAlert(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::CommitComment => {
+            GitHubEvent::CommitComment(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Create => GitHubEvent::Create(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Delete => GitHubEvent::Delete(deserialize_payload(kind, payload)?),
+        GitHubEventKind::DependabotAlert => {
+            GitHubEvent::DependabotAlert(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::DiscussionComment => {
+            GitHubEvent::DiscussionComment(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Discussion => GitHubEvent::Discussion(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Fork => GitHubEvent::Fork(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Installation => {
+            GitHubEvent::Installation(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::InstallationRepositories => {
+            GitHubEvent::InstallationRepositories(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Member => GitHubEvent::Member(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Membership => GitHubEvent::Membership(deserialize_payload(kind, payload)?),
+        GitHubEventKind::MergeGroup => GitHubEvent::MergeGroup(deserialize_payload(kind, payload)?),
+        GitHubEventKind::OrgBlock => GitHubEvent::OrgBlock(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Organization => {
+            GitHubEvent::Organization(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Ping => GitHubEvent::Ping(deserialize_payload(kind, payload)?),
+        GitHubEventKind::PullRequest => {
+            GitHubEvent::PullRequest(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Repository => GitHubEvent::Repository(deserialize_payload(kind, payload)?),
+        GitHubEventKind::SecretScanningAlert => {
+            GitHubEvent::SecretScanningAlert(deserialize_payload(kind, payload)?)
+        }
+        GitHubEventKind::Star => GitHubEvent::Star(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Status => GitHubEvent::Status(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Team => GitHubEvent::Team(deserialize_payload(kind, payload)?),
+        GitHubEventKind::Watch => GitHubEvent::Watch(deserialize_payload(kind, payload)?),
+        _ => GitHubEvent::Unsupported {
+            event: kind.to_string(),
+            payload: deserialize_payload(kind, payload)?,
+        },
+    };
+
+    Ok(event)
+}
+
+/// Deserialize the body of a webhook delivery
+///
+/// Webhook deliveries contain the event's payload either as JSON or as a form-encoded parameter,
+/// depending on the [`WebhookContentType`] that was configured for the webhook. This function
+/// extracts the payload from the body of the request and then deserializes the event.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(body)))]
+pub fn deserialize_webhook(
+    kind: &GitHubEventKind,
+    content_type: WebhookContentType,
+    body: &[u8],
+) -> Result<GitHubEvent, Error> {
+    let payload = content_type.payload(body)?;
+
+    deserialize_event(kind, &payload)
+}
+
+fn action_name<A>(action: A) -> Option<String>
+where
+    A: Serialize,
+{
+    serde_json::to_value(action)
+        .ok()
+        .and_then(|action| action.as_str().map(String::from))
+}
+
+#[cfg(not(feature = "simd-json"))]
+fn deserialize_payload<T>(kind: &GitHubEventKind, payload: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(payload).map_err(|error| {
+        Error::Serialization(format!("failed to deserialize {} event: {}", kind, error))
+    })
+}
+
+// simd-json parses the payload in place, which is why it needs a mutable copy of the payload.
+// Webhook payloads are large enough that the faster parser makes up for the copy.
+#[cfg(feature = "simd-json")]
+fn deserialize_payload<T>(kind: &GitHubEventKind, payload: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut payload = payload.to_vec();
+
+    simd_json::serde::from_slice(&mut payload).map_err(|error| {
+        Error::Serialization(format!("failed to deserialize {} event: {}", kind, error))
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GitHubEvent;
+    use std::fs::{read, read_dir};
+    use std::path::{Path, PathBuf};
+
+    use serde_json::Value;
+
+    use automatons::Error;
+
+    use crate::resource::{InstallationId, RepositoryId};
+
+    use super::{
+        deserialize_event, deserialize_webhook, GitHubEvent, GitHubEventKind, WebhookContentType,
+    };
+
+    fn fixtures(directory: &Path) -> Vec<PathBuf> {
+        let mut fixtures = Vec::new();
+
+        for entry in read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.is_dir() {
+                fixtures.append(&mut self::fixtures(&path));
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                fixtures.push(path);
+            }
+        }
+
+        fixtures
+    }
+
+    fn event_fixtures() -> Vec<(GitHubEventKind, PathBuf)> {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/event");
+
+        fixtures(&directory)
+            .into_iter()
+            .map(|path| {
+                let name = path.file_name().unwrap().to_str().unwrap();
+                let kind = GitHubEventKind::from(name.split('.').next().unwrap());
+
+                (kind, path)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deserialize_event_all_fixtures() {
+        let fixtures = event_fixtures();
+        assert!(fixtures.len() > 1);
+
+        for (kind, path) in fixtures {
+            let github_event = deserialize_event(&kind, &read(&path).unwrap())
+                .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+
+            assert!(
+                !matches!(github_event, GitHubEvent::Unsupported { .. }),
+                "{} is not supported",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_event_ignores_unknown_fields() {
+        for (kind, path) in event_fixtures() {
+            let mut payload: Value = serde_json::from_slice(&read(&path).unwrap()).unwrap();
+            payload["field_added_in_the_future"] = "value".into();
+
+            let payload = serde_json::to_vec(&payload).unwrap();
+
+            assert!(
+                deserialize_event(&kind, &payload).is_ok(),
+                "{} rejects unknown fields",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_event_preserves_all_fields() {
+        for (kind, path) in event_fixtures() {
+            let payload = read(&path).unwrap();
+            let original: Value = serde_json::from_slice(&payload).unwrap();
+
+            let github_event = deserialize_event(&kind, &payload).unwrap();
+            let queued = serde_json::to_string(&github_event).unwrap();
+
+            let dequeued: GitHubEvent = serde_json::from_str(&queued).unwrap();
+
+            assert_eq!(
+                original,
+                serde_json::to_value(&dequeued).unwrap(),
+                "{} loses fields in a round trip",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_event_check_run() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::CheckRun(_)));
+    }
+
+    #[test]
+    fn installation_id() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap();
+
+        assert_eq!(
+            Some(InstallationId::new(25802826)),
+            github_event.installation_id()
+        );
+    }
+
+    #[test]
+    fn installation_id_of_unsupported_event() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event =
+            deserialize_event(&GitHubEventKind::Unknown("custom".into()), payload).unwrap();
+
+        assert_eq!(
+            Some(InstallationId::new(25802826)),
+            github_event.installation_id()
+        );
+    }
+
+    #[test]
+    fn deserialize_event_installation_repositories() {
+        let payload =
+            include_bytes!("../../tests/fixtures/event/installation_repositories.removed.json");
+
+        let github_event =
+            deserialize_event(&GitHubEventKind::InstallationRepositories, payload).unwrap();
+
+        assert!(matches!(
+            github_event,
+            GitHubEvent::InstallationRepositories(_)
+        ));
+        assert_eq!(
+            Some(InstallationId::new(25802826)),
+            github_event.installation_id()
+        );
+        assert_eq!(None, github_event.repository_id());
+    }
+
+    #[test]
+    fn repository_id() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap();
+
+        assert_eq!(
+            Some(RepositoryId::new(518377950)),
+            github_event.repository_id()
+        );
+    }
+
+    #[test]
+    fn repository_id_of_unsupported_event() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event =
+            deserialize_event(&GitHubEventKind::Unknown("custom".into()), payload).unwrap();
+
+        assert_eq!(
+            Some(RepositoryId::new(518377950)),
+            github_event.repository_id()
+        );
+    }
+
+    #[test]
+    fn repository_organization_and_sender() {
+        let payload =
+            include_bytes!("../../tests/fixtures/event/pull_request.review_requested.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::PullRequest, payload).unwrap();
+
+        assert_eq!(
+            "devxbots/automatons",
+            github_event.repository().unwrap().full_name().get()
+        );
+        assert_eq!(
+            "devxbots",
+            github_event.organization().unwrap().login().get()
+        );
+        assert!(github_event.sender().is_some());
+    }
+
+    #[test]
+    fn action() {
+        let payload =
+            include_bytes!("../../tests/fixtures/event/pull_request.review_requested.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::PullRequest, payload).unwrap();
+
+        assert_eq!(Some("review_requested".into()), github_event.action());
+    }
+
+    #[test]
+    fn action_of_unsupported_event() {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+
+        let github_event =
+            deserialize_event(&GitHubEventKind::Unknown("custom".into()), payload).unwrap();
+
+        assert_eq!(Some("completed".into()), github_event.action());
+    }
+
+    #[test]
+    fn deserialize_event_ping() {
+        let payload = include_bytes!("../../tests/fixtures/event/ping.json");
+
+        let github_event = deserialize_event(&GitHubEventKind::Ping, payload).unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Ping(_)));
+    }
+
+    #[test]
+    fn deserialize_webhook_form_encoded() {
+        let payload = include_str!("../../tests/fixtures/event/check_run.completed.json");
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("payload", payload)
+            .finish();
+
+        let github_event = deserialize_webhook(
+            &GitHubEventKind::CheckRun,
+            WebhookContentType::Form,
+            body.as_bytes(),
+        )
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::CheckRun(_)));
+    }
+
+    #[test]
+    fn deserialize_event_unknown() {
+        let kind = GitHubEventKind::Unknown("future_event".into());
+
+        let github_event = deserialize_event(&kind, br#"{ "action": "created" }"#).unwrap();
+
+        if let GitHubEvent::Unsupported { event, payload } = github_event {
+            assert_eq!("future_event", event);
+            assert_eq!("created", payload["action"]);
+        } else {
+            panic!("expected an unsupported event");
+        }
+    }
+
+    #[test]
+    fn trait_deserialize_unsupported_roundtrips() {
+        let github_event = deserialize_event(
+            &GitHubEventKind::Unknown("future_event".into()),
+            br#"{ "action": "created" }"#,
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&github_event).unwrap();
+        let deserialized: GitHubEvent = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(github_event, deserialized);
+    }
+
+    #[test]
+    fn trait_display_unsupported() {
+        let github_event = deserialize_event(
+            &GitHubEventKind::Unknown("future_event".into()),
+            br#"{ "action": "created" }"#,
+        )
+        .unwrap();
+
+        assert_eq!("unsupported future_event", github_event.to_string());
+    }
+
+    #[test]
+    fn deserialize_event_with_invalid_payload() {
+        let error = deserialize_event(&GitHubEventKind::CheckRun, b"{}").unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_branch_protection_rule() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/branch_protection_rule.edited.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::BranchProtectionRule(_)));
+    }
 
     #[test]
     fn trait_deserialize_check_run() {
@@ -71,6 +911,140 @@ mod tests {
         }
     }
 
+    #[test]
+    fn trait_deserialize_code_scanning_alert() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/code_scanning_alert.created.json"
+        ))
+        .unwrap();
+
+        if let GitHubEvent::CodeScanningAlert(event) = github_event {
+            assert_eq!(3, event.alert().number().get());
+        } else {
+            panic!("expected a code scanning alert event");
+        }
+    }
+
+    #[test]
+    fn trait_deserialize_dependabot_alert() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/dependabot_alert.created.json"
+        ))
+        .unwrap();
+
+        if let GitHubEvent::DependabotAlert(event) = github_event {
+            assert_eq!(2, event.alert().number().get());
+        } else {
+            panic!("expected a dependabot alert event");
+        }
+    }
+
+    #[test]
+    fn trait_deserialize_installation() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/installation.suspend.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Installation(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_membership() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/membership.added.json"
+        ))
+        .unwrap();
+
+        if let GitHubEvent::Membership(event) = github_event {
+            assert_eq!("maintainers", event.team().slug().get());
+        } else {
+            panic!("expected a membership event");
+        }
+    }
+
+    #[test]
+    fn trait_deserialize_merge_group() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/merge_group.checks_requested.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::MergeGroup(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_organization() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/organization.member_added.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Organization(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_pull_request() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::PullRequest(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_repository() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/repository.renamed.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Repository(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_secret_scanning_alert() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/secret_scanning_alert.resolved.json"
+        ))
+        .unwrap();
+
+        if let GitHubEvent::SecretScanningAlert(event) = github_event {
+            assert_eq!(42, event.alert().number().get());
+        } else {
+            panic!("expected a secret scanning alert event");
+        }
+    }
+
+    #[test]
+    fn trait_deserialize_star_with_organization() {
+        let github_event: GitHubEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/star.created.json"))
+                .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Star(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_status() {
+        let github_event: GitHubEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/status.success.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Status(_)));
+    }
+
+    #[test]
+    fn trait_deserialize_team() {
+        let github_event: GitHubEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/team.created.json"))
+                .unwrap();
+
+        assert!(matches!(github_event, GitHubEvent::Team(_)));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
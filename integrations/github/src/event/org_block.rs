@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization};
+
+/// Organization block action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgBlockAction {
+    /// A user was blocked from the organization.
+    Blocked,
+
+    /// A user was unblocked from the organization.
+    Unblocked,
+}
+
+/// Organization block event
+///
+/// An organization block event is sent when an organization blocks or unblocks a user. Blocked
+/// users cannot interact with the organization's repositories.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrgBlockEvent {
+    action: OrgBlockAction,
+    blocked_user: Account,
+    organization: Organization,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl OrgBlockEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> OrgBlockAction {
+        self.action
+    }
+
+    /// Returns the user who was blocked or unblocked.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn blocked_user(&self) -> &Account {
+        &self.blocked_user
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for OrgBlockAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            OrgBlockAction::Blocked => "blocked",
+            OrgBlockAction::Unblocked => "unblocked",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for OrgBlockEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {} ({})",
+            self.blocked_user, self.organization, self.action
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrgBlockAction, OrgBlockEvent};
+
+    fn event() -> OrgBlockEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/org_block.blocked.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), OrgBlockAction::Blocked));
+        assert_eq!("octocat", event.blocked_user().login().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("octocat in devxbots (blocked)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<OrgBlockEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<OrgBlockEvent>();
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Membership, Organization};
+
+/// Organization action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationAction {
+    /// The organization was deleted.
+    Deleted,
+
+    /// A user accepted the invitation to the organization.
+    MemberAdded,
+
+    /// A user was invited to the organization.
+    MemberInvited,
+
+    /// A member was removed from the organization.
+    MemberRemoved,
+
+    /// The organization was renamed.
+    Renamed,
+}
+
+/// Organization event
+///
+/// An organization event is sent when an organization is deleted or renamed, and when a user is
+/// invited to, added to, or removed from the organization. Events about members contain the
+/// member's membership, which is missing for invitations and changes to the organization itself.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrganizationEvent {
+    action: OrganizationAction,
+    membership: Option<Membership>,
+    organization: Organization,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl OrganizationEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> OrganizationAction {
+        self.action
+    }
+
+    /// Returns the membership of the user who was added or removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn membership(&self) -> &Option<Membership> {
+        &self.membership
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for OrganizationAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            OrganizationAction::Deleted => "deleted",
+            OrganizationAction::MemberAdded => "member_added",
+            OrganizationAction::MemberInvited => "member_invited",
+            OrganizationAction::MemberRemoved => "member_removed",
+            OrganizationAction::Renamed => "renamed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for OrganizationEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.organization, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::MembershipRole;
+
+    use super::{OrganizationAction, OrganizationEvent};
+
+    fn event() -> OrganizationEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/organization.member_added.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), OrganizationAction::MemberAdded));
+        assert_eq!(
+            MembershipRole::Admin,
+            event.membership().as_ref().unwrap().role()
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("devxbots (member_added)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<OrganizationEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<OrganizationEvent>();
+    }
+}
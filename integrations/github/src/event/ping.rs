@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::event::GitHubEventKind;
+use crate::resource::{Account, Hook, HookId, Organization, Repository};
+
+/// Ping event
+///
+/// When a new webhook is created, GitHub sends a ping event to let the receiver know that the
+/// webhook was set up correctly. The event contains the webhook's configuration and a random
+/// piece of GitHub's zen. Ping events don't require any further processing.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PingEvent {
+    zen: String,
+    hook_id: HookId,
+    hook: Hook,
+    repository: Option<Repository>,
+    organization: Option<Organization>,
+    sender: Option<Account>,
+}
+
+impl PingEvent {
+    /// Returns the event's zen.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn zen(&self) -> &str {
+        &self.zen
+    }
+
+    /// Returns the id of the webhook that sent the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn hook_id(&self) -> HookId {
+        self.hook_id
+    }
+
+    /// Returns the webhook that sent the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn hook(&self) -> &Hook {
+        &self.hook
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Option<Repository> {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Option<Account> {
+        &self.sender
+    }
+
+    /// Returns the events of the webhook that are not in the list of supported events.
+    ///
+    /// Receivers can use this to validate that the webhook has not been subscribed to events that
+    /// they cannot handle.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn unsupported_events(&self, supported: &[GitHubEventKind]) -> Vec<GitHubEventKind> {
+        self.hook
+            .events()
+            .iter()
+            .map(|event| GitHubEventKind::from(event.as_str()))
+            .filter(|kind| !supported.contains(kind))
+            .collect()
+    }
+}
+
+impl Display for PingEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.zen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::GitHubEventKind;
+
+    use super::PingEvent;
+
+    fn ping() -> PingEvent {
+        serde_json::from_str(include_str!("../../tests/fixtures/event/ping.json")).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        assert_eq!(376543210, ping().hook_id().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("Design for failure.", ping().to_string());
+    }
+
+    #[test]
+    fn unsupported_events() {
+        let unsupported =
+            ping().unsupported_events(&[GitHubEventKind::CheckRun, GitHubEventKind::CheckSuite]);
+
+        assert_eq!(vec![GitHubEventKind::PullRequest], unsupported);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PingEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PingEvent>();
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{
+    Account, Installation, Organization, PullRequest, PullRequestNumber, Repository, Team,
+};
+
+/// Pull request action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestAction {
+    /// A pull request was assigned to a user.
+    Assigned,
+
+    /// Auto merge was disabled for a pull request.
+    AutoMergeDisabled,
+
+    /// Auto merge was enabled for a pull request.
+    AutoMergeEnabled,
+
+    /// A pull request was closed or merged.
+    Closed,
+
+    /// A pull request was converted to a draft.
+    ConvertedToDraft,
+
+    /// A pull request was removed from a milestone.
+    Demilestoned,
+
+    /// A pull request was removed from the merge queue.
+    Dequeued,
+
+    /// The title or body of a pull request was edited, or its base branch was changed.
+    Edited,
+
+    /// A pull request was added to the merge queue.
+    Enqueued,
+
+    /// A label was added to a pull request.
+    Labeled,
+
+    /// The conversation on a pull request was locked.
+    Locked,
+
+    /// A pull request was added to a milestone.
+    Milestoned,
+
+    /// A pull request was created.
+    Opened,
+
+    /// A draft pull request was marked as ready for review.
+    ReadyForReview,
+
+    /// A closed pull request was reopened.
+    Reopened,
+
+    /// A request for review by a user or team was removed from a pull request.
+    ReviewRequestRemoved,
+
+    /// A user or team was requested to review a pull request.
+    ReviewRequested,
+
+    /// The head branch of a pull request was updated.
+    Synchronize,
+
+    /// A user was unassigned from a pull request.
+    Unassigned,
+
+    /// A label was removed from a pull request.
+    Unlabeled,
+
+    /// The conversation on a pull request was unlocked.
+    Unlocked,
+}
+
+/// Pull request event
+///
+/// A pull request event is sent when there is activity on a pull request. When a review is
+/// requested or a review request is removed, the payload contains either the user or the team
+/// whose review was requested.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PullRequestEvent {
+    action: PullRequestAction,
+    number: PullRequestNumber,
+    pull_request: PullRequest,
+    #[serde(default)]
+    requested_reviewer: Option<Account>,
+    #[serde(default)]
+    requested_team: Option<Team>,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl PullRequestEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> PullRequestAction {
+        self.action
+    }
+
+    /// Returns the pull request's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> PullRequestNumber {
+        self.number
+    }
+
+    /// Returns the event's pull request.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pull_request(&self) -> &PullRequest {
+        &self.pull_request
+    }
+
+    /// Returns the user whose review was requested or whose review request was removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requested_reviewer(&self) -> &Option<Account> {
+        &self.requested_reviewer
+    }
+
+    /// Returns the team whose review was requested or whose review request was removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requested_team(&self) -> &Option<Team> {
+        &self.requested_team
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for PullRequestAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            PullRequestAction::Assigned => "assigned",
+            PullRequestAction::AutoMergeDisabled => "auto merge disabled",
+            PullRequestAction::AutoMergeEnabled => "auto merge enabled",
+            PullRequestAction::Closed => "closed",
+            PullRequestAction::ConvertedToDraft => "converted to draft",
+            PullRequestAction::Demilestoned => "demilestoned",
+            PullRequestAction::Dequeued => "dequeued",
+            PullRequestAction::Edited => "edited",
+            PullRequestAction::Enqueued => "enqueued",
+            PullRequestAction::Labeled => "labeled",
+            PullRequestAction::Locked => "locked",
+            PullRequestAction::Milestoned => "milestoned",
+            PullRequestAction::Opened => "opened",
+            PullRequestAction::ReadyForReview => "ready for review",
+            PullRequestAction::Reopened => "reopened",
+            PullRequestAction::ReviewRequestRemoved => "review request removed",
+            PullRequestAction::ReviewRequested => "review requested",
+            PullRequestAction::Synchronize => "synchronize",
+            PullRequestAction::Unassigned => "unassigned",
+            PullRequestAction::Unlabeled => "unlabeled",
+            PullRequestAction::Unlocked => "unlocked",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for PullRequestEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{} ({})",
+            self.repository.full_name(),
+            self.pull_request,
+            self.action
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PullRequestAction, PullRequestEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: PullRequestEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), PullRequestAction::ReviewRequested));
+        assert!(event.requested_reviewer().is_none());
+        assert_eq!(
+            "maintainers",
+            event.requested_team().as_ref().unwrap().slug().get()
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: PullRequestEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "devxbots/automatons#27 (review requested)",
+            event.to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PullRequestEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PullRequestEvent>();
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Login, Organization, Repository, RepositoryName};
+
+/// Repository action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryAction {
+    /// A repository was archived.
+    Archived,
+
+    /// A repository was created.
+    Created,
+
+    /// A repository was deleted.
+    Deleted,
+
+    /// The topics, default branch, description, or homepage of a repository were changed.
+    Edited,
+
+    /// The visibility of a repository was changed to private.
+    Privatized,
+
+    /// The visibility of a repository was changed to public.
+    Publicized,
+
+    /// A repository was renamed.
+    Renamed,
+
+    /// The ownership of a repository was transferred to another user or organization.
+    Transferred,
+
+    /// A previously archived repository was unarchived.
+    Unarchived,
+}
+
+/// Repository event
+///
+/// A repository event is sent when a repository is created, deleted, or changed, for example when
+/// it is archived, renamed, or made public. Renamed and transferred repositories contain their
+/// previous name and owner. If the repository is owned by an organization, the organization is
+/// included in the payload. If the event is sent to a GitHub App, the payload contains the
+/// installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RepositoryEvent {
+    action: RepositoryAction,
+    changes: Option<RepositoryChanges>,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct RepositoryChanges {
+    repository: Option<RepositoryNameChange>,
+    owner: Option<RepositoryOwnerChange>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct RepositoryNameChange {
+    name: Change<RepositoryName>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct RepositoryOwnerChange {
+    from: RepositoryOwner,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct RepositoryOwner {
+    user: Option<Account>,
+    organization: Option<Organization>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct Change<T> {
+    from: T,
+}
+
+impl RepositoryEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> RepositoryAction {
+        self.action
+    }
+
+    /// Returns the repository's previous name, if it was renamed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn previous_name(&self) -> Option<&RepositoryName> {
+        self.changes
+            .as_ref()
+            .and_then(|changes| changes.repository.as_ref())
+            .map(|repository| &repository.name.from)
+    }
+
+    /// Returns the repository's previous owner, if it was transferred.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn previous_owner(&self) -> Option<&Login> {
+        let owner = &self.changes.as_ref()?.owner.as_ref()?.from;
+
+        match (&owner.user, &owner.organization) {
+            (Some(user), _) => Some(user.login()),
+            (None, Some(organization)) => Some(organization.login()),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for RepositoryAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            RepositoryAction::Archived => "archived",
+            RepositoryAction::Created => "created",
+            RepositoryAction::Deleted => "deleted",
+            RepositoryAction::Edited => "edited",
+            RepositoryAction::Privatized => "privatized",
+            RepositoryAction::Publicized => "publicized",
+            RepositoryAction::Renamed => "renamed",
+            RepositoryAction::Transferred => "transferred",
+            RepositoryAction::Unarchived => "unarchived",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for RepositoryEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.repository.full_name(), self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RepositoryAction, RepositoryEvent};
+
+    fn event() -> RepositoryEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/repository.renamed.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), RepositoryAction::Renamed));
+        assert_eq!("automata", event.previous_name().unwrap().get());
+        assert!(event.previous_owner().is_none());
+    }
+
+    #[test]
+    fn trait_deserialize_transferred() {
+        let mut json: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/repository.renamed.json"
+        ))
+        .unwrap();
+        json["action"] = "transferred".into();
+        json["changes"] = serde_json::json!({ "owner": { "from": { "user": json["sender"] } } });
+
+        let event: RepositoryEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!("jdno", event.previous_owner().unwrap().get());
+        assert!(event.previous_name().is_none());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("devxbots/automatons (renamed)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RepositoryEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RepositoryEvent>();
+    }
+}
@@ -0,0 +1,73 @@
+use crate::event::{
+    BranchProtectionRuleEvent, CheckRunEvent, CodeScanningAlertEvent, CommitCommentEvent,
+    CreateEvent, DeleteEvent, DependabotAlertEvent, DiscussionCommentEvent, DiscussionEvent,
+    ForkEvent, MemberEvent, MergeGroupEvent, PullRequestEvent, RepositoryEvent,
+    SecretScanningAlertEvent, StarEvent, StatusEvent, WatchEvent,
+};
+use crate::resource::{Repository, RepositoryScoped};
+
+macro_rules! repository_scoped {
+    ($($event:ty),* $(,)?) => {
+        $(
+            impl RepositoryScoped for $event {
+                fn repository(&self) -> &Repository {
+                    <$event>::repository(self)
+                }
+            }
+        )*
+    };
+}
+
+repository_scoped!(
+    BranchProtectionRuleEvent,
+    CheckRunEvent,
+    CodeScanningAlertEvent,
+    CommitCommentEvent,
+    CreateEvent,
+    DeleteEvent,
+    DependabotAlertEvent,
+    DiscussionCommentEvent,
+    DiscussionEvent,
+    ForkEvent,
+    MemberEvent,
+    MergeGroupEvent,
+    PullRequestEvent,
+    RepositoryEvent,
+    SecretScanningAlertEvent,
+    StarEvent,
+    StatusEvent,
+    WatchEvent,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{CheckRunEvent, PullRequestEvent};
+    use crate::resource::{Login, RepositoryName, RepositoryScoped};
+
+    fn owner_and_name<T: RepositoryScoped>(scoped: &T) -> (&Login, &RepositoryName) {
+        (scoped.owner_login(), scoped.repository_name())
+    }
+
+    #[test]
+    fn pull_request_event() {
+        let event: PullRequestEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/pull_request.review_requested.json"
+        ))
+        .unwrap();
+
+        let (owner, name) = owner_and_name(&event);
+
+        assert_eq!("devxbots", owner.get());
+        assert_eq!("automatons", name.get());
+    }
+
+    #[test]
+    fn check_run_event() {
+        let event: CheckRunEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/check_run.completed.json"
+        ))
+        .unwrap();
+
+        assert_eq!("devxbots/automatons", event.repository_full_name().get());
+    }
+}
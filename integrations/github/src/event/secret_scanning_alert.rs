@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository, SecretScanningAlert};
+
+/// Secret scanning alert action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretScanningAlertAction {
+    /// A secret scanning alert was created.
+    Created,
+
+    /// A secret scanning alert was reopened.
+    Reopened,
+
+    /// A secret scanning alert was resolved.
+    Resolved,
+
+    /// A secret scanning alert was revoked.
+    Revoked,
+}
+
+/// Secret scanning alert event
+///
+/// A secret scanning alert event contains the action that occurred, the latest state of the alert,
+/// and the repository in which the secret was found. Alerts are created by GitHub itself, which is
+/// why the event only has a sender when a user changed the alert.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SecretScanningAlertEvent {
+    action: SecretScanningAlertAction,
+    alert: SecretScanningAlert,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Option<Account>,
+}
+
+impl SecretScanningAlertEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> SecretScanningAlertAction {
+        self.action
+    }
+
+    /// Returns the event's alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn alert(&self) -> &SecretScanningAlert {
+        &self.alert
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Option<Account> {
+        &self.sender
+    }
+}
+
+impl Display for SecretScanningAlertAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            SecretScanningAlertAction::Created => "created",
+            SecretScanningAlertAction::Reopened => "reopened",
+            SecretScanningAlertAction::Resolved => "resolved",
+            SecretScanningAlertAction::Revoked => "revoked",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for SecretScanningAlertEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.alert, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretScanningAlertAction, SecretScanningAlertEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: SecretScanningAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/secret_scanning_alert.resolved.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(
+            event.action(),
+            SecretScanningAlertAction::Resolved
+        ));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: SecretScanningAlertEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/secret_scanning_alert.resolved.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#42 (resolved)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SecretScanningAlertEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SecretScanningAlertEvent>();
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository};
+
+/// Star action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StarAction {
+    /// Someone starred a repository.
+    Created,
+
+    /// Someone unstarred a repository.
+    Deleted,
+}
+
+/// Star event
+///
+/// A star event is sent when someone stars or unstars a repository. The payload contains the date
+/// when the repository was starred, which is empty when the star was removed. If the webhook was
+/// configured for an organization, or if the repository is owned by one, the organization is
+/// included in the payload. If the event is sent to a GitHub App, the payload contains the
+/// installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StarEvent {
+    action: StarAction,
+    starred_at: Option<DateTime<Utc>>,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl StarEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> StarAction {
+        self.action
+    }
+
+    /// Returns the date when the repository was starred.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn starred_at(&self) -> &Option<DateTime<Utc>> {
+        &self.starred_at
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for StarAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            StarAction::Created => "created",
+            StarAction::Deleted => "deleted",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for StarEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.repository.full_name(), self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StarAction, StarEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: StarEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/star.created.json"))
+                .unwrap();
+
+        assert!(matches!(event.action(), StarAction::Created));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: StarEvent =
+            serde_json::from_str(include_str!("../../tests/fixtures/event/star.created.json"))
+                .unwrap();
+
+        assert_eq!("devxbots/automatons (created)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StarEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<StarEvent>();
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::{
+    Account, CommitStatusContext, CommitStatusId, CommitStatusState, GitSha, Installation,
+    Organization, Repository,
+};
+
+/// Status event
+///
+/// A status event is sent when the status of a commit changes. Statuses are created by external
+/// systems, often third-party CI services, and are the predecessor of check runs. The payload
+/// contains the branches that point at the commit. If the repository is owned by an organization,
+/// the organization is included in the payload. If the event is sent to a GitHub App, the payload
+/// contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StatusEvent {
+    id: CommitStatusId,
+    sha: GitSha,
+    context: CommitStatusContext,
+    state: CommitStatusState,
+    description: Option<String>,
+    target_url: Option<Url>,
+    branches: Vec<StatusBranch>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+struct StatusBranch {
+    name: String,
+}
+
+impl StatusEvent {
+    /// Returns the id of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> CommitStatusId {
+        self.id
+    }
+
+    /// Returns the SHA of the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sha(&self) -> &GitSha {
+        &self.sha
+    }
+
+    /// Returns the context of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn context(&self) -> &CommitStatusContext {
+        &self.context
+    }
+
+    /// Returns the state of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> CommitStatusState {
+        self.state
+    }
+
+    /// Returns the description of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    /// Returns the URL to the details of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn target_url(&self) -> &Option<Url> {
+        &self.target_url
+    }
+
+    /// Returns the names of the branches that point at the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn branches(&self) -> Vec<&str> {
+        self.branches
+            .iter()
+            .map(|branch| branch.name.as_str())
+            .collect()
+    }
+
+    /// Returns the date when the status was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the status was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for StatusEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.context, self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::CommitStatusState;
+
+    use super::StatusEvent;
+
+    fn event() -> StatusEvent {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/status.success.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert_eq!(CommitStatusState::Success, event.state());
+        assert_eq!(vec!["main"], event.branches());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("ci/jenkins (success)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StatusEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<StatusEvent>();
+    }
+}
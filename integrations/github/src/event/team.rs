@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository, Team};
+
+/// Team action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamAction {
+    /// A team was granted access to a repository.
+    AddedToRepository,
+
+    /// A team was created.
+    Created,
+
+    /// A team was deleted.
+    Deleted,
+
+    /// A team's name, description, privacy, or parent was changed.
+    Edited,
+
+    /// A team's access to a repository was removed.
+    RemovedFromRepository,
+}
+
+/// Team event
+///
+/// A team event is sent when a team is created, edited, or deleted, and when its access to a
+/// repository changes. Events about a team's access contain the repository.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TeamEvent {
+    action: TeamAction,
+    team: Team,
+    repository: Option<Repository>,
+    organization: Organization,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl TeamEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> TeamAction {
+        self.action
+    }
+
+    /// Returns the event's team.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn team(&self) -> &Team {
+        &self.team
+    }
+
+    /// Returns the repository to which the team's access changed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Option<Repository> {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for TeamAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            TeamAction::AddedToRepository => "added_to_repository",
+            TeamAction::Created => "created",
+            TeamAction::Deleted => "deleted",
+            TeamAction::Edited => "edited",
+            TeamAction::RemovedFromRepository => "removed_from_repository",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for TeamEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.team, self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TeamAction, TeamEvent};
+
+    fn event() -> TeamEvent {
+        serde_json::from_str(include_str!("../../tests/fixtures/event/team.created.json")).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let event = event();
+
+        assert!(matches!(event.action(), TeamAction::Created));
+        assert!(event.repository().is_none());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("maintainers (created)", event().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<TeamEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<TeamEvent>();
+    }
+}
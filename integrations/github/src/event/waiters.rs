@@ -0,0 +1,220 @@
+use std::fmt::{Debug, Formatter};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::oneshot::{channel, Receiver, Sender};
+
+use automatons::Error;
+
+use crate::event::GitHubEvent;
+
+type Matcher = Box<dyn Fn(&GitHubEvent) -> bool + Send + Sync>;
+
+/// Registry of automatons that wait for an event
+///
+/// Workflows often span several webhooks, e.g. an automaton that pushes a commit and then has to
+/// wait until the checks for that commit have completed. Instead of modelling this as a state
+/// machine across separate automatons, a task can subscribe to the event that it is waiting for
+/// and suspend until the event arrives or a timeout expires.
+///
+/// The receiver of the webhooks passes every event to [`EventWaiters::dispatch`], which wakes all
+/// subscriptions whose matcher accepts the event. Tasks should subscribe before they trigger the
+/// event, so that it cannot arrive before the subscription exists.
+///
+/// Subscriptions are held in memory, so the waiting automaton and the receiver of the webhooks
+/// must run in the same process.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use automatons::Error;
+/// use automatons_github::event::{EventWaiters, GitHubEvent};
+/// use automatons_github::resource::GitSha;
+///
+/// async fn wait_for_checks(waiters: &EventWaiters, sha: GitSha) -> Result<GitHubEvent, Error> {
+///     let subscription = waiters.subscribe(move |event| match event {
+///         GitHubEvent::CheckRun(event) => event.check_run().head_sha() == &sha,
+///         _ => false,
+///     });
+///
+///     subscription.wait(Duration::from_secs(600)).await
+/// }
+/// ```
+#[derive(Default)]
+pub struct EventWaiters {
+    waiters: Mutex<Vec<(Matcher, Sender<GitHubEvent>)>>,
+}
+
+/// Subscription to an event that has not arrived yet
+///
+/// The subscription is removed from the [`EventWaiters`] when the event arrives, or when the
+/// subscription is dropped, e.g. because the wait timed out.
+#[derive(Debug)]
+pub struct EventSubscription {
+    receiver: Receiver<GitHubEvent>,
+}
+
+impl EventWaiters {
+    /// Initializes a registry without subscriptions
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes to the next event that the matcher accepts.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn subscribe<F>(&self, matcher: F) -> EventSubscription
+    where
+        F: Fn(&GitHubEvent) -> bool + Send + Sync + 'static,
+    {
+        let (sender, receiver) = channel();
+
+        self.waiters.lock().push((Box::new(matcher), sender));
+
+        EventSubscription { receiver }
+    }
+
+    /// Passes the event to all subscriptions whose matcher accepts it.
+    ///
+    /// Returns the number of subscriptions that received the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn dispatch(&self, event: &GitHubEvent) -> usize {
+        let mut waiters = self.waiters.lock();
+        let mut delivered = 0;
+
+        for (matcher, sender) in std::mem::take(&mut *waiters) {
+            if sender.is_closed() {
+                continue;
+            }
+
+            if matcher(event) {
+                if sender.send(event.clone()).is_ok() {
+                    delivered += 1;
+                }
+            } else {
+                waiters.push((matcher, sender));
+            }
+        }
+
+        delivered
+    }
+
+    /// Returns the number of subscriptions that are waiting for an event.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn pending(&self) -> usize {
+        self.waiters
+            .lock()
+            .iter()
+            .filter(|(_, sender)| !sender.is_closed())
+            .count()
+    }
+}
+
+impl Debug for EventWaiters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventWaiters")
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+impl EventSubscription {
+    /// Waits until the event arrives.
+    ///
+    /// Returns [`Error::Timeout`] if the event does not arrive within the timeout, and
+    /// [`Error::Cancelled`] if the [`EventWaiters`] have been dropped.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn wait(self, timeout: Duration) -> Result<GitHubEvent, Error> {
+        tokio::time::timeout(timeout, self.receiver)
+            .await
+            .map_err(|_| Error::Timeout(format!("event did not arrive within {:?}", timeout)))?
+            .map_err(|_| Error::Cancelled("event waiters have been dropped".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use automatons::Error;
+
+    use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+
+    use super::{EventSubscription, EventWaiters};
+
+    fn check_run_event() -> GitHubEvent {
+        let payload = include_bytes!("../../tests/fixtures/event/check_run.completed.json");
+        deserialize_event(&GitHubEventKind::CheckRun, payload).unwrap()
+    }
+
+    fn star_event() -> GitHubEvent {
+        let payload = include_bytes!("../../tests/fixtures/event/star.created.json");
+        deserialize_event(&GitHubEventKind::Star, payload).unwrap()
+    }
+
+    #[tokio::test]
+    async fn wait_returns_matching_event() {
+        let waiters = EventWaiters::new();
+        let subscription = waiters.subscribe(|event| matches!(event, GitHubEvent::CheckRun(_)));
+
+        assert_eq!(0, waiters.dispatch(&star_event()));
+        assert_eq!(1, waiters.dispatch(&check_run_event()));
+
+        let event = subscription.wait(Duration::from_secs(1)).await.unwrap();
+
+        assert!(matches!(event, GitHubEvent::CheckRun(_)));
+        assert_eq!(0, waiters.pending());
+    }
+
+    #[tokio::test]
+    async fn wait_times_out() {
+        let waiters = EventWaiters::new();
+        let subscription = waiters.subscribe(|event| matches!(event, GitHubEvent::CheckRun(_)));
+
+        waiters.dispatch(&star_event());
+
+        let error = subscription
+            .wait(Duration::from_millis(10))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Timeout(_)));
+        assert_eq!(0, waiters.pending());
+    }
+
+    #[tokio::test]
+    async fn wait_fails_when_waiters_are_dropped() {
+        let waiters = EventWaiters::new();
+        let subscription = waiters.subscribe(|_| true);
+
+        drop(waiters);
+
+        let error = subscription.wait(Duration::from_secs(1)).await.unwrap_err();
+
+        assert!(matches!(error, Error::Cancelled(_)));
+    }
+
+    #[test]
+    fn dispatch_wakes_all_matching_subscriptions() {
+        let waiters = EventWaiters::new();
+        let _first = waiters.subscribe(|_| true);
+        let _second = waiters.subscribe(|_| true);
+
+        assert_eq!(2, waiters.dispatch(&star_event()));
+        assert_eq!(0, waiters.dispatch(&star_event()));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<EventWaiters>();
+        assert_send::<EventSubscription>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<EventWaiters>();
+        assert_sync::<EventSubscription>();
+    }
+}
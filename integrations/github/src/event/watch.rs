@@ -0,0 +1,115 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{Account, Installation, Organization, Repository};
+
+/// Watch action
+///
+/// The type of activity that has occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchAction {
+    /// Someone starred a repository.
+    Started,
+}
+
+/// Watch event
+///
+/// Despite its name, the watch event is sent when someone stars a repository. It predates the star
+/// event and only supports the `started` action. If the webhook was configured for an organization,
+/// or if the repository is owned by one, the organization is included in the payload. If the event
+/// is sent to a GitHub App, the payload contains the installation.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WatchEvent {
+    action: WatchAction,
+    repository: Repository,
+    organization: Option<Organization>,
+    installation: Option<Installation>,
+    sender: Account,
+}
+
+impl WatchEvent {
+    /// Returns the event's action.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> WatchAction {
+        self.action
+    }
+
+    /// Returns the event's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository(&self) -> &Repository {
+        &self.repository
+    }
+
+    /// Returns the event's organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization(&self) -> &Option<Organization> {
+        &self.organization
+    }
+
+    /// Returns the event's installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation(&self) -> &Option<Installation> {
+        &self.installation
+    }
+
+    /// Returns the event's sender.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sender(&self) -> &Account {
+        &self.sender
+    }
+}
+
+impl Display for WatchAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            WatchAction::Started => "started",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for WatchEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.repository.full_name(), self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WatchAction, WatchEvent};
+
+    #[test]
+    fn trait_deserialize() {
+        let event: WatchEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/watch.started.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(event.action(), WatchAction::Started));
+    }
+
+    #[test]
+    fn trait_display() {
+        let event: WatchEvent = serde_json::from_str(include_str!(
+            "../../tests/fixtures/event/watch.started.json"
+        ))
+        .unwrap();
+
+        assert_eq!("devxbots/automatons (started)", event.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WatchEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WatchEvent>();
+    }
+}
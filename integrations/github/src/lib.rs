@@ -3,7 +3,8 @@
 //! The [automatons] platform is an automation framework for software developers, and this crate
 //! enables users to interact with GitHub. It defines _resources_ that closely match the resources
 //! in [GitHub's REST API](https://docs.github.com/en/rest), _tasks_ that interact with these
-//! resources, and _events_ that can be consumed by (and trigger) automations.
+//! resources, and _events_ that can be consumed by (and trigger) automations. Reusable _automatons_
+//! combine events and tasks into complete workflows.
 //!
 //! [automatons]: https://github.com/devxbots/automatons
 
@@ -11,10 +12,12 @@
 
 mod macros;
 
+pub mod automaton;
 pub mod client;
 pub mod event;
 pub mod resource;
 pub mod task;
+pub mod webhook;
 
 #[allow(missing_docs)]
 pub mod testing;
@@ -139,14 +139,14 @@ macro_rules! secret {
         $secret:ident
     ) => {
         $(#[$meta])*
-        #[derive(Clone, Debug)]
+        #[derive(Clone)]
         #[derive(serde::Deserialize)]
         pub struct $secret(secrecy::SecretString);
 
         #[allow(dead_code)]
         impl $secret {
             /// Initializes a new secret.
-            #[cfg_attr(feature = "tracing", tracing::instrument)]
+            #[cfg_attr(feature = "tracing", tracing::instrument(skip(secret)))]
             pub fn new(secret: &str) -> Self {
                 Self(secrecy::SecretString::new(String::from(secret)))
             }
@@ -159,6 +159,12 @@ macro_rules! secret {
             }
         }
 
+        impl std::fmt::Debug for $secret {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}([REDACTED])", stringify!($secret))
+            }
+        }
+
         impl std::fmt::Display for $secret {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "[REDACTED]")
@@ -166,14 +172,14 @@ macro_rules! secret {
         }
 
         impl From<&str> for $secret {
-            #[cfg_attr(feature = "tracing", tracing::instrument)]
+            #[cfg_attr(feature = "tracing", tracing::instrument(skip(secret)))]
             fn from(secret: &str) -> $secret {
                 $secret(secrecy::SecretString::new(String::from(secret)))
             }
         }
 
         impl From<String> for $secret {
-            #[cfg_attr(feature = "tracing", tracing::instrument)]
+            #[cfg_attr(feature = "tracing", tracing::instrument(skip(secret)))]
             fn from(secret: String) -> $secret {
                 $secret(secrecy::SecretString::new(secret))
             }
@@ -183,8 +189,6 @@ macro_rules! secret {
 
 #[cfg(test)]
 mod tests {
-    use crate::{id, name};
-
     id!(
         /// Identifier for tests
         TestId
@@ -239,6 +243,13 @@ mod tests {
         assert_eq!("[REDACTED]", secret.to_string());
     }
 
+    #[test]
+    fn secret_debug_redacts_value() {
+        let secret = TestSecret::new("test");
+
+        assert_eq!("TestSecret([REDACTED])", format!("{:?}", secret));
+    }
+
     #[test]
     fn secret_from_str() {
         let _secret: TestSecret = "test".into();
@@ -165,6 +165,12 @@ impl Account {
     pub fn site_admin(&self) -> bool {
         self.site_admin
     }
+
+    /// Returns the account's [`AccountType`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn account_type(&self) -> AccountType {
+        self.account_type
+    }
 }
 
 impl Display for Account {
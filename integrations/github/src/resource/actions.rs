@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{name, secret};
+
+name!(
+    /// Public key id
+    ///
+    /// GitHub identifies the public key that was used to encrypt a secret by its [`ActionsPublicKeyId`],
+    /// which must be sent along with the encrypted secret.
+    ActionsPublicKeyId
+);
+
+name!(
+    /// Name of an Actions secret
+    ///
+    /// Secrets are referenced by their name in workflows, e.g. `${{ secrets.API_TOKEN }}`.
+    ActionsSecretName
+);
+
+secret!(
+    /// Value of an Actions secret
+    ///
+    /// The value of a secret is encrypted with the repository's public key before it is sent to
+    /// GitHub, and cannot be read back through the API.
+    ActionsSecretValue
+);
+
+name!(
+    /// Name of an Actions variable
+    ///
+    /// Variables are referenced by their name in workflows, e.g. `${{ vars.ENVIRONMENT }}`.
+    ActionsVariableName
+);
+
+/// Public key for Actions secrets
+///
+/// Secrets must be encrypted with the public key of the repository, organization, or environment
+/// before they are sent to GitHub. The key is a Base64-encoded Curve25519 key for libsodium's
+/// sealed boxes.
+///
+/// Read more: https://docs.github.com/en/rest/actions/secrets#get-a-repository-public-key
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ActionsPublicKey {
+    key_id: ActionsPublicKeyId,
+    key: String,
+}
+
+/// Actions variable
+///
+/// Variables store non-sensitive configuration for workflows. Unlike secrets, their values can be
+/// read through the API.
+///
+/// Read more: https://docs.github.com/en/rest/actions/variables
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ActionsVariable {
+    name: ActionsVariableName,
+    value: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ActionsPublicKey {
+    /// Returns the key's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn key_id(&self) -> &ActionsPublicKeyId {
+        &self.key_id
+    }
+
+    /// Returns the Base64-encoded key.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl ActionsVariable {
+    /// Returns the variable's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &ActionsVariableName {
+        &self.name
+    }
+
+    /// Returns the variable's value.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the date when the variable was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the variable was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for ActionsPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key_id)
+    }
+}
+
+impl Display for ActionsVariable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionsPublicKey, ActionsVariable};
+
+    #[test]
+    fn trait_deserialize_public_key() {
+        let public_key: ActionsPublicKey = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/actions_public_key.json"
+        ))
+        .unwrap();
+
+        assert_eq!("012345678912345678", public_key.key_id().get());
+    }
+
+    #[test]
+    fn trait_deserialize_variable() {
+        let variable: ActionsVariable = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/actions_variable.json"
+        ))
+        .unwrap();
+
+        assert_eq!("USERNAME", variable.name().get());
+        assert_eq!("octocat", variable.value());
+    }
+
+    #[test]
+    fn trait_display() {
+        let variable: ActionsVariable = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/actions_variable.json"
+        ))
+        .unwrap();
+
+        assert_eq!("USERNAME", variable.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ActionsPublicKey>();
+        assert_send::<ActionsVariable>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ActionsPublicKey>();
+        assert_sync::<ActionsVariable>();
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Actions billing of an account
+///
+/// GitHub includes a number of minutes for GitHub Actions in every plan, and bills the minutes that
+/// exceed them. Minutes on macOS and Windows runners count with a multiplier towards the included
+/// minutes, while the breakdown shows the minutes that were used per operating system.
+///
+/// Read more: https://docs.github.com/en/rest/billing/billing
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ActionsBilling {
+    total_minutes_used: u64,
+    total_paid_minutes_used: u64,
+    included_minutes: u64,
+    minutes_used_breakdown: BTreeMap<String, u64>,
+}
+
+impl ActionsBilling {
+    /// Returns the number of minutes that were used in the current billing cycle.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn total_minutes_used(&self) -> u64 {
+        self.total_minutes_used
+    }
+
+    /// Returns the number of minutes that exceeded the included minutes and were billed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn total_paid_minutes_used(&self) -> u64 {
+        self.total_paid_minutes_used
+    }
+
+    /// Returns the number of minutes that are included in the account's plan.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn included_minutes(&self) -> u64 {
+        self.included_minutes
+    }
+
+    /// Returns the minutes that were used per operating system, e.g. `UBUNTU` or `MACOS`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn minutes_used_breakdown(&self) -> &BTreeMap<String, u64> {
+        &self.minutes_used_breakdown
+    }
+
+    /// Returns the number of included minutes that have not been used yet.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn remaining_included_minutes(&self) -> u64 {
+        self.included_minutes
+            .saturating_sub(self.total_minutes_used)
+    }
+}
+
+impl Display for ActionsBilling {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{} minutes",
+            self.total_minutes_used, self.included_minutes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActionsBilling;
+
+    fn billing() -> ActionsBilling {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/actions_billing.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let billing = billing();
+
+        assert_eq!(305, billing.total_minutes_used());
+        assert_eq!(Some(&205), billing.minutes_used_breakdown().get("UBUNTU"));
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("305/3000 minutes", billing().to_string());
+    }
+
+    #[test]
+    fn remaining_included_minutes() {
+        assert_eq!(2695, billing().remaining_included_minutes());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ActionsBilling>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ActionsBilling>();
+    }
+}
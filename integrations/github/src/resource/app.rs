@@ -5,7 +5,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::resource::{Account, NodeId};
+use crate::resource::{Account, Login, NodeId};
 use crate::{id, name};
 
 id!(
@@ -77,6 +77,15 @@ impl App {
         &self.slug
     }
 
+    /// Returns the login of the app's bot user, e.g. `dependabot[bot]`.
+    ///
+    /// Apps act on GitHub as a bot user, which is the sender of the events that the app triggers
+    /// with its own requests.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn bot_login(&self) -> Login {
+        Login::new(&format!("{}[bot]", self.slug))
+    }
+
     /// Returns the app's owner.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn owner(&self) -> &Account {
@@ -152,6 +161,14 @@ mod tests {
         assert_eq!("devxbots/checkbot", app.to_string());
     }
 
+    #[test]
+    fn bot_login() {
+        let app: App =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/app.json")).unwrap();
+
+        assert_eq!("devxbots-checkbot[bot]", app.bot_login().get());
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
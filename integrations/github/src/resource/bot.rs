@@ -0,0 +1,149 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use automatons::Error;
+
+use crate::resource::{Account, AccountId, AccountType, Login, NodeId};
+
+/// Bot
+///
+/// Bots represent integrations with GitHub, for example the installations of GitHub Apps that act
+/// on their own behalf. Their login ends with `[bot]`, and their profile links to the app instead
+/// of a user.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Bot {
+    login: Login,
+    id: AccountId,
+    node_id: NodeId,
+    avatar_url: Url,
+    url: Url,
+    html_url: Url,
+}
+
+impl Bot {
+    /// Returns the bot's unique [`Login`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn login(&self) -> &Login {
+        &self.login
+    }
+
+    /// Returns the bot's unique [`AccountId`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> AccountId {
+        self.id
+    }
+
+    /// Returns the bot's unique [`NodeId`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the URL to the bot's avatar.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn avatar_url(&self) -> &Url {
+        &self.avatar_url
+    }
+
+    /// Returns the API endpoint to query the bot.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the bot's app.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+}
+
+impl TryFrom<Account> for Bot {
+    type Error = Error;
+
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        if account.account_type() != AccountType::Bot {
+            return Err(Error::Serialization(format!(
+                "failed to convert {:?} account {} to a bot",
+                account.account_type(),
+                account.login()
+            )));
+        }
+
+        Ok(Self {
+            login: account.login().clone(),
+            id: account.id(),
+            node_id: account.node_id().clone(),
+            avatar_url: account.avatar_url().clone(),
+            url: account.url().clone(),
+            html_url: account.html_url().clone(),
+        })
+    }
+}
+
+impl Display for Bot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.login)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::resource::Account;
+
+    use super::Bot;
+
+    fn account(fixture: &str) -> Account {
+        serde_json::from_str(fixture).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let bot: Bot =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/bot.json")).unwrap();
+
+        assert_eq!("dependabot[bot]", bot.login().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let bot: Bot =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/bot.json")).unwrap();
+
+        assert_eq!("dependabot[bot]", bot.to_string());
+    }
+
+    #[test]
+    fn trait_try_from_account() {
+        let account = account(include_str!("../../tests/fixtures/resource/bot.json"));
+
+        let bot = Bot::try_from(account).unwrap();
+
+        assert_eq!(49699333, bot.id().get());
+    }
+
+    #[test]
+    fn trait_try_from_user_account() {
+        let account = account(include_str!("../../tests/fixtures/resource/user.json"));
+
+        let error = Bot::try_from(account).unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Bot>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Bot>();
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::id;
+use crate::resource::RepositoryId;
+
+id!(
+    /// Branch protection rule id
+    ///
+    /// The [`BranchProtectionRuleId`] is a unique, numerical id that identifies a branch protection
+    /// rule.
+    BranchProtectionRuleId
+);
+
+/// Enforcement level of a branch protection setting
+///
+/// Branch protection rules can enforce their settings for everyone, or exempt administrators from
+/// them.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcementLevel {
+    /// The setting is not enforced
+    Off,
+
+    /// The setting is enforced for everyone except administrators
+    NonAdmins,
+
+    /// The setting is enforced for everyone
+    Everyone,
+}
+
+/// Branch protection rule
+///
+/// Branch protection rules enforce workflows for branches whose name matches the rule's pattern,
+/// for example that pull requests must be reviewed or that status checks must pass before they
+/// can be merged. Webhook events represent the rule with the enforcement level of each setting.
+///
+/// Read more: https://docs.github.com/en/repositories/configuring-branches-and-merges-in-your-repository/managing-protected-branches/about-protected-branches
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BranchProtectionRule {
+    id: BranchProtectionRuleId,
+    repository_id: RepositoryId,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    pull_request_reviews_enforcement_level: EnforcementLevel,
+    required_approving_review_count: u32,
+    dismiss_stale_reviews_on_push: bool,
+    require_code_owner_review: bool,
+    required_status_checks: Vec<String>,
+    required_status_checks_enforcement_level: EnforcementLevel,
+    strict_required_status_checks_policy: bool,
+    signature_requirement_enforcement_level: EnforcementLevel,
+    linear_history_requirement_enforcement_level: EnforcementLevel,
+    required_conversation_resolution_level: EnforcementLevel,
+    allow_force_pushes_enforcement_level: EnforcementLevel,
+    allow_deletions_enforcement_level: EnforcementLevel,
+    admin_enforced: bool,
+}
+
+impl BranchProtectionRule {
+    /// Returns the rule's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> BranchProtectionRuleId {
+        self.id
+    }
+
+    /// Returns the id of the rule's repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository_id(&self) -> RepositoryId {
+        self.repository_id
+    }
+
+    /// Returns the pattern of the branches that the rule protects, e.g. `main` or `release/*`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the date when the rule was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the rule was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the enforcement level of required pull request reviews.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pull_request_reviews_enforcement_level(&self) -> EnforcementLevel {
+        self.pull_request_reviews_enforcement_level
+    }
+
+    /// Returns the number of approving reviews that a pull request requires.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn required_approving_review_count(&self) -> u32 {
+        self.required_approving_review_count
+    }
+
+    /// Indicates whether approvals are dismissed when new commits are pushed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismiss_stale_reviews_on_push(&self) -> bool {
+        self.dismiss_stale_reviews_on_push
+    }
+
+    /// Indicates whether pull requests require a review from a code owner.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn require_code_owner_review(&self) -> bool {
+        self.require_code_owner_review
+    }
+
+    /// Returns the names of the status checks that must pass.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn required_status_checks(&self) -> &Vec<String> {
+        &self.required_status_checks
+    }
+
+    /// Returns the enforcement level of required status checks.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn required_status_checks_enforcement_level(&self) -> EnforcementLevel {
+        self.required_status_checks_enforcement_level
+    }
+
+    /// Indicates whether branches must be up to date before they can be merged.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn strict_required_status_checks_policy(&self) -> bool {
+        self.strict_required_status_checks_policy
+    }
+
+    /// Returns the enforcement level of required commit signatures.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn signature_requirement_enforcement_level(&self) -> EnforcementLevel {
+        self.signature_requirement_enforcement_level
+    }
+
+    /// Returns the enforcement level of a linear history.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn linear_history_requirement_enforcement_level(&self) -> EnforcementLevel {
+        self.linear_history_requirement_enforcement_level
+    }
+
+    /// Returns the enforcement level of resolved conversations before merging.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn required_conversation_resolution_level(&self) -> EnforcementLevel {
+        self.required_conversation_resolution_level
+    }
+
+    /// Returns the enforcement level of force pushes, which are allowed when it is not `off`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn allow_force_pushes_enforcement_level(&self) -> EnforcementLevel {
+        self.allow_force_pushes_enforcement_level
+    }
+
+    /// Returns the enforcement level of deletions, which are allowed when it is not `off`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn allow_deletions_enforcement_level(&self) -> EnforcementLevel {
+        self.allow_deletions_enforcement_level
+    }
+
+    /// Indicates whether the rule is enforced for administrators.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn admin_enforced(&self) -> bool {
+        self.admin_enforced
+    }
+}
+
+impl Display for EnforcementLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            EnforcementLevel::Off => "off",
+            EnforcementLevel::NonAdmins => "non_admins",
+            EnforcementLevel::Everyone => "everyone",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for BranchProtectionRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BranchProtectionRule, EnforcementLevel};
+
+    fn rule() -> BranchProtectionRule {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/branch_protection_rule.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let rule = rule();
+
+        assert_eq!(1, rule.required_approving_review_count());
+        assert_eq!(
+            EnforcementLevel::Everyone,
+            rule.pull_request_reviews_enforcement_level()
+        );
+        assert_eq!(&vec!["test".to_string()], rule.required_status_checks());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("main", rule().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<BranchProtectionRule>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<BranchProtectionRule>();
+    }
+}
@@ -0,0 +1,172 @@
+use crate::resource::{CheckRun, CheckRunConclusion, CommitStatus};
+
+/// Result of a check for a commit
+///
+/// GitHub reports the results of checks for a commit in two ways: GitHub Apps create check runs,
+/// while older integrations and many third-party CI services create commit statuses. The
+/// [`CheckLike`] trait provides a unified interface to both, so that automatons which gate merges
+/// can treat them the same way.
+pub trait CheckLike {
+    /// Returns the name of the check, i.e. the name of a check run or the context of a status.
+    fn check_name(&self) -> &str;
+
+    /// Returns the conclusion of the check, or `None` while the check has not finished yet.
+    fn check_conclusion(&self) -> Option<CheckRunConclusion>;
+}
+
+impl CheckLike for CheckRun {
+    fn check_name(&self) -> &str {
+        self.name().get()
+    }
+
+    fn check_conclusion(&self) -> Option<CheckRunConclusion> {
+        self.conclusion()
+    }
+}
+
+impl CheckLike for CommitStatus {
+    fn check_name(&self) -> &str {
+        self.context().get()
+    }
+
+    fn check_conclusion(&self) -> Option<CheckRunConclusion> {
+        self.state().conclusion()
+    }
+}
+
+/// Computes the overall conclusion of the checks for a commit.
+///
+/// A single failing check fails the commit, even if other checks are still running. Otherwise,
+/// the commit is pending and `None` is returned until all checks have finished. Finished commits
+/// succeed if at least one check succeeded, and are neutral if all checks were neutral or skipped
+/// or if the commit has no checks at all.
+///
+/// Statuses with the same context replace each other, so only the latest status for each context
+/// should be passed to this function.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(checks)))]
+pub fn aggregate_conclusion<'a, I>(checks: I) -> Option<CheckRunConclusion>
+where
+    I: IntoIterator<Item = &'a dyn CheckLike>,
+{
+    let conclusions: Vec<Option<CheckRunConclusion>> = checks
+        .into_iter()
+        .map(|check| check.check_conclusion())
+        .collect();
+
+    if let Some(failure) = CheckRunConclusion::worst(conclusions.iter().flatten().copied())
+        .filter(|conclusion| conclusion.is_failure())
+    {
+        return Some(failure);
+    }
+
+    if conclusions.contains(&None) {
+        return None;
+    }
+
+    if conclusions.contains(&Some(CheckRunConclusion::Success)) {
+        Some(CheckRunConclusion::Success)
+    } else {
+        Some(CheckRunConclusion::Neutral)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{CheckRun, CheckRunConclusion, CommitStatus};
+
+    use super::{aggregate_conclusion, CheckLike};
+
+    struct Check(Option<CheckRunConclusion>);
+
+    impl CheckLike for Check {
+        fn check_name(&self) -> &str {
+            "check"
+        }
+
+        fn check_conclusion(&self) -> Option<CheckRunConclusion> {
+            self.0
+        }
+    }
+
+    fn aggregate(checks: &[Check]) -> Option<CheckRunConclusion> {
+        aggregate_conclusion(checks.iter().map(|check| check as &dyn CheckLike))
+    }
+
+    #[test]
+    fn check_run() {
+        let check_run: CheckRun =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/check_run.json"))
+                .unwrap();
+
+        assert_eq!(check_run.name().get(), check_run.check_name());
+        assert_eq!(check_run.conclusion(), check_run.check_conclusion());
+    }
+
+    #[test]
+    fn commit_status() {
+        let status: CommitStatus = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_status.json"
+        ))
+        .unwrap();
+
+        assert_eq!("ci/jenkins", status.check_name());
+        assert_eq!(Some(CheckRunConclusion::Success), status.check_conclusion());
+    }
+
+    #[test]
+    fn aggregate_conclusion_mixes_check_runs_and_statuses() {
+        let check_run: CheckRun =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/check_run.json"))
+                .unwrap();
+        let status: CommitStatus = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_status.json"
+        ))
+        .unwrap();
+
+        let checks: Vec<&dyn CheckLike> = vec![&check_run, &status];
+
+        assert_eq!(
+            Some(CheckRunConclusion::Success),
+            aggregate_conclusion(checks)
+        );
+    }
+
+    #[test]
+    fn aggregate_conclusion_fails_with_running_checks() {
+        let checks = [Check(None), Check(Some(CheckRunConclusion::Failure))];
+
+        assert_eq!(Some(CheckRunConclusion::Failure), aggregate(&checks));
+    }
+
+    #[test]
+    fn aggregate_conclusion_prefers_most_severe_failure() {
+        let checks = [
+            Check(Some(CheckRunConclusion::Cancelled)),
+            Check(Some(CheckRunConclusion::TimedOut)),
+        ];
+
+        assert_eq!(Some(CheckRunConclusion::TimedOut), aggregate(&checks));
+    }
+
+    #[test]
+    fn aggregate_conclusion_is_pending() {
+        let checks = [Check(None), Check(Some(CheckRunConclusion::Success))];
+
+        assert_eq!(None, aggregate(&checks));
+    }
+
+    #[test]
+    fn aggregate_conclusion_succeeds() {
+        let checks = [
+            Check(Some(CheckRunConclusion::Skipped)),
+            Check(Some(CheckRunConclusion::Success)),
+        ];
+
+        assert_eq!(Some(CheckRunConclusion::Success), aggregate(&checks));
+    }
+
+    #[test]
+    fn aggregate_conclusion_without_checks() {
+        assert_eq!(Some(CheckRunConclusion::Neutral), aggregate(&[]));
+    }
+}
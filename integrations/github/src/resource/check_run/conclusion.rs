@@ -35,6 +35,51 @@ pub enum CheckRunConclusion {
     Stale,
 }
 
+impl CheckRunConclusion {
+    /// Indicates whether the conclusion fails the check, e.g. because it failed or timed out.
+    ///
+    /// Neutral and skipped check runs do not fail, since GitHub treats them like successful ones
+    /// when it evaluates branch protection rules.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            CheckRunConclusion::Failure
+                | CheckRunConclusion::TimedOut
+                | CheckRunConclusion::Cancelled
+                | CheckRunConclusion::ActionRequired
+                | CheckRunConclusion::Stale
+        )
+    }
+
+    /// Returns the most severe of the conclusions, or `None` if there are none.
+    ///
+    /// Conclusions are ordered from skipped, success, and neutral to stale, action required,
+    /// cancelled, failure, and timed out, which is the most severe conclusion.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(conclusions)))]
+    pub fn worst<I>(conclusions: I) -> Option<CheckRunConclusion>
+    where
+        I: IntoIterator<Item = CheckRunConclusion>,
+    {
+        conclusions
+            .into_iter()
+            .max_by_key(|conclusion| conclusion.severity())
+    }
+
+    fn severity(&self) -> u8 {
+        match self {
+            CheckRunConclusion::Skipped => 0,
+            CheckRunConclusion::Success => 1,
+            CheckRunConclusion::Neutral => 2,
+            CheckRunConclusion::Stale => 3,
+            CheckRunConclusion::ActionRequired => 4,
+            CheckRunConclusion::Cancelled => 5,
+            CheckRunConclusion::Failure => 6,
+            CheckRunConclusion::TimedOut => 7,
+        }
+    }
+}
+
 impl Display for CheckRunConclusion {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let string_representation = match self {
@@ -56,6 +101,57 @@ impl Display for CheckRunConclusion {
 mod tests {
     use super::CheckRunConclusion;
 
+    #[test]
+    fn is_failure() {
+        assert!(CheckRunConclusion::TimedOut.is_failure());
+        assert!(!CheckRunConclusion::Neutral.is_failure());
+        assert!(!CheckRunConclusion::Skipped.is_failure());
+    }
+
+    #[test]
+    fn worst_returns_most_severe_conclusion() {
+        let conclusions = [
+            CheckRunConclusion::Success,
+            CheckRunConclusion::Cancelled,
+            CheckRunConclusion::Failure,
+            CheckRunConclusion::TimedOut,
+        ];
+
+        assert_eq!(
+            Some(CheckRunConclusion::TimedOut),
+            CheckRunConclusion::worst(conclusions)
+        );
+    }
+
+    #[test]
+    fn worst_prefers_failure_over_neutral() {
+        let conclusions = [CheckRunConclusion::Neutral, CheckRunConclusion::Failure];
+
+        assert_eq!(
+            Some(CheckRunConclusion::Failure),
+            CheckRunConclusion::worst(conclusions)
+        );
+    }
+
+    #[test]
+    fn worst_prefers_neutral_over_success() {
+        let conclusions = [
+            CheckRunConclusion::Neutral,
+            CheckRunConclusion::Success,
+            CheckRunConclusion::Skipped,
+        ];
+
+        assert_eq!(
+            Some(CheckRunConclusion::Neutral),
+            CheckRunConclusion::worst(conclusions)
+        );
+    }
+
+    #[test]
+    fn worst_without_conclusions() {
+        assert_eq!(None, CheckRunConclusion::worst([]));
+    }
+
     #[test]
     fn trait_deserialize() {
         let conclusion: CheckRunConclusion = serde_json::from_str(r#""action_required""#).unwrap();
@@ -20,6 +20,14 @@ pub enum CheckRunStatus {
     Completed,
 }
 
+impl CheckRunStatus {
+    /// Indicates whether the check run has finished, after which its status no longer changes.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, CheckRunStatus::Completed)
+    }
+}
+
 impl Display for CheckRunStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let string_representation = match self {
@@ -36,6 +44,12 @@ impl Display for CheckRunStatus {
 mod tests {
     use super::CheckRunStatus;
 
+    #[test]
+    fn is_terminal() {
+        assert!(CheckRunStatus::Completed.is_terminal());
+        assert!(!CheckRunStatus::InProgress.is_terminal());
+    }
+
     #[test]
     fn trait_deserialize() {
         let status: CheckRunStatus = serde_json::from_str(r#""in_progress""#).unwrap();
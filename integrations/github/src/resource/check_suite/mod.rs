@@ -10,8 +10,10 @@ use crate::resource::{
 };
 
 pub use self::minimal::MinimalCheckSuite;
+pub use self::preferences::{AutoTriggerCheck, CheckSuitePreferences};
 
 mod minimal;
+mod preferences;
 
 id!(
     /// Check suite id
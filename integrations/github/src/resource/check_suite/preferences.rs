@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::AppId;
+
+/// Check suite preferences of a repository
+///
+/// GitHub creates a check suite for every app that has the `checks:write` permission whenever code
+/// is pushed to a repository. Apps can disable the automatic creation of check suites for a
+/// repository, and create check suites themselves when they are needed.
+///
+/// Read more: https://docs.github.com/en/rest/checks/suites#update-repository-preferences-for-check-suites
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CheckSuitePreferences {
+    auto_trigger_checks: Vec<AutoTriggerCheck>,
+}
+
+/// Automatic creation of check suites for an app
+///
+/// The setting determines whether GitHub automatically creates check suites for an app.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct AutoTriggerCheck {
+    app_id: AppId,
+    setting: bool,
+}
+
+impl CheckSuitePreferences {
+    /// Returns the settings for the apps that have changed the default.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn auto_trigger_checks(&self) -> &Vec<AutoTriggerCheck> {
+        &self.auto_trigger_checks
+    }
+
+    /// Indicates whether GitHub automatically creates check suites for the app.
+    ///
+    /// Check suites are created automatically unless the app has disabled them.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_auto_triggered(&self, app_id: AppId) -> bool {
+        self.auto_trigger_checks
+            .iter()
+            .find(|check| check.app_id == app_id)
+            .is_none_or(|check| check.setting)
+    }
+}
+
+impl AutoTriggerCheck {
+    /// Returns the id of the app.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app_id(&self) -> AppId {
+        self.app_id
+    }
+
+    /// Indicates whether check suites are created automatically for the app.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn setting(&self) -> bool {
+        self.setting
+    }
+}
+
+impl Display for CheckSuitePreferences {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let settings: Vec<String> = self
+            .auto_trigger_checks
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        write!(f, "{}", settings.join(", "))
+    }
+}
+
+impl Display for AutoTriggerCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.app_id, self.setting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::AppId;
+
+    use super::CheckSuitePreferences;
+
+    const JSON: &str = r#"
+    {
+        "auto_trigger_checks": [
+            { "app_id": 2, "setting": true },
+            { "app_id": 4, "setting": false }
+        ]
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let preferences: CheckSuitePreferences = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!(2, preferences.auto_trigger_checks().len());
+    }
+
+    #[test]
+    fn trait_display() {
+        let preferences: CheckSuitePreferences = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("2: true, 4: false", preferences.to_string());
+    }
+
+    #[test]
+    fn is_auto_triggered() {
+        let preferences: CheckSuitePreferences = serde_json::from_str(JSON).unwrap();
+
+        assert!(preferences.is_auto_triggered(AppId::new(2)));
+        assert!(!preferences.is_auto_triggered(AppId::new(4)));
+        assert!(preferences.is_auto_triggered(AppId::new(8)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CheckSuitePreferences>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CheckSuitePreferences>();
+    }
+}
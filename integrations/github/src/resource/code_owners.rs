@@ -0,0 +1,280 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::name;
+
+name!(
+    /// Code owner
+    ///
+    /// Code owners are referenced by their username (`@octocat`), team (`@octo-org/octocats`), or
+    /// email address (`octocat@github.com`).
+    CodeOwner
+);
+
+/// Code owners of a repository
+///
+/// The `CODEOWNERS` file assigns individuals or teams as the owners of the code in a repository.
+/// Each line of the file consists of a pattern that uses the same rules as `.gitignore` files,
+/// followed by the owners of the matching paths. When multiple lines match a path, the last one
+/// takes precedence.
+///
+/// Read more: https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct CodeOwners {
+    rules: Vec<CodeOwnersRule>,
+}
+
+/// Rule in a `CODEOWNERS` file
+///
+/// Rules assign owners to the paths that match their pattern. A rule without owners removes the
+/// ownership of the matching paths.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeOwnersRule {
+    pattern: String,
+    owners: Vec<CodeOwner>,
+}
+
+impl CodeOwners {
+    /// Parses the content of a `CODEOWNERS` file.
+    ///
+    /// Blank lines and comments are skipped. Lines that start with an escaped `\#` are parsed as
+    /// patterns that start with `#`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let mut tokens = line
+                    .split_whitespace()
+                    .take_while(|token| !token.starts_with('#'));
+
+                let pattern = tokens.next()?;
+                let pattern = pattern.strip_prefix('\\').unwrap_or(pattern);
+
+                Some(CodeOwnersRule {
+                    pattern: pattern.into(),
+                    owners: tokens.map(CodeOwner::new).collect(),
+                })
+            })
+            .collect();
+
+        Self { rules }
+    }
+
+    /// Returns the rules in the order in which they appear in the file.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rules(&self) -> &Vec<CodeOwnersRule> {
+        &self.rules
+    }
+
+    /// Returns the owners of the given path.
+    ///
+    /// The path is relative to the root of the repository. Paths without a matching rule have no
+    /// owners.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn owners(&self, path: &str) -> &[CodeOwner] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(path))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl CodeOwnersRule {
+    /// Returns the rule's pattern.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the owners that the rule assigns.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn owners(&self) -> &Vec<CodeOwner> {
+        &self.owners
+    }
+
+    /// Indicates whether the rule's pattern matches the given path.
+    ///
+    /// Patterns that match a directory match all files below it, except for patterns that end in
+    /// `/*`, which only match the files directly inside the directory.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn matches(&self, path: &str) -> bool {
+        let pattern = self.pattern.as_str();
+
+        let directory_only = pattern.ends_with('/');
+        let pattern = pattern.trim_end_matches('/');
+
+        // Patterns that contain a slash are relative to the root of the repository, while other
+        // patterns match at any depth.
+        let anchored = pattern.contains('/');
+        let pattern = pattern.trim_start_matches('/');
+
+        let mut segments: Vec<&str> = Vec::new();
+        if !anchored {
+            segments.push("**");
+        }
+        segments.extend(pattern.split('/'));
+
+        let path: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        if !directory_only && match_segments(&segments, &path) {
+            return true;
+        }
+
+        if segments.last() == Some(&"*") {
+            return false;
+        }
+
+        (1..path.len()).any(|depth| match_segments(&segments, &path[..depth]))
+    }
+}
+
+impl Display for CodeOwnersRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)?;
+
+        for owner in &self.owners {
+            write!(f, " {}", owner)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path)) => {
+                match_wildcards(segment.as_bytes(), name.as_bytes()) && match_segments(rest, path)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_wildcards(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| match_wildcards(rest, &name[skip..])),
+        Some((b'?', rest)) => !name.is_empty() && match_wildcards(rest, &name[1..]),
+        Some((character, rest)) => {
+            name.first() == Some(character) && match_wildcards(rest, &name[1..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeOwner, CodeOwners};
+
+    const CODEOWNERS: &str = r#"
+# Default owners for everything in the repository
+*       @global-owner1 @global-owner2
+
+*.js    @js-owner # Owners of JavaScript files
+/build/logs/ @doctocat
+docs/*  docs@example.com
+apps/   @octocat
+/scripts/ @doctocat @octocat
+/apps/github
+"#;
+
+    fn owners(code_owners: &CodeOwners, path: &str) -> Vec<String> {
+        code_owners
+            .owners(path)
+            .iter()
+            .map(CodeOwner::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn parse_skips_comments() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(7, code_owners.rules().len());
+        assert_eq!("*.js @js-owner", code_owners.rules()[1].to_string());
+    }
+
+    #[test]
+    fn owners_with_default_rule() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(
+            vec!["@global-owner1", "@global-owner2"],
+            owners(&code_owners, "README.md")
+        );
+    }
+
+    #[test]
+    fn owners_with_extension() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(vec!["@js-owner"], owners(&code_owners, "src/lib/index.js"));
+    }
+
+    #[test]
+    fn owners_with_anchored_directory() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(
+            vec!["@doctocat"],
+            owners(&code_owners, "build/logs/today.log")
+        );
+        assert_eq!(
+            vec!["@global-owner1", "@global-owner2"],
+            owners(&code_owners, "src/build/logs/today.log")
+        );
+    }
+
+    #[test]
+    fn owners_with_direct_children() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(
+            vec!["docs@example.com"],
+            owners(&code_owners, "docs/getting-started.md")
+        );
+        assert_eq!(
+            vec!["@global-owner1", "@global-owner2"],
+            owners(&code_owners, "docs/build-app/troubleshooting.md")
+        );
+    }
+
+    #[test]
+    fn owners_with_directory_at_any_depth() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(vec!["@octocat"], owners(&code_owners, "src/apps/main.rs"));
+    }
+
+    #[test]
+    fn owners_with_unset_owners() {
+        let code_owners = CodeOwners::parse(CODEOWNERS);
+
+        assert!(owners(&code_owners, "apps/github/main.rs").is_empty());
+    }
+
+    #[test]
+    fn owners_without_rules() {
+        let code_owners = CodeOwners::default();
+
+        assert!(owners(&code_owners, "README.md").is_empty());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeOwners>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeOwners>();
+    }
+}
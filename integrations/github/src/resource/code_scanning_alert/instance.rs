@@ -0,0 +1,171 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{CodeScanningAlertState, GitRef, GitSha};
+
+/// Location of a code scanning alert
+///
+/// Code scanning alerts point to the location in the source code where the problem was found.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeScanningAlertLocation {
+    path: String,
+    start_line: u64,
+    end_line: u64,
+    start_column: u64,
+    end_column: u64,
+}
+
+/// Instance of a code scanning alert
+///
+/// The same alert can be found on different branches and in different analyses. Each occurrence is
+/// an instance of the alert.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeScanningAlertInstance {
+    #[serde(rename = "ref")]
+    git_ref: GitRef,
+    analysis_key: String,
+    category: Option<String>,
+    state: CodeScanningAlertState,
+    commit_sha: Option<GitSha>,
+    location: Option<CodeScanningAlertLocation>,
+}
+
+impl CodeScanningAlertLocation {
+    /// Returns the path of the file.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the first line of the location.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn start_line(&self) -> u64 {
+        self.start_line
+    }
+
+    /// Returns the last line of the location.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn end_line(&self) -> u64 {
+        self.end_line
+    }
+
+    /// Returns the first column of the location.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn start_column(&self) -> u64 {
+        self.start_column
+    }
+
+    /// Returns the last column of the location.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn end_column(&self) -> u64 {
+        self.end_column
+    }
+}
+
+impl CodeScanningAlertInstance {
+    /// Returns the Git reference on which the instance was found.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn git_ref(&self) -> &GitRef {
+        &self.git_ref
+    }
+
+    /// Returns the key of the analysis that found the instance.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn analysis_key(&self) -> &str {
+        &self.analysis_key
+    }
+
+    /// Returns the category of the analysis that found the instance.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn category(&self) -> &Option<String> {
+        &self.category
+    }
+
+    /// Returns the instance's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> CodeScanningAlertState {
+        self.state
+    }
+
+    /// Returns the SHA of the commit in which the instance was found.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn commit_sha(&self) -> &Option<GitSha> {
+        &self.commit_sha
+    }
+
+    /// Returns the instance's location.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn location(&self) -> &Option<CodeScanningAlertLocation> {
+        &self.location
+    }
+}
+
+impl Display for CodeScanningAlertLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.path, self.start_line)
+    }
+}
+
+impl Display for CodeScanningAlertInstance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.git_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeScanningAlertInstance;
+
+    const JSON: &str = r#"
+    {
+        "ref": "refs/heads/main",
+        "analysis_key": ".github/workflows/codeql.yml:analyze",
+        "environment": "{}",
+        "category": ".github/workflows/codeql.yml:analyze",
+        "state": "open",
+        "commit_sha": "24275d56a48c2dc73ae191524e20b19c41676b4f",
+        "message": {
+            "text": "Unsafe block detected."
+        },
+        "location": {
+            "path": "src/lib.rs",
+            "start_line": 12,
+            "end_line": 14,
+            "start_column": 5,
+            "end_column": 6
+        },
+        "classifications": []
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let instance: CodeScanningAlertInstance = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("refs/heads/main", instance.git_ref().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let instance: CodeScanningAlertInstance = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("refs/heads/main", instance.to_string());
+        assert_eq!(
+            "src/lib.rs:12",
+            instance.location().as_ref().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningAlertInstance>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningAlertInstance>();
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::Account;
+
+pub use self::instance::{CodeScanningAlertInstance, CodeScanningAlertLocation};
+pub use self::rule::{CodeScanningRule, CodeScanningRuleId, CodeScanningRuleSeverity};
+pub use self::state::{CodeScanningAlertDismissedReason, CodeScanningAlertState};
+pub use self::tool::{CodeScanningTool, CodeScanningToolName};
+
+mod instance;
+mod rule;
+mod state;
+mod tool;
+
+id!(
+    /// Code scanning alert number
+    ///
+    /// Every [`CodeScanningAlert`] has a unique, monotonically increasing number that identifies the
+    /// alert within its repository.
+    CodeScanningAlertNumber
+);
+
+/// Code scanning alert
+///
+/// Code scanning analyzes the code in a repository with tools like CodeQL, and opens an alert for
+/// every problem that a tool reports. Third-party tools can publish their results to code scanning
+/// by uploading them in the SARIF format.
+///
+/// Read more: https://docs.github.com/en/rest/code-scanning
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeScanningAlert {
+    number: CodeScanningAlertNumber,
+    url: Url,
+    html_url: Url,
+    state: CodeScanningAlertState,
+    rule: CodeScanningRule,
+    tool: CodeScanningTool,
+    most_recent_instance: Option<CodeScanningAlertInstance>,
+    created_at: DateTime<Utc>,
+    fixed_at: Option<DateTime<Utc>>,
+    dismissed_at: Option<DateTime<Utc>>,
+    dismissed_by: Option<Account>,
+    dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+}
+
+impl CodeScanningAlert {
+    /// Returns the alert's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> CodeScanningAlertNumber {
+        self.number
+    }
+
+    /// Returns the API endpoint to query the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the alert's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> CodeScanningAlertState {
+        self.state
+    }
+
+    /// Returns the rule that was violated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rule(&self) -> &CodeScanningRule {
+        &self.rule
+    }
+
+    /// Returns the tool that created the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn tool(&self) -> &CodeScanningTool {
+        &self.tool
+    }
+
+    /// Returns the most recent instance of the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn most_recent_instance(&self) -> &Option<CodeScanningAlertInstance> {
+        &self.most_recent_instance
+    }
+
+    /// Returns the date when the alert was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the alert was fixed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn fixed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.fixed_at
+    }
+
+    /// Returns the date when the alert was dismissed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.dismissed_at
+    }
+
+    /// Returns the account that dismissed the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_by(&self) -> &Option<Account> {
+        &self.dismissed_by
+    }
+
+    /// Returns the reason why the alert was dismissed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_reason(&self) -> Option<CodeScanningAlertDismissedReason> {
+        self.dismissed_reason
+    }
+}
+
+impl Display for CodeScanningAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeScanningAlert, CodeScanningAlertState};
+
+    #[test]
+    fn trait_deserialize() {
+        let alert: CodeScanningAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/code_scanning_alert.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(alert.state(), CodeScanningAlertState::Open));
+    }
+
+    #[test]
+    fn trait_display() {
+        let alert: CodeScanningAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/code_scanning_alert.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#3", alert.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningAlert>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningAlert>();
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::name;
+use crate::resource::SecurityAdvisorySeverity;
+
+name!(
+    /// Code scanning rule id
+    ///
+    /// Analysis tools identify the rules that they check with a unique id, for example
+    /// `js/unused-local-variable`.
+    CodeScanningRuleId
+);
+
+/// Severity of a code scanning rule
+///
+/// Analysis tools classify the rules that they check by severity. Rules that detect security
+/// vulnerabilities additionally have a security severity level.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeScanningRuleSeverity {
+    /// No severity
+    None,
+
+    /// Note
+    Note,
+
+    /// Warning
+    Warning,
+
+    /// Error
+    Error,
+}
+
+/// Code scanning rule
+///
+/// Code scanning alerts are created when an analysis tool detects a violation of one of its rules.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeScanningRule {
+    id: Option<CodeScanningRuleId>,
+    severity: Option<CodeScanningRuleSeverity>,
+    security_severity_level: Option<SecurityAdvisorySeverity>,
+    description: String,
+}
+
+impl CodeScanningRule {
+    /// Returns the rule's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> &Option<CodeScanningRuleId> {
+        &self.id
+    }
+
+    /// Returns the rule's severity.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn severity(&self) -> Option<CodeScanningRuleSeverity> {
+        self.severity
+    }
+
+    /// Returns the rule's security severity level.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn security_severity_level(&self) -> Option<SecurityAdvisorySeverity> {
+        self.security_severity_level
+    }
+
+    /// Returns the rule's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl Display for CodeScanningRuleSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CodeScanningRuleSeverity::None => "none",
+            CodeScanningRuleSeverity::Note => "note",
+            CodeScanningRuleSeverity::Warning => "warning",
+            CodeScanningRuleSeverity::Error => "error",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for CodeScanningRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "{}", id),
+            None => write!(f, "{}", self.description),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeScanningRule, CodeScanningRuleSeverity};
+
+    const JSON: &str = r#"
+    {
+        "id": "rust/unsafe-block",
+        "severity": "warning",
+        "security_severity_level": null,
+        "description": "Unsafe block",
+        "name": "rust/unsafe-block",
+        "tags": ["security"]
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let rule: CodeScanningRule = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!(Some(CodeScanningRuleSeverity::Warning), rule.severity());
+    }
+
+    #[test]
+    fn trait_display() {
+        let rule: CodeScanningRule = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("rust/unsafe-block", rule.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningRule>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningRule>();
+    }
+}
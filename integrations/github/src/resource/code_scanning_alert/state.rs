@@ -0,0 +1,107 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Code scanning alert state
+///
+/// Code scanning alerts are opened when an analysis reports a problem. They are closed when the
+/// problem is no longer reported, or when a user dismisses them.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeScanningAlertState {
+    /// Alert is closed
+    Closed,
+
+    /// Alert was dismissed by a user
+    Dismissed,
+
+    /// Alert was fixed
+    Fixed,
+
+    /// Alert is open
+    Open,
+}
+
+/// Reason for dismissing a code scanning alert
+///
+/// When a user dismisses a code scanning alert, they have to provide a reason for the dismissal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum CodeScanningAlertDismissedReason {
+    /// The alert is a false positive
+    #[serde(rename = "false positive")]
+    FalsePositive,
+
+    /// The alert will not be fixed
+    #[serde(rename = "won't fix")]
+    WontFix,
+
+    /// The alert is in code that is only used in tests
+    #[serde(rename = "used in tests")]
+    UsedInTests,
+}
+
+impl Display for CodeScanningAlertState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CodeScanningAlertState::Closed => "closed",
+            CodeScanningAlertState::Dismissed => "dismissed",
+            CodeScanningAlertState::Fixed => "fixed",
+            CodeScanningAlertState::Open => "open",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for CodeScanningAlertDismissedReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CodeScanningAlertDismissedReason::FalsePositive => "false positive",
+            CodeScanningAlertDismissedReason::WontFix => "won't fix",
+            CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeScanningAlertDismissedReason, CodeScanningAlertState};
+
+    #[test]
+    fn trait_deserialize() {
+        let state: CodeScanningAlertState = serde_json::from_str(r#""dismissed""#).unwrap();
+
+        assert!(matches!(state, CodeScanningAlertState::Dismissed));
+    }
+
+    #[test]
+    fn trait_deserialize_dismissed_reason() {
+        let reason: CodeScanningAlertDismissedReason =
+            serde_json::from_str(r#""won't fix""#).unwrap();
+
+        assert!(matches!(reason, CodeScanningAlertDismissedReason::WontFix));
+    }
+
+    #[test]
+    fn trait_display() {
+        let reason = CodeScanningAlertDismissedReason::UsedInTests;
+
+        assert_eq!("used in tests", reason.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningAlertState>();
+        assert_send::<CodeScanningAlertDismissedReason>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningAlertState>();
+        assert_sync::<CodeScanningAlertDismissedReason>();
+    }
+}
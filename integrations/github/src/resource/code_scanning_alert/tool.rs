@@ -0,0 +1,88 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::name;
+
+name!(
+    /// Code scanning tool name
+    ///
+    /// The name of the analysis tool that created a code scanning alert, for example `CodeQL`.
+    CodeScanningToolName
+);
+
+/// Code scanning tool
+///
+/// Code scanning alerts are created by analysis tools. GitHub's own tool is CodeQL, but third-party
+/// tools can upload their results in the SARIF format as well.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeScanningTool {
+    name: CodeScanningToolName,
+    version: Option<String>,
+    guid: Option<String>,
+}
+
+impl CodeScanningTool {
+    /// Returns the tool's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &CodeScanningToolName {
+        &self.name
+    }
+
+    /// Returns the tool's version.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn version(&self) -> &Option<String> {
+        &self.version
+    }
+
+    /// Returns the tool's GUID.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn guid(&self) -> &Option<String> {
+        &self.guid
+    }
+}
+
+impl Display for CodeScanningTool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeScanningTool;
+
+    const JSON: &str = r#"
+    {
+        "name": "clippy",
+        "guid": null,
+        "version": "0.1.63"
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let tool: CodeScanningTool = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!(&Some("0.1.63".into()), tool.version());
+    }
+
+    #[test]
+    fn trait_display() {
+        let tool: CodeScanningTool = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("clippy", tool.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeScanningTool>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeScanningTool>();
+    }
+}
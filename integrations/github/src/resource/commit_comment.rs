@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, GitSha, NodeId};
+
+id!(
+    /// Commit comment id
+    ///
+    /// The [`CommitCommentId`] is a unique, numerical id that is used to interact with a comment on
+    /// a commit through [GitHub's REST API](https://docs.github.com/en/rest).
+    CommitCommentId
+);
+
+/// Commit comment
+///
+/// Users can comment on commits, either on the commit as a whole or on a specific line in one of
+/// the commit's files. In the latter case, the comment contains the file's path and the line.
+///
+/// Read more: https://docs.github.com/en/rest/commits/comments
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CommitComment {
+    id: CommitCommentId,
+    node_id: NodeId,
+    url: Url,
+    html_url: Url,
+    body: String,
+    path: Option<String>,
+    position: Option<u64>,
+    line: Option<u64>,
+    commit_id: GitSha,
+    user: Account,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl CommitComment {
+    /// Returns the comment's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> CommitCommentId {
+        self.id
+    }
+
+    /// Returns the comment's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the comment's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the path of the file on which the comment was made.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn path(&self) -> &Option<String> {
+        &self.path
+    }
+
+    /// Returns the position of the comment in the commit's diff.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn position(&self) -> Option<u64> {
+        self.position
+    }
+
+    /// Returns the line of the file on which the comment was made.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    /// Returns the SHA of the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn commit_id(&self) -> &GitSha {
+        &self.commit_id
+    }
+
+    /// Returns the comment's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the date when the comment was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the comment was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for CommitComment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommitComment;
+
+    #[test]
+    fn trait_deserialize() {
+        let comment: CommitComment = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_comment.json"
+        ))
+        .unwrap();
+
+        assert_eq!(Some(1), comment.line());
+    }
+
+    #[test]
+    fn trait_display() {
+        let comment: CommitComment = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_comment.json"
+        ))
+        .unwrap();
+
+        assert_eq!("84173620", comment.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CommitComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CommitComment>();
+    }
+}
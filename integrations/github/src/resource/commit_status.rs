@@ -0,0 +1,227 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::{Account, CheckRunConclusion, NodeId};
+use crate::{id, name};
+
+id!(
+    /// Commit status id
+    ///
+    /// The [`CommitStatusId`] is a unique, numerical id that identifies a commit status.
+    CommitStatusId
+);
+
+name!(
+    /// Commit status context
+    ///
+    /// The context differentiates the statuses of different systems for the same commit, e.g.
+    /// `ci/jenkins` or `continuous-integration/travis-ci`. A newer status replaces older statuses
+    /// with the same context.
+    CommitStatusContext
+);
+
+/// Commit status state
+///
+/// Commit statuses are the predecessor of check runs, and are still used by many third-party CI
+/// systems. Unlike check runs, a status has a single state that combines its progress and result.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitStatusState {
+    /// The system failed to run
+    Error,
+
+    /// The commit failed the check
+    Failure,
+
+    /// The check is still running
+    Pending,
+
+    /// The commit passed the check
+    Success,
+}
+
+/// Commit status
+///
+/// External systems, e.g. third-party CI services, report the result of their checks for a commit
+/// as a status. Each system uses its own context, and a new status replaces older statuses with
+/// the same context.
+///
+/// Read more: https://docs.github.com/en/rest/commits/statuses
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CommitStatus {
+    id: CommitStatusId,
+    node_id: NodeId,
+    url: Url,
+    state: CommitStatusState,
+    description: Option<String>,
+    target_url: Option<Url>,
+    context: CommitStatusContext,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    creator: Option<Account>,
+}
+
+impl CommitStatusState {
+    /// Returns the equivalent check run conclusion, or `None` while the status is pending.
+    ///
+    /// Both errors and failures conclude as [`CheckRunConclusion::Failure`], which allows
+    /// statuses to be aggregated with check runs.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn conclusion(&self) -> Option<CheckRunConclusion> {
+        match self {
+            CommitStatusState::Error | CommitStatusState::Failure => {
+                Some(CheckRunConclusion::Failure)
+            }
+            CommitStatusState::Pending => None,
+            CommitStatusState::Success => Some(CheckRunConclusion::Success),
+        }
+    }
+}
+
+impl CommitStatus {
+    /// Returns the status's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> CommitStatusId {
+        self.id
+    }
+
+    /// Returns the status's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the status's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> CommitStatusState {
+        self.state
+    }
+
+    /// Returns the status's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    /// Returns the URL to the details of the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn target_url(&self) -> &Option<Url> {
+        &self.target_url
+    }
+
+    /// Returns the status's context.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn context(&self) -> &CommitStatusContext {
+        &self.context
+    }
+
+    /// Returns the date when the status was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the status was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the account that created the status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn creator(&self) -> &Option<Account> {
+        &self.creator
+    }
+}
+
+impl Display for CommitStatusState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            CommitStatusState::Error => "error",
+            CommitStatusState::Failure => "failure",
+            CommitStatusState::Pending => "pending",
+            CommitStatusState::Success => "success",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for CommitStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.context, self.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::CheckRunConclusion;
+
+    use super::{CommitStatus, CommitStatusState};
+
+    fn status() -> CommitStatus {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_status.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let status = status();
+
+        assert_eq!(CommitStatusState::Success, status.state());
+        assert_eq!("ci/jenkins", status.context().get());
+    }
+
+    #[test]
+    fn trait_deserialize_state() {
+        let state: CommitStatusState = serde_json::from_str(r#""pending""#).unwrap();
+
+        assert_eq!(CommitStatusState::Pending, state);
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("ci/jenkins (success)", status().to_string());
+    }
+
+    #[test]
+    fn trait_display_state() {
+        assert_eq!("error", CommitStatusState::Error.to_string());
+    }
+
+    #[test]
+    fn conclusion() {
+        assert_eq!(
+            Some(CheckRunConclusion::Failure),
+            CommitStatusState::Error.conclusion()
+        );
+        assert_eq!(None, CommitStatusState::Pending.conclusion());
+        assert_eq!(
+            Some(CheckRunConclusion::Success),
+            CommitStatusState::Success.conclusion()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CommitStatus>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CommitStatus>();
+    }
+}
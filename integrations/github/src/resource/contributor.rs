@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::Account;
+
+/// Contributor to a repository
+///
+/// Contributors are the accounts that have authored commits in a repository. GitHub counts their
+/// contributions by the commits on the default branch, and matches commits to accounts by their
+/// email address.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Contributor {
+    #[serde(flatten)]
+    account: Account,
+    contributions: u64,
+}
+
+impl Contributor {
+    /// Returns the contributor's [`Account`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn account(&self) -> &Account {
+        &self.account
+    }
+
+    /// Returns the number of commits that the contributor has authored.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn contributions(&self) -> u64 {
+        self.contributions
+    }
+}
+
+impl Display for Contributor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.account.login())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Contributor;
+
+    fn contributor() -> Contributor {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/contributor.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let contributor = contributor();
+
+        assert_eq!("octocat", contributor.account().login().get());
+        assert_eq!(32, contributor.contributions());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("octocat", contributor().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Contributor>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Contributor>();
+    }
+}
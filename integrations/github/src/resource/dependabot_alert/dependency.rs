@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::name;
+
+name!(
+    /// Package ecosystem
+    ///
+    /// Dependabot supports a variety of package ecosystems, for example `npm`, `pip`, or `cargo`.
+    PackageEcosystem
+);
+
+name!(
+    /// Package name
+    ///
+    /// The unique name of a package within its ecosystem.
+    PackageName
+);
+
+/// Package in a package ecosystem
+///
+/// Dependencies are packages that are published to a package ecosystem, for example a crate on
+/// [crates.io](https://crates.io).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Package {
+    ecosystem: PackageEcosystem,
+    name: PackageName,
+}
+
+/// Vulnerable dependency
+///
+/// Dependabot alerts reference the dependency that has a vulnerability, and the manifest in which
+/// the dependency has been declared.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DependabotAlertDependency {
+    package: Package,
+    manifest_path: String,
+    scope: Option<String>,
+}
+
+impl Package {
+    /// Returns the package's ecosystem.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn ecosystem(&self) -> &PackageEcosystem {
+        &self.ecosystem
+    }
+
+    /// Returns the package's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+}
+
+impl DependabotAlertDependency {
+    /// Returns the vulnerable package.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn package(&self) -> &Package {
+        &self.package
+    }
+
+    /// Returns the path to the manifest that declares the dependency.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn manifest_path(&self) -> &str {
+        &self.manifest_path
+    }
+
+    /// Returns the dependency's scope, e.g. `development` or `runtime`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn scope(&self) -> &Option<String> {
+        &self.scope
+    }
+}
+
+impl Display for Package {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.ecosystem, self.name)
+    }
+}
+
+impl Display for DependabotAlertDependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.package)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DependabotAlertDependency;
+
+    const JSON: &str = r#"
+    {
+        "package": {
+            "ecosystem": "pip",
+            "name": "django"
+        },
+        "manifest_path": "path/to/requirements.txt",
+        "scope": "runtime"
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let dependency: DependabotAlertDependency = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("django", dependency.package().name().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let dependency: DependabotAlertDependency = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("pip/django", dependency.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DependabotAlertDependency>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DependabotAlertDependency>();
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, SecurityAdvisory};
+
+pub use self::dependency::{DependabotAlertDependency, Package, PackageEcosystem, PackageName};
+pub use self::state::{DependabotAlertDismissedReason, DependabotAlertState};
+
+mod dependency;
+mod state;
+
+id!(
+    /// Dependabot alert number
+    ///
+    /// Every [`DependabotAlert`] has a unique, monotonically increasing number that identifies the
+    /// alert within its repository.
+    DependabotAlertNumber
+);
+
+/// Dependabot alert
+///
+/// Dependabot scans the dependencies of a repository and opens an alert when a dependency is
+/// affected by a known vulnerability. The alert references the vulnerable dependency as well as
+/// the [`SecurityAdvisory`] that describes the vulnerability.
+///
+/// Read more: https://docs.github.com/en/rest/dependabot/alerts
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DependabotAlert {
+    number: DependabotAlertNumber,
+    state: DependabotAlertState,
+    dependency: DependabotAlertDependency,
+    security_advisory: SecurityAdvisory,
+    url: Url,
+    html_url: Url,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    dismissed_at: Option<DateTime<Utc>>,
+    dismissed_by: Option<Account>,
+    dismissed_reason: Option<DependabotAlertDismissedReason>,
+    dismissed_comment: Option<String>,
+    fixed_at: Option<DateTime<Utc>>,
+}
+
+impl DependabotAlert {
+    /// Returns the alert's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> DependabotAlertNumber {
+        self.number
+    }
+
+    /// Returns the alert's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> DependabotAlertState {
+        self.state
+    }
+
+    /// Returns the alert's vulnerable dependency.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dependency(&self) -> &DependabotAlertDependency {
+        &self.dependency
+    }
+
+    /// Returns the alert's security advisory.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn security_advisory(&self) -> &SecurityAdvisory {
+        &self.security_advisory
+    }
+
+    /// Returns the API endpoint to query the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the date when the alert was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the alert was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the date when the alert was dismissed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.dismissed_at
+    }
+
+    /// Returns the account that dismissed the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_by(&self) -> &Option<Account> {
+        &self.dismissed_by
+    }
+
+    /// Returns the reason why the alert was dismissed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_reason(&self) -> Option<DependabotAlertDismissedReason> {
+        self.dismissed_reason
+    }
+
+    /// Returns the comment that was left when the alert was dismissed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dismissed_comment(&self) -> &Option<String> {
+        &self.dismissed_comment
+    }
+
+    /// Returns the date when the alert was fixed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn fixed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.fixed_at
+    }
+}
+
+impl Display for DependabotAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DependabotAlert, DependabotAlertState};
+
+    #[test]
+    fn trait_deserialize() {
+        let alert: DependabotAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/dependabot_alert.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(alert.state(), DependabotAlertState::Dismissed));
+    }
+
+    #[test]
+    fn trait_display() {
+        let alert: DependabotAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/dependabot_alert.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#2", alert.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DependabotAlert>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DependabotAlert>();
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Dependabot alert state
+///
+/// Dependabot alerts are opened when a vulnerable dependency is detected in a repository. They are
+/// either fixed by upgrading the dependency, or dismissed by a user or by Dependabot itself.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependabotAlertState {
+    /// Alert was dismissed automatically by Dependabot
+    AutoDismissed,
+
+    /// Alert was dismissed by a user
+    Dismissed,
+
+    /// Alert was fixed by upgrading the vulnerable dependency
+    Fixed,
+
+    /// Alert is open
+    Open,
+}
+
+/// Reason for dismissing a Dependabot alert
+///
+/// When a user dismisses a Dependabot alert, they have to provide a reason for the dismissal.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependabotAlertDismissedReason {
+    /// A fix has already been started
+    FixStarted,
+
+    /// The alert is inaccurate or incorrect
+    Inaccurate,
+
+    /// There is no bandwidth to fix the alert
+    NoBandwidth,
+
+    /// The vulnerable code is not actually used
+    NotUsed,
+
+    /// The risk is tolerable to this project
+    TolerableRisk,
+}
+
+impl Display for DependabotAlertState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DependabotAlertState::AutoDismissed => "auto dismissed",
+            DependabotAlertState::Dismissed => "dismissed",
+            DependabotAlertState::Fixed => "fixed",
+            DependabotAlertState::Open => "open",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for DependabotAlertDismissedReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DependabotAlertDismissedReason::FixStarted => "fix started",
+            DependabotAlertDismissedReason::Inaccurate => "inaccurate",
+            DependabotAlertDismissedReason::NoBandwidth => "no bandwidth",
+            DependabotAlertDismissedReason::NotUsed => "not used",
+            DependabotAlertDismissedReason::TolerableRisk => "tolerable risk",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DependabotAlertDismissedReason, DependabotAlertState};
+
+    #[test]
+    fn trait_deserialize() {
+        let state: DependabotAlertState = serde_json::from_str(r#""auto_dismissed""#).unwrap();
+
+        assert!(matches!(state, DependabotAlertState::AutoDismissed));
+    }
+
+    #[test]
+    fn trait_deserialize_dismissed_reason() {
+        let reason: DependabotAlertDismissedReason =
+            serde_json::from_str(r#""tolerable_risk""#).unwrap();
+
+        assert!(matches!(
+            reason,
+            DependabotAlertDismissedReason::TolerableRisk
+        ));
+    }
+
+    #[test]
+    fn trait_display() {
+        let state = DependabotAlertState::AutoDismissed;
+
+        assert_eq!("auto dismissed", state.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DependabotAlertState>();
+        assert_send::<DependabotAlertDismissedReason>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DependabotAlertState>();
+        assert_sync::<DependabotAlertDismissedReason>();
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::resource::NodeId;
+use crate::{id, name};
+
+id!(
+    /// Discussion category id
+    ///
+    /// The [`DiscussionCategoryId`] is a unique, numerical id that identifies a discussion
+    /// category.
+    DiscussionCategoryId
+);
+
+name!(
+    /// Discussion category name
+    ///
+    /// Discussion categories have a human-readable name, e.g. `Q&A` or `Ideas`.
+    DiscussionCategoryName
+);
+
+/// Discussion category
+///
+/// Discussions are organized into categories. Categories can be answerable, in which case a comment
+/// can be marked as the answer to a discussion.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DiscussionCategory {
+    id: DiscussionCategoryId,
+    node_id: NodeId,
+    name: DiscussionCategoryName,
+    slug: String,
+    emoji: String,
+    description: String,
+    is_answerable: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl DiscussionCategory {
+    /// Returns the category's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> DiscussionCategoryId {
+        self.id
+    }
+
+    /// Returns the category's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the category's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &DiscussionCategoryName {
+        &self.name
+    }
+
+    /// Returns the category's slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    /// Returns the category's emoji.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn emoji(&self) -> &str {
+        &self.emoji
+    }
+
+    /// Returns the category's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Indicates whether discussions in the category can be answered.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_answerable(&self) -> bool {
+        self.is_answerable
+    }
+
+    /// Returns the date when the category was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the category was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for DiscussionCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiscussionCategory;
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DiscussionCategory>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DiscussionCategory>();
+    }
+}
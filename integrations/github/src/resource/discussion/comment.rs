@@ -0,0 +1,112 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, NodeId};
+
+id!(
+    /// Discussion comment id
+    ///
+    /// The [`DiscussionCommentId`] is a unique, numerical id that identifies a comment on a
+    /// discussion.
+    DiscussionCommentId
+);
+
+/// Discussion comment
+///
+/// Users can comment on discussions, and reply to other comments. Replies reference the comment
+/// they respond to as their parent.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DiscussionComment {
+    id: DiscussionCommentId,
+    node_id: NodeId,
+    html_url: Url,
+    parent_id: Option<DiscussionCommentId>,
+    child_comment_count: u64,
+    body: String,
+    user: Account,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl DiscussionComment {
+    /// Returns the comment's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> DiscussionCommentId {
+        self.id
+    }
+
+    /// Returns the comment's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the URL to the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the id of the comment to which this comment replies.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn parent_id(&self) -> Option<DiscussionCommentId> {
+        self.parent_id
+    }
+
+    /// Returns the number of replies to the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn child_comment_count(&self) -> u64 {
+        self.child_comment_count
+    }
+
+    /// Returns the comment's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the comment's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the date when the comment was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the comment was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for DiscussionComment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiscussionComment;
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DiscussionComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DiscussionComment>();
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, NodeId};
+
+pub use self::category::{DiscussionCategory, DiscussionCategoryId, DiscussionCategoryName};
+pub use self::comment::{DiscussionComment, DiscussionCommentId};
+
+mod category;
+mod comment;
+
+id!(
+    /// Discussion id
+    ///
+    /// The [`DiscussionId`] is a unique, numerical id that identifies a discussion.
+    DiscussionId
+);
+
+id!(
+    /// Discussion number
+    ///
+    /// Every [`Discussion`] has a unique, human-readable, monotonically increasing number assigned
+    /// to it. This number identifies the discussion on GitHub's website.
+    DiscussionNumber
+);
+
+/// Discussion state
+///
+/// Discussions are open while the community can participate in them, and can be closed or locked
+/// by maintainers. While a discussion is converted into an issue or transferred to another
+/// repository, it is in a transitional state.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionState {
+    /// The discussion is closed.
+    Closed,
+
+    /// The discussion is being converted into an issue.
+    Converting,
+
+    /// The discussion is locked.
+    Locked,
+
+    /// The discussion is open.
+    Open,
+
+    /// The discussion is being transferred to another repository.
+    Transferring,
+}
+
+/// Discussion
+///
+/// Discussions are a collaborative communication forum for the community around a repository.
+/// They are organized into categories, and can be answered if their category is answerable.
+///
+/// Read more: https://docs.github.com/en/discussions
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Discussion {
+    id: DiscussionId,
+    node_id: NodeId,
+    number: DiscussionNumber,
+    title: String,
+    body: Option<String>,
+    html_url: Url,
+    state: DiscussionState,
+    locked: bool,
+    comments: u64,
+    category: DiscussionCategory,
+    answer_html_url: Option<Url>,
+    answer_chosen_at: Option<DateTime<Utc>>,
+    answer_chosen_by: Option<Account>,
+    user: Account,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Discussion {
+    /// Returns the discussion's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> DiscussionId {
+        self.id
+    }
+
+    /// Returns the discussion's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the discussion's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> DiscussionNumber {
+        self.number
+    }
+
+    /// Returns the discussion's title.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the discussion's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &Option<String> {
+        &self.body
+    }
+
+    /// Returns the URL to the discussion.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the discussion's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> DiscussionState {
+        self.state
+    }
+
+    /// Indicates whether the discussion is locked.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Returns the number of comments on the discussion.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn comments(&self) -> u64 {
+        self.comments
+    }
+
+    /// Returns the discussion's category.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn category(&self) -> &DiscussionCategory {
+        &self.category
+    }
+
+    /// Returns the URL to the comment that was chosen as the answer.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn answer_html_url(&self) -> &Option<Url> {
+        &self.answer_html_url
+    }
+
+    /// Returns the date when the answer was chosen.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn answer_chosen_at(&self) -> &Option<DateTime<Utc>> {
+        &self.answer_chosen_at
+    }
+
+    /// Returns the account that chose the answer.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn answer_chosen_by(&self) -> &Option<Account> {
+        &self.answer_chosen_by
+    }
+
+    /// Returns the discussion's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the date when the discussion was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the discussion was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for DiscussionState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            DiscussionState::Closed => "closed",
+            DiscussionState::Converting => "converting",
+            DiscussionState::Locked => "locked",
+            DiscussionState::Open => "open",
+            DiscussionState::Transferring => "transferring",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Discussion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Discussion, DiscussionState};
+
+    #[test]
+    fn trait_deserialize() {
+        let discussion: Discussion = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/discussion.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(discussion.state(), DiscussionState::Open));
+        assert!(discussion.category().is_answerable());
+    }
+
+    #[test]
+    fn trait_display() {
+        let discussion: Discussion = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/discussion.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#90", discussion.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Discussion>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Discussion>();
+    }
+}
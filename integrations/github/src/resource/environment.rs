@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::NodeId;
+use crate::{id, name};
+
+id!(
+    /// Environment id
+    ///
+    /// The [`EnvironmentId`] is a unique, numerical id that is used to interact with a deployment
+    /// environment through [GitHub's REST API](https://docs.github.com/en/rest).
+    EnvironmentId
+);
+
+name!(
+    /// Environment name
+    ///
+    /// Environments are referenced by their name in workflows, e.g. `environment: production`.
+    EnvironmentName
+);
+
+/// Deployment environment
+///
+/// Environments describe deployment targets like `staging` or `production`. Workflow jobs that
+/// reference an environment can use its secrets and variables, and must pass its protection rules.
+///
+/// Read more: https://docs.github.com/en/rest/deployments/environments
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Environment {
+    id: EnvironmentId,
+    node_id: NodeId,
+    name: EnvironmentName,
+    url: Url,
+    html_url: Url,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Environment {
+    /// Returns the environment's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> EnvironmentId {
+        self.id
+    }
+
+    /// Returns the environment's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the environment's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &EnvironmentName {
+        &self.name
+    }
+
+    /// Returns the API endpoint to query the environment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the environment's deployments.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the date when the environment was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the environment was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for Environment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Environment;
+
+    #[test]
+    fn trait_deserialize() {
+        let environment: Environment = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/environment.json"
+        ))
+        .unwrap();
+
+        assert_eq!(161088068, environment.id().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let environment: Environment = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/environment.json"
+        ))
+        .unwrap();
+
+        assert_eq!("staging", environment.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Environment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Environment>();
+    }
+}
@@ -49,6 +49,12 @@ impl File {
         }
     }
 
+    /// Replaces the contents of the file, e.g. with the contents from a cache.
+    pub(crate) fn with_content(mut self, content: Vec<u8>) -> Self {
+        self.content = content;
+        self
+    }
+
     /// Returns the file name.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn name(&self) -> &String {
@@ -1,3 +1,7 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
 use crate::name;
 
 name!(
@@ -18,3 +22,86 @@ name!(
     /// GitHub's API to reference commits in the Git database.
     GitSha
 );
+
+/// Type of a Git reference
+///
+/// Git references that are created or deleted on GitHub are either branches or tags.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitRefType {
+    /// The reference is a branch.
+    Branch,
+
+    /// The reference is a tag.
+    Tag,
+}
+
+/// Type of a pusher
+///
+/// Changes to a repository are pushed either by a user, or with a deploy key that was configured
+/// for the repository.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PusherType {
+    /// The changes were pushed with a deploy key.
+    DeployKey,
+
+    /// The changes were pushed by a user.
+    User,
+}
+
+impl Display for GitRefType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            GitRefType::Branch => "branch",
+            GitRefType::Tag => "tag",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for PusherType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            PusherType::DeployKey => "deploy key",
+            PusherType::User => "user",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GitRefType, PusherType};
+
+    #[test]
+    fn trait_deserialize() {
+        let ref_type: GitRefType = serde_json::from_str(r#""branch""#).unwrap();
+        let pusher_type: PusherType = serde_json::from_str(r#""deploy_key""#).unwrap();
+
+        assert!(matches!(ref_type, GitRefType::Branch));
+        assert!(matches!(pusher_type, PusherType::DeployKey));
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("tag", GitRefType::Tag.to_string());
+        assert_eq!("deploy key", PusherType::DeployKey.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GitRefType>();
+        assert_send::<PusherType>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GitRefType>();
+        assert_sync::<PusherType>();
+    }
+}
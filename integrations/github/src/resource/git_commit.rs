@@ -0,0 +1,230 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::{GitSha, NodeId};
+
+/// Commit in Git's database
+///
+/// Commits point to a tree with the files in a repository, and to their parent commits. GitHub's
+/// Git database API exposes commits as Git objects, including the author, the committer, and the
+/// verification of the commit's signature.
+///
+/// Read more: https://docs.github.com/en/rest/git/commits
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GitCommit {
+    sha: GitSha,
+    node_id: NodeId,
+    url: Url,
+    html_url: Url,
+    author: GitUser,
+    committer: GitUser,
+    message: String,
+    tree: GitCommitTree,
+    parents: Vec<GitCommitParent>,
+    verification: GitCommitVerification,
+}
+
+/// Author or committer of a Git commit
+///
+/// Git records the name and email address of the author and the committer of a commit, and when
+/// they authored and committed it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GitUser {
+    name: String,
+    email: String,
+    date: DateTime<Utc>,
+}
+
+/// Tree of a Git commit
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GitCommitTree {
+    sha: GitSha,
+    url: Url,
+}
+
+/// Parent of a Git commit
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GitCommitParent {
+    sha: GitSha,
+    url: Url,
+    html_url: Url,
+}
+
+/// Verification of a commit's signature
+///
+/// GitHub verifies the signatures of commits against the keys of their authors, and shows
+/// verified commits with a "Verified" badge.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GitCommitVerification {
+    verified: bool,
+    reason: String,
+    signature: Option<String>,
+    payload: Option<String>,
+}
+
+impl GitCommit {
+    /// Returns the commit's SHA.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sha(&self) -> &GitSha {
+        &self.sha
+    }
+
+    /// Returns the commit's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the commit's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn author(&self) -> &GitUser {
+        &self.author
+    }
+
+    /// Returns the commit's committer.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn committer(&self) -> &GitUser {
+        &self.committer
+    }
+
+    /// Returns the commit's message.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the SHA of the commit's tree.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn tree(&self) -> &GitSha {
+        &self.tree.sha
+    }
+
+    /// Returns the SHAs of the commit's parents.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn parents(&self) -> Vec<&GitSha> {
+        self.parents.iter().map(|parent| &parent.sha).collect()
+    }
+
+    /// Returns the verification of the commit's signature.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verification(&self) -> &GitCommitVerification {
+        &self.verification
+    }
+}
+
+impl GitUser {
+    /// Returns the user's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the user's email address.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// Returns the date when the user authored or committed the commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn date(&self) -> &DateTime<Utc> {
+        &self.date
+    }
+}
+
+impl GitCommitVerification {
+    /// Indicates whether GitHub verified the commit's signature.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn verified(&self) -> bool {
+        self.verified
+    }
+
+    /// Returns the reason for the verification result, e.g. `valid` or `unsigned`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns the commit's signature.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn signature(&self) -> &Option<String> {
+        &self.signature
+    }
+
+    /// Returns the payload that was signed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn payload(&self) -> &Option<String> {
+        &self.payload
+    }
+}
+
+impl Display for GitCommit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.sha)
+    }
+}
+
+impl Display for GitUser {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GitCommit;
+
+    #[test]
+    fn trait_deserialize() {
+        let commit: GitCommit = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/git_commit.json"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "827efc6d56897b048c772eb4087f854f46256132",
+            commit.tree().get()
+        );
+        assert!(!commit.verification().verified());
+    }
+
+    #[test]
+    fn trait_display() {
+        let commit: GitCommit = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/git_commit.json"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "Monalisa Octocat <octocat@github.com>",
+            commit.author().to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GitCommit>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GitCommit>();
+    }
+}
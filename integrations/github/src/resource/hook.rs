@@ -0,0 +1,205 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::AppId;
+
+id!(
+    /// Hook id
+    ///
+    /// The [`HookId`] is a unique, numerical id that is used to interact with a webhook through
+    /// [GitHub's REST API](https://docs.github.com/en/rest).
+    HookId
+);
+
+/// Webhook type
+///
+/// Webhooks can be configured for GitHub Apps, organizations, and repositories.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum HookType {
+    /// Webhook of a GitHub App
+    App,
+
+    /// Webhook of an organization
+    Organization,
+
+    /// Webhook of a repository
+    Repository,
+}
+
+/// Webhook configuration
+///
+/// The configuration of a webhook determines where and how GitHub delivers the webhook's events.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HookConfig {
+    url: Option<Url>,
+    content_type: Option<String>,
+    insecure_ssl: Option<String>,
+}
+
+/// Webhook
+///
+/// Webhooks deliver events on GitHub to an external URL. They can be configured for GitHub Apps,
+/// organizations, and repositories, and subscribe to a list of events.
+///
+/// Read more: https://docs.github.com/en/rest/webhooks
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Hook {
+    id: HookId,
+    #[serde(rename = "type")]
+    hook_type: HookType,
+    name: String,
+    active: bool,
+    events: Vec<String>,
+    config: HookConfig,
+    app_id: Option<AppId>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl HookConfig {
+    /// Returns the URL to which the events are delivered.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Option<Url> {
+        &self.url
+    }
+
+    /// Returns the content type with which the events are delivered, e.g. `json` or `form`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn content_type(&self) -> &Option<String> {
+        &self.content_type
+    }
+
+    /// Returns whether SSL verification is disabled for the deliveries.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn insecure_ssl(&self) -> &Option<String> {
+        &self.insecure_ssl
+    }
+}
+
+impl Hook {
+    /// Returns the webhook's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> HookId {
+        self.id
+    }
+
+    /// Returns the webhook's type.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn hook_type(&self) -> HookType {
+        self.hook_type
+    }
+
+    /// Returns the webhook's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Indicates whether the webhook is active.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the events to which the webhook is subscribed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn events(&self) -> &Vec<String> {
+        &self.events
+    }
+
+    /// Returns the webhook's configuration.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn config(&self) -> &HookConfig {
+        &self.config
+    }
+
+    /// Returns the id of the app to which the webhook belongs.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app_id(&self) -> Option<AppId> {
+        self.app_id
+    }
+
+    /// Returns the date when the webhook was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the webhook was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for HookType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            HookType::App => "app",
+            HookType::Organization => "organization",
+            HookType::Repository => "repository",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Hook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hook, HookType};
+
+    const JSON: &str = r#"
+    {
+        "type": "App",
+        "id": 376543210,
+        "name": "web",
+        "active": true,
+        "events": ["check_run", "check_suite", "pull_request"],
+        "config": {
+            "content_type": "json",
+            "insecure_ssl": "0",
+            "url": "https://automatons.example.com/github/webhooks"
+        },
+        "updated_at": "2022-08-23T14:29:47Z",
+        "created_at": "2022-08-23T14:29:47Z",
+        "app_id": 221441,
+        "deliveries_url": "https://api.github.com/app/hook/deliveries"
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let hook: Hook = serde_json::from_str(JSON).unwrap();
+
+        assert!(matches!(hook.hook_type(), HookType::App));
+    }
+
+    #[test]
+    fn trait_display() {
+        let hook: Hook = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("376543210", hook.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Hook>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Hook>();
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{InstallationId, RepositoryId};
+use crate::{id, name};
+
+id!(
+    /// Hook delivery id
+    ///
+    /// The [`HookDeliveryId`] is a unique, numerical id that is used to interact with a delivery of
+    /// a webhook through [GitHub's REST API](https://docs.github.com/en/rest).
+    HookDeliveryId
+);
+
+name!(
+    /// Hook delivery GUID
+    ///
+    /// Every delivery has a GUID, which GitHub sends in the `X-GitHub-Delivery` header. Redeliveries
+    /// keep the GUID of the original delivery.
+    HookDeliveryGuid
+);
+
+/// Webhook delivery
+///
+/// GitHub records every attempt to deliver an event to a webhook, including the response of the
+/// receiving server. Failed deliveries can be redelivered, e.g. after an outage of the server.
+///
+/// Read more: https://docs.github.com/en/rest/apps/webhooks
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct HookDelivery {
+    id: HookDeliveryId,
+    guid: HookDeliveryGuid,
+    delivered_at: DateTime<Utc>,
+    redelivery: bool,
+    duration: f64,
+    status: String,
+    status_code: u16,
+    event: String,
+    action: Option<String>,
+    installation_id: Option<InstallationId>,
+    repository_id: Option<RepositoryId>,
+}
+
+impl HookDelivery {
+    /// Returns the delivery's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> HookDeliveryId {
+        self.id
+    }
+
+    /// Returns the delivery's GUID.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn guid(&self) -> &HookDeliveryGuid {
+        &self.guid
+    }
+
+    /// Returns the date when the event was delivered.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn delivered_at(&self) -> &DateTime<Utc> {
+        &self.delivered_at
+    }
+
+    /// Indicates whether the delivery is a redelivery.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn redelivery(&self) -> bool {
+        self.redelivery
+    }
+
+    /// Returns the time in seconds that the delivery took.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// Returns the description of the delivery's status, e.g. `OK` or `Invalid HTTP Response: 503`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// Returns the HTTP status code of the response, or 0 if the server did not respond.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// Returns the name of the event that was delivered.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Returns the action of the event that was delivered.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn action(&self) -> &Option<String> {
+        &self.action
+    }
+
+    /// Returns the id of the installation that the event belongs to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn installation_id(&self) -> Option<InstallationId> {
+        self.installation_id
+    }
+
+    /// Returns the id of the repository that the event belongs to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository_id(&self) -> Option<RepositoryId> {
+        self.repository_id
+    }
+
+    /// Indicates whether the delivery failed.
+    ///
+    /// Deliveries fail when the server does not respond, or responds with a status code other than
+    /// 2xx.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn failed(&self) -> bool {
+        !(200..300).contains(&self.status_code)
+    }
+}
+
+impl Display for HookDelivery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.guid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HookDelivery;
+
+    #[test]
+    fn trait_deserialize() {
+        let delivery: HookDelivery = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/hook_delivery.json"
+        ))
+        .unwrap();
+
+        assert!(delivery.failed());
+    }
+
+    #[test]
+    fn trait_display() {
+        let delivery: HookDelivery = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/hook_delivery.json"
+        ))
+        .unwrap();
+
+        assert_eq!("0b989ba4-242f-11e5-81e1-c7b6966d2516", delivery.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<HookDelivery>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<HookDelivery>();
+    }
+}
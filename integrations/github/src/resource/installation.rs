@@ -1,9 +1,11 @@
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::id;
-use crate::resource::NodeId;
+use crate::resource::{Account, AppId, NodeId, RepositoryFullName, RepositoryId, RepositoryName};
 
 id!(
     /// Installation id
@@ -18,10 +20,59 @@ id!(
 /// When a user adds a GitHub App to an account, a new app installation is created. The installation
 /// id can be used by the app to request a scoped access token that allows it to interact with the
 /// resources of the account.
+///
+/// Most events contain a minimal representation of the installation with its node id, while
+/// installation events contain the full installation without it. The fields of the full
+/// installation are empty for the minimal representation.
+///
+/// Owners can suspend an installation, after which the app can no longer access the account's
+/// resources until the installation is unsuspended.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Installation {
     id: InstallationId,
+    node_id: Option<NodeId>,
+
+    #[serde(default)]
+    account: Option<Account>,
+    #[serde(default)]
+    app_id: Option<AppId>,
+    #[serde(default)]
+    repository_selection: Option<RepositorySelection>,
+    #[serde(default)]
+    permissions: BTreeMap<String, String>,
+    #[serde(default)]
+    events: Vec<String>,
+    #[serde(default)]
+    suspended_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    suspended_by: Option<Account>,
+}
+
+/// Repository selection of an installation
+///
+/// Users can grant an installation access to all repositories of an account, including the ones
+/// that are created in the future, or only to selected repositories.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositorySelection {
+    /// The installation can access all repositories
+    All,
+
+    /// The installation can only access selected repositories
+    Selected,
+}
+
+/// Repository of an installation
+///
+/// Installation events reference the repositories that were added to or removed from an
+/// installation with a minimal representation.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct InstallationRepository {
+    id: RepositoryId,
     node_id: NodeId,
+    name: RepositoryName,
+    full_name: RepositoryFullName,
+    private: bool,
 }
 
 impl Installation {
@@ -33,9 +84,89 @@ impl Installation {
 
     /// Returns the installation's node id.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &Option<NodeId> {
+        &self.node_id
+    }
+
+    /// Returns the account on which the app is installed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn account(&self) -> &Option<Account> {
+        &self.account
+    }
+
+    /// Returns the id of the installed app.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app_id(&self) -> Option<AppId> {
+        self.app_id
+    }
+
+    /// Returns the repositories that the installation can access.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repository_selection(&self) -> Option<RepositorySelection> {
+        self.repository_selection
+    }
+
+    /// Returns the permissions that the installation has been granted, e.g. `checks: write`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn permissions(&self) -> &BTreeMap<String, String> {
+        &self.permissions
+    }
+
+    /// Returns the events that the installation is subscribed to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn events(&self) -> &Vec<String> {
+        &self.events
+    }
+
+    /// Returns the date when the installation was suspended.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn suspended_at(&self) -> &Option<DateTime<Utc>> {
+        &self.suspended_at
+    }
+
+    /// Returns the account that suspended the installation.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn suspended_by(&self) -> &Option<Account> {
+        &self.suspended_by
+    }
+
+    /// Indicates whether the installation is suspended.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+}
+
+impl InstallationRepository {
+    /// Returns the repository's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> RepositoryId {
+        self.id
+    }
+
+    /// Returns the repository's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn node_id(&self) -> &NodeId {
         &self.node_id
     }
+
+    /// Returns the repository's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &RepositoryName {
+        &self.name
+    }
+
+    /// Returns the repository's full name, including its owner.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn full_name(&self) -> &RepositoryFullName {
+        &self.full_name
+    }
+
+    /// Indicates whether the repository is private.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn private(&self) -> bool {
+        self.private
+    }
 }
 
 impl Display for Installation {
@@ -44,11 +175,28 @@ impl Display for Installation {
     }
 }
 
+impl Display for RepositorySelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            RepositorySelection::All => "all",
+            RepositorySelection::Selected => "selected",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for InstallationRepository {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::resource::NodeId;
+    use crate::resource::RepositorySelection;
 
-    use super::{Installation, InstallationId};
+    use super::Installation;
 
     #[test]
     fn trait_deserialize() {
@@ -58,14 +206,40 @@ mod tests {
         .unwrap();
 
         assert_eq!(25802826, installation.id().get());
+        assert_eq!(
+            Some(RepositorySelection::Selected),
+            installation.repository_selection()
+        );
+        assert_eq!(
+            Some(&"write".to_string()),
+            installation.permissions().get("checks")
+        );
+        assert!(!installation.is_suspended());
+    }
+
+    #[test]
+    fn trait_deserialize_without_node_id() {
+        let installation: Installation = serde_json::from_str(r#"{ "id": 42 }"#).unwrap();
+
+        assert_eq!(42, installation.id().get());
+        assert!(installation.node_id().is_none());
+        assert!(installation.account().is_none());
+        assert!(installation.events().is_empty());
+    }
+
+    #[test]
+    fn trait_deserialize_suspended() {
+        let installation: Installation = serde_json::from_str(
+            r#"{ "id": 42, "suspended_at": "2022-10-10T12:00:00Z", "suspended_by": null }"#,
+        )
+        .unwrap();
+
+        assert!(installation.is_suspended());
     }
 
     #[test]
     fn trait_display() {
-        let installation = Installation {
-            id: InstallationId::new(42),
-            node_id: NodeId::new("node_id"),
-        };
+        let installation: Installation = serde_json::from_str(r#"{ "id": 42 }"#).unwrap();
 
         assert_eq!("42", installation.to_string());
     }
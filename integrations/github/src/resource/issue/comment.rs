@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::{Account, NodeId};
+
+use super::IssueCommentId;
+
+/// Issue comment
+///
+/// Users and apps can comment on issues and pull requests. Comments on pull requests that are not
+/// part of a review are issue comments as well.
+///
+/// Read more: https://docs.github.com/en/rest/issues/comments
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IssueComment {
+    id: IssueCommentId,
+    node_id: NodeId,
+    url: Url,
+    html_url: Url,
+    issue_url: Url,
+    body: Option<String>,
+    user: Account,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl IssueComment {
+    /// Returns the comment's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> IssueCommentId {
+        self.id
+    }
+
+    /// Returns the comment's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the API endpoint to query the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn issue_url(&self) -> &Url {
+        &self.issue_url
+    }
+
+    /// Returns the comment's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &Option<String> {
+        &self.body
+    }
+
+    /// Returns the comment's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the date when the comment was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the comment was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for IssueComment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.html_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IssueComment;
+
+    #[test]
+    fn trait_deserialize() {
+        let comment: IssueComment = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .unwrap();
+
+        assert_eq!(1, comment.id().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let comment: IssueComment = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "https://github.com/github/hello-world/issues/1347#issuecomment-1",
+            comment.to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<IssueComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<IssueComment>();
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, GitSha, LabelName, NodeId};
+
+id!(
+    /// Issue event id
+    ///
+    /// The [`IssueEventId`] is a unique, numerical id that identifies an event on an issue.
+    IssueEventId
+);
+
+/// Issue event kind
+///
+/// Issue events are triggered by activity on issues and pull requests. GitHub adds new kinds of
+/// events from time to time, which are deserialized as `Unknown`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueEventKind {
+    /// The issue was added to a project board.
+    AddedToProject,
+
+    /// The issue was assigned to a user.
+    Assigned,
+
+    /// The issue was closed.
+    Closed,
+
+    /// The pull request was converted to a draft.
+    ConvertedToDraft,
+
+    /// The issue was removed from a milestone.
+    Demilestoned,
+
+    /// The pull request's branch was deleted.
+    HeadRefDeleted,
+
+    /// The pull request's branch was force pushed.
+    HeadRefForcePushed,
+
+    /// A label was added to the issue.
+    Labeled,
+
+    /// The issue was locked.
+    Locked,
+
+    /// A user was mentioned in the issue.
+    Mentioned,
+
+    /// The pull request was merged.
+    Merged,
+
+    /// The issue was added to a milestone.
+    Milestoned,
+
+    /// The issue was moved between the columns of a project board.
+    MovedColumnsInProject,
+
+    /// The issue was referenced from a commit message.
+    Referenced,
+
+    /// The issue was removed from a project board.
+    RemovedFromProject,
+
+    /// The issue's title was changed.
+    Renamed,
+
+    /// The issue was reopened.
+    Reopened,
+
+    /// A review of the pull request was dismissed.
+    ReviewDismissed,
+
+    /// A review of the pull request was requested.
+    ReviewRequested,
+
+    /// A review request of the pull request was removed.
+    ReviewRequestRemoved,
+
+    /// A user subscribed to notifications for the issue.
+    Subscribed,
+
+    /// A user was unassigned from the issue.
+    Unassigned,
+
+    /// A label was removed from the issue.
+    Unlabeled,
+
+    /// The issue was unlocked.
+    Unlocked,
+
+    /// A user unsubscribed from notifications for the issue.
+    Unsubscribed,
+
+    /// An event that is not (yet) supported by this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Label of an issue event
+///
+/// Issue events contain a reduced representation of the label that was added or removed.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IssueEventLabel {
+    name: LabelName,
+    color: String,
+}
+
+/// Issue event
+///
+/// Issue events record the activity on an issue or pull request, for example when it was labeled,
+/// assigned, closed, or referenced from a commit.
+///
+/// Read more: https://docs.github.com/en/rest/issues/events
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IssueEvent {
+    id: IssueEventId,
+    node_id: NodeId,
+    url: Url,
+    actor: Option<Account>,
+    event: IssueEventKind,
+    commit_id: Option<GitSha>,
+    label: Option<IssueEventLabel>,
+    assignee: Option<Account>,
+    created_at: DateTime<Utc>,
+}
+
+impl IssueEventLabel {
+    /// Returns the label's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &LabelName {
+        &self.name
+    }
+
+    /// Returns the label's color as a hexadecimal code.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn color(&self) -> &str {
+        &self.color
+    }
+}
+
+impl IssueEvent {
+    /// Returns the event's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> IssueEventId {
+        self.id
+    }
+
+    /// Returns the event's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the account that triggered the event.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn actor(&self) -> &Option<Account> {
+        &self.actor
+    }
+
+    /// Returns the event's kind.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn event(&self) -> IssueEventKind {
+        self.event
+    }
+
+    /// Returns the SHA of the commit that referenced the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn commit_id(&self) -> &Option<GitSha> {
+        &self.commit_id
+    }
+
+    /// Returns the label that was added or removed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn label(&self) -> &Option<IssueEventLabel> {
+        &self.label
+    }
+
+    /// Returns the account that was assigned or unassigned.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn assignee(&self) -> &Option<Account> {
+        &self.assignee
+    }
+
+    /// Returns the date when the event occurred.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+impl Display for IssueEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            IssueEventKind::AddedToProject => "added to project",
+            IssueEventKind::Assigned => "assigned",
+            IssueEventKind::Closed => "closed",
+            IssueEventKind::ConvertedToDraft => "converted to draft",
+            IssueEventKind::Demilestoned => "demilestoned",
+            IssueEventKind::HeadRefDeleted => "head ref deleted",
+            IssueEventKind::HeadRefForcePushed => "head ref force pushed",
+            IssueEventKind::Labeled => "labeled",
+            IssueEventKind::Locked => "locked",
+            IssueEventKind::Mentioned => "mentioned",
+            IssueEventKind::Merged => "merged",
+            IssueEventKind::Milestoned => "milestoned",
+            IssueEventKind::MovedColumnsInProject => "moved columns in project",
+            IssueEventKind::Referenced => "referenced",
+            IssueEventKind::RemovedFromProject => "removed from project",
+            IssueEventKind::Renamed => "renamed",
+            IssueEventKind::Reopened => "reopened",
+            IssueEventKind::ReviewDismissed => "review dismissed",
+            IssueEventKind::ReviewRequested => "review requested",
+            IssueEventKind::ReviewRequestRemoved => "review request removed",
+            IssueEventKind::Subscribed => "subscribed",
+            IssueEventKind::Unassigned => "unassigned",
+            IssueEventKind::Unlabeled => "unlabeled",
+            IssueEventKind::Unlocked => "unlocked",
+            IssueEventKind::Unsubscribed => "unsubscribed",
+            IssueEventKind::Unknown => "unknown",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for IssueEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IssueEvent, IssueEventKind};
+
+    #[test]
+    fn trait_deserialize() {
+        let value: IssueEvent = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_event.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(value.event(), IssueEventKind::Closed));
+    }
+
+    #[test]
+    fn trait_display() {
+        let value: IssueEvent = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_event.json"
+        ))
+        .unwrap();
+
+        assert_eq!("closed", value.to_string());
+    }
+
+    #[test]
+    fn trait_deserialize_unknown_kind() {
+        let kind: IssueEventKind = serde_json::from_str(r#""pinned""#).unwrap();
+
+        assert!(matches!(kind, IssueEventKind::Unknown));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<IssueEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<IssueEvent>();
+    }
+}
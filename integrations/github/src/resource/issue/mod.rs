@@ -0,0 +1,294 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, Label, NodeId};
+
+pub use self::comment::IssueComment;
+pub use self::event::{IssueEvent, IssueEventId, IssueEventKind, IssueEventLabel};
+pub use self::timeline::{IssueCommentId, TimelineComment, TimelineCrossReference, TimelineEvent};
+
+mod comment;
+mod event;
+mod timeline;
+
+id!(
+    /// Issue id
+    ///
+    /// The [`IssueId`] is a unique, numerical id that is used to interact with an issue through
+    /// [GitHub's REST API](https://docs.github.com/en/rest).
+    IssueId
+);
+
+id!(
+    /// Issue number
+    ///
+    /// Every [`Issue`] has a unique, human-readable, monotonically increasing number assigned to it.
+    /// This number identifies the issue on GitHub's website.
+    IssueNumber
+);
+
+/// Issue state
+///
+/// Issues are either open or closed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueState {
+    /// The issue is closed.
+    Closed,
+
+    /// The issue is open.
+    Open,
+}
+
+/// Reason for an issue's state
+///
+/// When an issue is closed, the reason explains whether the work has been completed or is not
+/// planned. Issues that are opened again have the reason `reopened`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueStateReason {
+    /// The issue has been resolved.
+    Completed,
+
+    /// The issue will not be worked on.
+    NotPlanned,
+
+    /// The issue has been opened again.
+    Reopened,
+}
+
+/// Reason for locking an issue
+///
+/// Locked issues can only be commented on by collaborators. The reason is shown on the issue's
+/// timeline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum IssueLockReason {
+    /// The conversation is off-topic.
+    #[serde(rename = "off-topic")]
+    OffTopic,
+
+    /// The conversation is too heated.
+    #[serde(rename = "too heated")]
+    TooHeated,
+
+    /// The conversation has been resolved.
+    #[serde(rename = "resolved")]
+    Resolved,
+
+    /// The conversation is spam.
+    #[serde(rename = "spam")]
+    Spam,
+}
+
+/// Issue
+///
+/// Issues are used to track ideas, feedback, tasks, or bugs for work on GitHub. Pull requests are
+/// issues as well, which is why GitHub's issue endpoints return them too.
+///
+/// Read more: https://docs.github.com/en/rest/issues
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Issue {
+    id: IssueId,
+    node_id: NodeId,
+    number: IssueNumber,
+    url: Url,
+    html_url: Url,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    #[serde(default)]
+    state_reason: Option<IssueStateReason>,
+    locked: bool,
+    user: Account,
+    labels: Vec<Label>,
+    assignees: Vec<Account>,
+    comments: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+impl Issue {
+    /// Returns the issue's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> IssueId {
+        self.id
+    }
+
+    /// Returns the issue's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the issue's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> IssueNumber {
+        self.number
+    }
+
+    /// Returns the API endpoint to query the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the issue's title.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the issue's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &Option<String> {
+        &self.body
+    }
+
+    /// Returns the issue's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> IssueState {
+        self.state
+    }
+
+    /// Returns the reason for the issue's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state_reason(&self) -> Option<IssueStateReason> {
+        self.state_reason
+    }
+
+    /// Indicates whether the issue is locked.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Returns the issue's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the issue's labels.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn labels(&self) -> &Vec<Label> {
+        &self.labels
+    }
+
+    /// Returns the issue's assignees.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn assignees(&self) -> &Vec<Account> {
+        &self.assignees
+    }
+
+    /// Returns the number of comments on the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn comments(&self) -> u64 {
+        self.comments
+    }
+
+    /// Returns the date when the issue was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the issue was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the date when the issue was closed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn closed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.closed_at
+    }
+}
+
+impl Display for IssueState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            IssueState::Closed => "closed",
+            IssueState::Open => "open",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for IssueStateReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            IssueStateReason::Completed => "completed",
+            IssueStateReason::NotPlanned => "not planned",
+            IssueStateReason::Reopened => "reopened",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for IssueLockReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            IssueLockReason::OffTopic => "off-topic",
+            IssueLockReason::TooHeated => "too heated",
+            IssueLockReason::Resolved => "resolved",
+            IssueLockReason::Spam => "spam",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Issue, IssueState};
+
+    #[test]
+    fn trait_deserialize() {
+        let value: Issue =
+            serde_json::from_str(include_str!("../../../tests/fixtures/resource/issue.json"))
+                .unwrap();
+
+        assert!(matches!(value.state(), IssueState::Open));
+    }
+
+    #[test]
+    fn trait_display() {
+        let value: Issue =
+            serde_json::from_str(include_str!("../../../tests/fixtures/resource/issue.json"))
+                .unwrap();
+
+        assert_eq!("#1347", value.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Issue>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Issue>();
+    }
+}
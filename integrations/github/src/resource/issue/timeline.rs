@@ -0,0 +1,308 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::id;
+use crate::resource::{Account, Issue, NodeId};
+
+use super::{IssueEvent, IssueEventKind};
+
+id!(
+    /// Issue comment id
+    ///
+    /// The [`IssueCommentId`] is a unique, numerical id that identifies a comment on an issue.
+    IssueCommentId
+);
+
+/// Timeline event
+///
+/// The timeline of an issue or pull request combines its issue events with comments, commits, and
+/// cross-references from other issues. Events that are relevant to reason about the history of an
+/// issue have their own variant, while other issue events are grouped as `Event`. Timeline items
+/// that are not (yet) supported by this crate are deserialized as `Unknown`.
+///
+/// Read more: https://docs.github.com/en/rest/issues/timeline
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+#[serde(untagged)]
+pub enum TimelineEvent {
+    /// A user was assigned to the issue.
+    Assigned(IssueEvent),
+
+    /// The issue was closed.
+    Closed(IssueEvent),
+
+    /// A comment was added to the issue.
+    Commented(TimelineComment),
+
+    /// The issue was referenced from another issue or pull request.
+    CrossReferenced(TimelineCrossReference),
+
+    /// A label was added to the issue.
+    Labeled(IssueEvent),
+
+    /// The pull request was merged.
+    Merged(IssueEvent),
+
+    /// The issue was referenced from a commit message.
+    Referenced(IssueEvent),
+
+    /// The issue was reopened.
+    Reopened(IssueEvent),
+
+    /// A user was unassigned from the issue.
+    Unassigned(IssueEvent),
+
+    /// A label was removed from the issue.
+    Unlabeled(IssueEvent),
+
+    /// Any other issue event.
+    Event(IssueEvent),
+
+    /// A timeline item that is not (yet) supported by this crate.
+    Unknown,
+}
+
+/// Comment in a timeline
+///
+/// Comments on an issue are part of its timeline.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TimelineComment {
+    id: IssueCommentId,
+    node_id: NodeId,
+    url: Url,
+    html_url: Url,
+    body: Option<String>,
+    user: Account,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Cross-reference in a timeline
+///
+/// An issue is cross-referenced when another issue or pull request mentions it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TimelineCrossReference {
+    actor: Option<Account>,
+    source: CrossReferenceSource,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+struct CrossReferenceSource {
+    issue: Issue,
+}
+
+impl TimelineEvent {
+    /// Returns the date when the event occurred.
+    ///
+    /// Timeline items that are not supported by this crate do not have a date.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        match self {
+            TimelineEvent::Commented(comment) => Some(comment.created_at()),
+            TimelineEvent::CrossReferenced(reference) => Some(reference.created_at()),
+            TimelineEvent::Assigned(event)
+            | TimelineEvent::Closed(event)
+            | TimelineEvent::Labeled(event)
+            | TimelineEvent::Merged(event)
+            | TimelineEvent::Referenced(event)
+            | TimelineEvent::Reopened(event)
+            | TimelineEvent::Unassigned(event)
+            | TimelineEvent::Unlabeled(event)
+            | TimelineEvent::Event(event) => Some(event.created_at()),
+            TimelineEvent::Unknown => None,
+        }
+    }
+}
+
+impl TimelineComment {
+    /// Returns the comment's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> IssueCommentId {
+        self.id
+    }
+
+    /// Returns the comment's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the comment.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the comment's body.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn body(&self) -> &Option<String> {
+        &self.body
+    }
+
+    /// Returns the comment's author.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+
+    /// Returns the date when the comment was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the comment was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl TimelineCrossReference {
+    /// Returns the account that referenced the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn actor(&self) -> &Option<Account> {
+        &self.actor
+    }
+
+    /// Returns the issue or pull request that referenced the issue.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn source(&self) -> &Issue {
+        &self.source.issue
+    }
+
+    /// Returns the date when the issue was referenced.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the reference was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+// GitHub identifies the kind of a timeline item with its `event` field. The field is part of the
+// issue events as well, which is why the enum is not internally tagged and instead dispatches on a
+// copy of the field.
+impl<'de> Deserialize<'de> for TimelineEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = Value::deserialize(deserializer)?;
+
+        let kind = match value.get("event").and_then(Value::as_str) {
+            Some("commented") => {
+                return serde_json::from_value(value)
+                    .map(TimelineEvent::Commented)
+                    .map_err(D::Error::custom)
+            }
+            Some("cross-referenced") => {
+                return serde_json::from_value(value)
+                    .map(TimelineEvent::CrossReferenced)
+                    .map_err(D::Error::custom)
+            }
+            Some(kind) => serde_json::from_value(Value::String(kind.into()))
+                .unwrap_or(IssueEventKind::Unknown),
+            None => IssueEventKind::Unknown,
+        };
+
+        if matches!(kind, IssueEventKind::Unknown) {
+            return Ok(TimelineEvent::Unknown);
+        }
+
+        let event: IssueEvent = serde_json::from_value(value).map_err(D::Error::custom)?;
+
+        let timeline_event = match kind {
+            IssueEventKind::Assigned => TimelineEvent::Assigned(event),
+            IssueEventKind::Closed => TimelineEvent::Closed(event),
+            IssueEventKind::Labeled => TimelineEvent::Labeled(event),
+            IssueEventKind::Merged => TimelineEvent::Merged(event),
+            IssueEventKind::Referenced => TimelineEvent::Referenced(event),
+            IssueEventKind::Reopened => TimelineEvent::Reopened(event),
+            IssueEventKind::Unassigned => TimelineEvent::Unassigned(event),
+            IssueEventKind::Unlabeled => TimelineEvent::Unlabeled(event),
+            _ => TimelineEvent::Event(event),
+        };
+
+        Ok(timeline_event)
+    }
+}
+
+impl Display for TimelineEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            TimelineEvent::Assigned(_) => "assigned",
+            TimelineEvent::Closed(_) => "closed",
+            TimelineEvent::Commented(_) => "commented",
+            TimelineEvent::CrossReferenced(_) => "cross-referenced",
+            TimelineEvent::Labeled(_) => "labeled",
+            TimelineEvent::Merged(_) => "merged",
+            TimelineEvent::Referenced(_) => "referenced",
+            TimelineEvent::Reopened(_) => "reopened",
+            TimelineEvent::Unassigned(_) => "unassigned",
+            TimelineEvent::Unlabeled(_) => "unlabeled",
+            TimelineEvent::Event(event) => return write!(f, "{}", event),
+            TimelineEvent::Unknown => "unknown",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimelineEvent;
+
+    #[test]
+    fn trait_deserialize() {
+        let timeline: Vec<TimelineEvent> = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_timeline.json"
+        ))
+        .unwrap();
+
+        assert!(matches!(timeline[0], TimelineEvent::Labeled(_)));
+        assert!(matches!(timeline[1], TimelineEvent::Commented(_)));
+        assert!(matches!(timeline[2], TimelineEvent::CrossReferenced(_)));
+        assert!(matches!(timeline[3], TimelineEvent::Unknown));
+        assert!(matches!(timeline[4], TimelineEvent::Closed(_)));
+    }
+
+    #[test]
+    fn trait_display() {
+        let timeline: Vec<TimelineEvent> = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/issue_timeline.json"
+        ))
+        .unwrap();
+
+        assert_eq!("cross-referenced", timeline[2].to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<TimelineEvent>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<TimelineEvent>();
+    }
+}
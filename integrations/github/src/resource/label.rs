@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::NodeId;
+use crate::{id, name};
+
+id!(
+    /// Label id
+    ///
+    /// The [`LabelId`] is a unique, numerical id that identifies a label.
+    LabelId
+);
+
+name!(
+    /// Label name
+    ///
+    /// Labels have a human-readable name that is unique within their repository.
+    LabelName
+);
+
+/// Label
+///
+/// Labels categorize issues and pull requests. Each repository has its own set of labels, which
+/// starts with GitHub's default labels.
+///
+/// Read more: https://docs.github.com/en/rest/issues/labels
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Label {
+    id: LabelId,
+    node_id: NodeId,
+    url: Url,
+    name: LabelName,
+    description: Option<String>,
+    color: String,
+    default: bool,
+}
+
+impl Label {
+    /// Returns the label's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> LabelId {
+        self.id
+    }
+
+    /// Returns the label's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the API endpoint to query the label.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the label's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &LabelName {
+        &self.name
+    }
+
+    /// Returns the label's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    /// Returns the label's color as a hexadecimal code.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn color(&self) -> &str {
+        &self.color
+    }
+
+    /// Indicates whether the label is one of GitHub's default labels.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn default(&self) -> bool {
+        self.default
+    }
+}
+
+impl Display for Label {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Label;
+
+    #[test]
+    fn trait_deserialize() {
+        let value: Label =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/label.json")).unwrap();
+
+        assert!(value.default());
+    }
+
+    #[test]
+    fn trait_display() {
+        let value: Label =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/label.json")).unwrap();
+
+        assert_eq!("bug", value.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Label>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Label>();
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::Account;
+
+/// Membership state
+///
+/// Users who are invited to an organization have a pending membership until they accept the
+/// invitation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipState {
+    /// The user is a member of the organization
+    Active,
+
+    /// The user has been invited, but has not accepted the invitation yet
+    Pending,
+}
+
+/// Membership role
+///
+/// The role determines the permissions that a member has in the organization.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipRole {
+    /// Owner of the organization
+    Admin,
+
+    /// Manager of the organization's billing settings
+    BillingManager,
+
+    /// Member of the organization
+    Member,
+}
+
+/// Organization membership
+///
+/// A membership connects a user with an organization, and describes the user's role in it.
+///
+/// Read more: https://docs.github.com/en/rest/orgs/members
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Membership {
+    url: Url,
+    state: MembershipState,
+    role: MembershipRole,
+    organization_url: Url,
+    user: Account,
+}
+
+impl Membership {
+    /// Returns the API endpoint to query the membership.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the membership's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> MembershipState {
+        self.state
+    }
+
+    /// Returns the member's role in the organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn role(&self) -> MembershipRole {
+        self.role
+    }
+
+    /// Returns the API endpoint to query the organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn organization_url(&self) -> &Url {
+        &self.organization_url
+    }
+
+    /// Returns the member.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn user(&self) -> &Account {
+        &self.user
+    }
+}
+
+impl Display for MembershipState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MembershipState::Active => "active",
+            MembershipState::Pending => "pending",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for MembershipRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            MembershipRole::Admin => "admin",
+            MembershipRole::BillingManager => "billing_manager",
+            MembershipRole::Member => "member",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Membership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.user, self.role)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Membership, MembershipRole};
+
+    fn membership() -> Membership {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/membership.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let membership = membership();
+
+        assert_eq!(MembershipRole::Admin, membership.role());
+        assert_eq!("jdno", membership.user().login().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("jdno (admin)", membership().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Membership>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Membership>();
+    }
+}
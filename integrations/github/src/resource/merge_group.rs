@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::resource::{GitRef, GitSha};
+
+/// Merge group
+///
+/// When pull requests are added to a merge queue, GitHub groups them and creates a temporary branch
+/// that contains the changes of the group on top of the base branch. Checks must pass on the head
+/// commit of this branch before the group is merged.
+///
+/// Read more: https://docs.github.com/en/repositories/configuring-branches-and-merges-in-your-repository/configuring-pull-request-merges/managing-a-merge-queue
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MergeGroup {
+    head_sha: GitSha,
+    head_ref: GitRef,
+    base_sha: GitSha,
+    base_ref: GitRef,
+}
+
+impl MergeGroup {
+    /// Returns the SHA of the merge group's head commit.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn head_sha(&self) -> &GitSha {
+        &self.head_sha
+    }
+
+    /// Returns the full ref of the merge group's temporary branch.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn head_ref(&self) -> &GitRef {
+        &self.head_ref
+    }
+
+    /// Returns the SHA of the base branch when the merge group was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn base_sha(&self) -> &GitSha {
+        &self.base_sha
+    }
+
+    /// Returns the full ref of the base branch, e.g. `refs/heads/main`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn base_ref(&self) -> &GitRef {
+        &self.base_ref
+    }
+}
+
+impl Display for MergeGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.head_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MergeGroup;
+
+    fn merge_group() -> MergeGroup {
+        serde_json::from_str(
+            r#"{
+                "head_sha": "ec26c3e57ca3a959ca5aad62de7213c562f8c821",
+                "head_ref": "refs/heads/gh-readonly-queue/main/pr-42-d6fde92930d4715a2b49857d24b940956b26d2d3",
+                "base_sha": "d6fde92930d4715a2b49857d24b940956b26d2d3",
+                "base_ref": "refs/heads/main"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        assert_eq!("refs/heads/main", merge_group().base_ref().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(
+            "refs/heads/gh-readonly-queue/main/pr-42-d6fde92930d4715a2b49857d24b940956b26d2d3",
+            merge_group().to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MergeGroup>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MergeGroup>();
+    }
+}
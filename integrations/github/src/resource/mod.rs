@@ -11,35 +11,136 @@ use serde::{Deserialize, Serialize};
 use crate::name;
 
 pub use self::account::{Account, AccountId, AccountType, Login};
+pub use self::actions::{
+    ActionsPublicKey, ActionsPublicKeyId, ActionsSecretName, ActionsSecretValue, ActionsVariable,
+    ActionsVariableName,
+};
+pub use self::actions_billing::ActionsBilling;
 pub use self::app::{App, AppId, AppName, AppSlug};
+pub use self::bot::Bot;
+pub use self::branch_protection_rule::{
+    BranchProtectionRule, BranchProtectionRuleId, EnforcementLevel,
+};
+pub use self::check_like::{aggregate_conclusion, CheckLike};
 pub use self::check_run::{
     CheckRun, CheckRunConclusion, CheckRunId, CheckRunName, CheckRunOutput, CheckRunOutputSummary,
     CheckRunOutputTitle, CheckRunStatus,
 };
-pub use self::check_suite::{CheckSuite, CheckSuiteId, MinimalCheckSuite};
+pub use self::check_suite::{
+    AutoTriggerCheck, CheckSuite, CheckSuiteId, CheckSuitePreferences, MinimalCheckSuite,
+};
+pub use self::code_owners::{CodeOwner, CodeOwners, CodeOwnersRule};
+pub use self::code_scanning_alert::{
+    CodeScanningAlert, CodeScanningAlertDismissedReason, CodeScanningAlertInstance,
+    CodeScanningAlertLocation, CodeScanningAlertNumber, CodeScanningAlertState, CodeScanningRule,
+    CodeScanningRuleId, CodeScanningRuleSeverity, CodeScanningTool, CodeScanningToolName,
+};
+pub use self::commit_comment::{CommitComment, CommitCommentId};
+pub use self::commit_status::{
+    CommitStatus, CommitStatusContext, CommitStatusId, CommitStatusState,
+};
+pub use self::contributor::Contributor;
+pub use self::dependabot_alert::{
+    DependabotAlert, DependabotAlertDependency, DependabotAlertDismissedReason,
+    DependabotAlertNumber, DependabotAlertState, Package, PackageEcosystem, PackageName,
+};
+pub use self::discussion::{
+    Discussion, DiscussionCategory, DiscussionCategoryId, DiscussionCategoryName,
+    DiscussionComment, DiscussionCommentId, DiscussionId, DiscussionNumber, DiscussionState,
+};
+pub use self::environment::{Environment, EnvironmentId, EnvironmentName};
 pub use self::file::File;
-pub use self::git::{GitRef, GitSha};
-pub use self::installation::{Installation, InstallationId};
+pub use self::git::{GitRef, GitRefType, GitSha, PusherType};
+pub use self::git_commit::{
+    GitCommit, GitCommitParent, GitCommitTree, GitCommitVerification, GitUser,
+};
+pub use self::hook::{Hook, HookConfig, HookId, HookType};
+pub use self::hook_delivery::{HookDelivery, HookDeliveryGuid, HookDeliveryId};
+pub use self::installation::{
+    Installation, InstallationId, InstallationRepository, RepositorySelection,
+};
+pub use self::issue::{
+    Issue, IssueComment, IssueCommentId, IssueEvent, IssueEventId, IssueEventKind, IssueEventLabel,
+    IssueId, IssueLockReason, IssueNumber, IssueState, IssueStateReason, TimelineComment,
+    TimelineCrossReference, TimelineEvent,
+};
+pub use self::label::{Label, LabelId, LabelName};
 pub use self::license::{License, LicenseKey, LicenseName, SpdxId};
+pub use self::membership::{Membership, MembershipRole, MembershipState};
+pub use self::merge_group::MergeGroup;
 pub use self::organization::{Organization, OrganizationId};
+pub use self::preserved::Preserved;
 pub use self::pull_request::{PullRequest, PullRequestBranch, PullRequestId, PullRequestNumber};
+pub use self::rate_limit::{RateLimit, RateLimitOverview};
 pub use self::repository::{
     MinimalRepository, Repository, RepositoryFullName, RepositoryId, RepositoryName,
 };
+pub use self::repository_scoped::RepositoryScoped;
+pub use self::ruleset::{
+    Ruleset, RulesetBypassActor, RulesetEnforcement, RulesetId, RulesetName, RulesetRule,
+    RulesetSourceType, RulesetTarget,
+};
+pub use self::sarif_upload::{SarifId, SarifUpload};
+pub use self::secret_scanning_alert::{
+    SecretScanningAlert, SecretScanningAlertNumber, SecretScanningAlertResolution,
+    SecretScanningAlertState,
+};
+pub use self::security_advisory::{CveId, GhsaId, SecurityAdvisory, SecurityAdvisorySeverity};
+pub use self::statistics::{CodeFrequency, CommitActivity, Participation};
+pub use self::team::{Team, TeamId, TeamName, TeamPrivacy, TeamSlug};
+pub use self::traffic::{CloneTraffic, PopularPath, TrafficBucket, ViewTraffic};
+pub use self::tree::{Tree, TreeEntry, TreeEntryType};
+pub use self::user::User;
 pub use self::visibility::Visibility;
+pub use self::workflow_run::{
+    WorkflowId, WorkflowRun, WorkflowRunConclusion, WorkflowRunId, WorkflowRunStatus,
+};
 
 mod account;
+mod actions;
+mod actions_billing;
 mod app;
+mod bot;
+mod branch_protection_rule;
+mod check_like;
 mod check_run;
 mod check_suite;
+mod code_owners;
+mod code_scanning_alert;
+mod commit_comment;
+mod commit_status;
+mod contributor;
+mod dependabot_alert;
+mod discussion;
+mod environment;
 mod file;
 mod git;
+mod git_commit;
+mod hook;
+mod hook_delivery;
 mod installation;
+mod issue;
+mod label;
 mod license;
+mod membership;
+mod merge_group;
 mod organization;
+mod preserved;
 mod pull_request;
+mod rate_limit;
 mod repository;
+mod repository_scoped;
+mod ruleset;
+mod sarif_upload;
+mod secret_scanning_alert;
+mod security_advisory;
+mod statistics;
+mod team;
+mod traffic;
+mod tree;
+mod user;
 mod visibility;
+mod workflow_run;
 
 name!(
     /// Unique identifier used with GitHub's GraphQL API
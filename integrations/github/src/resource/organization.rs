@@ -3,8 +3,10 @@ use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use automatons::Error;
+
 use crate::id;
-use crate::resource::{Login, NodeId};
+use crate::resource::{Account, AccountType, Login, NodeId};
 
 id!(
     /// Organization id
@@ -108,6 +110,50 @@ impl Organization {
     }
 }
 
+impl TryFrom<Account> for Organization {
+    type Error = Error;
+
+    /// Converts an organization account into an organization.
+    ///
+    /// Accounts reference the organization's API endpoints below `/users`, so the conversion
+    /// derives the endpoints below `/orgs` from the account's URL. The account does not contain
+    /// the organization's description, which is left empty. Use the
+    /// [`GetOrganization`](crate::task::GetOrganization) task to get the full organization.
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        if account.account_type() != AccountType::Organization {
+            return Err(Error::Serialization(format!(
+                "failed to convert {:?} account {} to an organization",
+                account.account_type(),
+                account.login()
+            )));
+        }
+
+        let url = account
+            .url()
+            .join(&format!("../orgs/{}", account.login()))
+            .map_err(|error| Error::Serialization(error.to_string()))?;
+        let endpoint = |path: &str| {
+            Url::parse(&format!("{}/{}", url, path))
+                .map_err(|error| Error::Serialization(error.to_string()))
+        };
+
+        Ok(Self {
+            login: account.login().clone(),
+            id: OrganizationId::new(account.id().get()),
+            node_id: account.node_id().clone(),
+            repos_url: endpoint("repos")?,
+            events_url: endpoint("events")?,
+            hooks_url: endpoint("hooks")?,
+            issues_url: endpoint("issues")?,
+            members_url: endpoint("members{/member}")?,
+            public_members_url: endpoint("public_members{/member}")?,
+            avatar_url: account.avatar_url().clone(),
+            description: String::new(),
+            url,
+        })
+    }
+}
+
 impl Display for Organization {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.login)
@@ -116,6 +162,10 @@ impl Display for Organization {
 
 #[cfg(test)]
 mod tests {
+    use automatons::Error;
+
+    use crate::resource::Account;
+
     use super::Organization;
 
     #[test]
@@ -138,6 +188,35 @@ mod tests {
         assert_eq!("devxbots", organization.to_string());
     }
 
+    #[test]
+    fn trait_try_from_account() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/user.json")).unwrap();
+        json["type"] = "Organization".into();
+        let account: Account = serde_json::from_value(json).unwrap();
+
+        let organization = Organization::try_from(account).unwrap();
+
+        assert_eq!(
+            "https://api.github.com/orgs/octocat",
+            organization.url().as_str()
+        );
+        assert_eq!(
+            "https://api.github.com/orgs/octocat/hooks",
+            organization.hooks_url().as_str()
+        );
+    }
+
+    #[test]
+    fn trait_try_from_user_account() {
+        let account: Account =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/user.json")).unwrap();
+
+        let error = Organization::try_from(account).unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
@@ -0,0 +1,117 @@
+use std::ops::Deref;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Resource that preserves its original payload
+///
+/// The resources and events in this crate only model the fields that are relevant to automatons,
+/// and drop all other fields that GitHub sends. Serializing them again thus loses data, which is a
+/// problem when an event is passed on to other consumers, e.g. through a queue.
+///
+/// `Preserved` deserializes the payload into a typed resource, but keeps the original payload as
+/// well. It dereferences to the typed resource, and serializes the original payload, so that
+/// round-tripping the resource is lossless.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Preserved<T> {
+    value: T,
+    raw: Value,
+}
+
+impl<T> Preserved<T> {
+    /// Returns the typed resource.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the original payload.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+
+    /// Returns the typed resource and drops the original payload.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Preserved<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Preserved<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let raw = Value::deserialize(deserializer)?;
+        let value = T::deserialize(&raw).map_err(D::Error::custom)?;
+
+        Ok(Self { value, raw })
+    }
+}
+
+impl<T> Serialize for Preserved<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::event::CheckRunEvent;
+    use crate::resource::Repository;
+
+    use super::Preserved;
+
+    #[test]
+    fn trait_deserialize() {
+        let repository: Preserved<Repository> = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/repository.json"
+        ))
+        .unwrap();
+
+        assert_eq!(518377950, repository.id().get());
+    }
+
+    #[test]
+    fn trait_serialize_preserves_unknown_fields() {
+        let payload = include_str!("../../tests/fixtures/event/check_run.completed.json");
+
+        let event: Preserved<CheckRunEvent> = serde_json::from_str(payload).unwrap();
+        let serialized = serde_json::to_value(&event).unwrap();
+
+        let original: Value = serde_json::from_str(payload).unwrap();
+        assert_eq!(original, serialized);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Preserved<Repository>>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Preserved<Repository>>();
+    }
+}
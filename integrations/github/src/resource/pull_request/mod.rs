@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::id;
+use crate::resource::{Account, Label, Team};
 
 pub use self::branch::PullRequestBranch;
 
@@ -30,6 +31,9 @@ id!(
 /// Pull requests are a feature of GitHub to merge two branches. Users can create, review, and merge
 /// pull requests using GitHub's platform. Each pull request has a unique `id`, a human-readable
 /// `number`, and references to the two branches.
+///
+/// Pull requests that are referenced by other resources, e.g. check runs, have no labels and no
+/// requested reviewers.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct PullRequest {
     id: PullRequestId,
@@ -37,6 +41,13 @@ pub struct PullRequest {
     url: Url,
     head: PullRequestBranch,
     base: PullRequestBranch,
+
+    #[serde(default)]
+    labels: Vec<Label>,
+    #[serde(default)]
+    requested_reviewers: Vec<Account>,
+    #[serde(default)]
+    requested_teams: Vec<Team>,
 }
 
 impl PullRequest {
@@ -69,6 +80,24 @@ impl PullRequest {
     pub fn base(&self) -> &PullRequestBranch {
         &self.base
     }
+
+    /// Returns the pull request's labels.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn labels(&self) -> &Vec<Label> {
+        &self.labels
+    }
+
+    /// Returns the users whose review has been requested.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requested_reviewers(&self) -> &Vec<Account> {
+        &self.requested_reviewers
+    }
+
+    /// Returns the teams whose review has been requested.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn requested_teams(&self) -> &Vec<Team> {
+        &self.requested_teams
+    }
 }
 
 impl Display for PullRequest {
@@ -89,6 +118,9 @@ mod tests {
         .unwrap();
 
         assert_eq!(27, pr.number().get());
+        assert_eq!("dependencies", pr.labels()[0].name().get());
+        assert!(pr.requested_reviewers().is_empty());
+        assert_eq!("maintainers", pr.requested_teams()[0].slug().get());
     }
 
     #[test]
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Rate limit status
+///
+/// GitHub limits the number of requests that an app or user can send in an hour. The status shows
+/// how many requests remain in the current window, and when the window resets.
+///
+/// Read more: https://docs.github.com/en/rest/rate-limit
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RateLimit {
+    limit: u64,
+    used: u64,
+    remaining: u64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    reset: DateTime<Utc>,
+}
+
+/// Rate limit status for all resources
+///
+/// GitHub tracks separate rate limits for different parts of its API, for example the REST API
+/// (`core`), the search API (`search`), and the GraphQL API (`graphql`).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RateLimitOverview {
+    resources: BTreeMap<String, RateLimit>,
+    rate: RateLimit,
+}
+
+impl RateLimit {
+    /// Returns the maximum number of requests in the current window.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Returns the number of requests that were sent in the current window.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Returns the number of requests that remain in the current window.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the date when the current window resets.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn reset(&self) -> &DateTime<Utc> {
+        &self.reset
+    }
+
+    /// Returns the share of the limit that was used, between `0.0` and `1.0`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn usage(&self) -> f64 {
+        if self.limit == 0 {
+            return 1.0;
+        }
+
+        self.used as f64 / self.limit as f64
+    }
+}
+
+impl RateLimitOverview {
+    /// Returns the rate limits of all resources, indexed by the resource's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resources(&self) -> &BTreeMap<String, RateLimit> {
+        &self.resources
+    }
+
+    /// Returns the rate limit of the given resource, e.g. `core` or `graphql`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resource(&self, name: &str) -> Option<&RateLimit> {
+        self.resources.get(name)
+    }
+
+    /// Returns the rate limit of the REST API.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rate(&self) -> &RateLimit {
+        &self.rate
+    }
+}
+
+impl Display for RateLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.used, self.limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimitOverview;
+
+    fn overview() -> RateLimitOverview {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/rate_limit.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let overview = overview();
+
+        assert_eq!(4999, overview.rate().remaining());
+        assert_eq!(1691591363, overview.rate().reset().timestamp());
+        assert_eq!(18, overview.resource("search").unwrap().remaining());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("12/30", overview().resource("search").unwrap().to_string());
+    }
+
+    #[test]
+    fn usage() {
+        assert_eq!(0.4, overview().resource("search").unwrap().usage());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RateLimitOverview>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RateLimitOverview>();
+    }
+}
@@ -1,10 +1,13 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::resource::{Account, License, NodeId, Visibility};
+use automatons::Error;
+
+use crate::resource::{Account, License, Login, NodeId, Visibility};
 use crate::{id, name};
 
 pub use self::minimal::MinimalRepository;
@@ -34,10 +37,68 @@ name!(
     RepositoryFullName
 );
 
+impl RepositoryFullName {
+    /// Initializes the full name from the owner and the name of a repository.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_parts(owner: &Login, name: &RepositoryName) -> Self {
+        Self::new(&format!("{}/{}", owner, name))
+    }
+
+    /// Returns the login of the repository's owner, or `None` if the full name is invalid.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn owner(&self) -> Option<Login> {
+        self.split().map(|(owner, _)| Login::new(owner))
+    }
+
+    /// Returns the name of the repository, or `None` if the full name is invalid.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> Option<RepositoryName> {
+        self.split().map(|(_, name)| RepositoryName::new(name))
+    }
+
+    /// Splits the full name into the owner and the name, if it has exactly one non-empty of each.
+    fn split(&self) -> Option<(&str, &str)> {
+        let (owner, name) = self.get().split_once('/')?;
+
+        let is_valid = |part: &str| {
+            !part.is_empty() && !part.contains('/') && !part.chars().any(char::is_whitespace)
+        };
+
+        (is_valid(owner) && is_valid(name)).then_some((owner, name))
+    }
+}
+
+impl From<(Login, RepositoryName)> for RepositoryFullName {
+    fn from((owner, name): (Login, RepositoryName)) -> Self {
+        Self::from_parts(&owner, &name)
+    }
+}
+
+impl FromStr for RepositoryFullName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let full_name = RepositoryFullName::new(s);
+
+        match full_name.split() {
+            Some(_) => Ok(full_name),
+            None => Err(Error::Serialization(format!(
+                "failed to parse {} as owner/name of a repository",
+                s
+            ))),
+        }
+    }
+}
+
 /// Repository on GitHub
 ///
 /// Repositories are a core resource on GitHub, and most other resources belong to them. They are
 /// uniquely identified by the combination of their `owner` and `name`.
+///
+/// The fields of a repository vary between versions of GitHub Enterprise Server and between the
+/// payloads of different webhook events. Only the fields that identify the repository are
+/// required, while all other fields are optional so that deserializing a repository does not fail
+/// when GitHub omits them.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Repository {
     #[serde(flatten)]
@@ -46,73 +107,74 @@ pub struct Repository {
     node_id: NodeId,
     owner: Account,
     full_name: RepositoryFullName,
-    description: String,
-    homepage: String,
-    language: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    language: Option<String>,
     license: Option<License>,
-    visibility: Visibility,
+    visibility: Option<Visibility>,
     default_branch: String,
+    #[serde(default)]
     topics: Vec<String>,
-    size: u64,
-    stargazers_count: u64,
-    watchers_count: u64,
-    forks_count: u64,
-    open_issues_count: u64,
+    size: Option<u64>,
+    stargazers_count: Option<u64>,
+    watchers_count: Option<u64>,
+    forks_count: Option<u64>,
+    open_issues_count: Option<u64>,
     private: bool,
     fork: bool,
-    has_issues: bool,
-    has_projects: bool,
-    has_wiki: bool,
-    has_pages: bool,
-    archived: bool,
-    disabled: bool,
-    allow_forking: bool,
-    is_template: bool,
-    web_commit_signoff_required: bool,
+    has_issues: Option<bool>,
+    has_projects: Option<bool>,
+    has_wiki: Option<bool>,
+    has_pages: Option<bool>,
+    archived: Option<bool>,
+    disabled: Option<bool>,
+    allow_forking: Option<bool>,
+    is_template: Option<bool>,
+    web_commit_signoff_required: Option<bool>,
     html_url: Url,
-    keys_url: Url,
-    collaborators_url: Url,
-    teams_url: Url,
-    hooks_url: Url,
-    issue_events_url: Url,
-    events_url: Url,
-    assignees_url: Url,
-    branches_url: Url,
-    tags_url: Url,
-    blobs_url: Url,
-    git_tags_url: Url,
-    git_refs_url: Url,
-    trees_url: Url,
-    statuses_url: Url,
-    languages_url: Url,
-    stargazers_url: Url,
-    contributors_url: Url,
-    subscribers_url: Url,
-    subscription_url: Url,
-    commits_url: Url,
-    git_commits_url: Url,
-    comments_url: Url,
-    issue_comment_url: Url,
-    contents_url: Url,
-    compare_url: Url,
-    merges_url: Url,
-    archive_url: Url,
-    downloads_url: Url,
-    issues_url: Url,
-    pulls_url: Url,
-    milestones_url: Url,
-    notifications_url: Url,
-    labels_url: Url,
-    releases_url: Url,
-    deployments_url: Url,
-    git_url: Url,
-    ssh_url: String,
-    clone_url: Url,
-    svn_url: Url,
+    keys_url: Option<Url>,
+    collaborators_url: Option<Url>,
+    teams_url: Option<Url>,
+    hooks_url: Option<Url>,
+    issue_events_url: Option<Url>,
+    events_url: Option<Url>,
+    assignees_url: Option<Url>,
+    branches_url: Option<Url>,
+    tags_url: Option<Url>,
+    blobs_url: Option<Url>,
+    git_tags_url: Option<Url>,
+    git_refs_url: Option<Url>,
+    trees_url: Option<Url>,
+    statuses_url: Option<Url>,
+    languages_url: Option<Url>,
+    stargazers_url: Option<Url>,
+    contributors_url: Option<Url>,
+    subscribers_url: Option<Url>,
+    subscription_url: Option<Url>,
+    commits_url: Option<Url>,
+    git_commits_url: Option<Url>,
+    comments_url: Option<Url>,
+    issue_comment_url: Option<Url>,
+    contents_url: Option<Url>,
+    compare_url: Option<Url>,
+    merges_url: Option<Url>,
+    archive_url: Option<Url>,
+    downloads_url: Option<Url>,
+    issues_url: Option<Url>,
+    pulls_url: Option<Url>,
+    milestones_url: Option<Url>,
+    notifications_url: Option<Url>,
+    labels_url: Option<Url>,
+    releases_url: Option<Url>,
+    deployments_url: Option<Url>,
+    git_url: Option<Url>,
+    ssh_url: Option<String>,
+    clone_url: Option<Url>,
+    svn_url: Option<Url>,
     mirror_url: Option<Url>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
-    pushed_at: DateTime<Utc>,
+    pushed_at: Option<DateTime<Utc>>,
 }
 
 impl Repository {
@@ -148,19 +210,19 @@ impl Repository {
 
     /// Returns the repository's description.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn description(&self) -> &String {
+    pub fn description(&self) -> &Option<String> {
         &self.description
     }
 
     /// Returns the URL to the repository's homepage.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn homepage(&self) -> &String {
+    pub fn homepage(&self) -> &Option<String> {
         &self.homepage
     }
 
     /// Returns the repository's primary programming language.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn language(&self) -> &String {
+    pub fn language(&self) -> &Option<String> {
         &self.language
     }
 
@@ -172,7 +234,7 @@ impl Repository {
 
     /// Returns the repository's visibility.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn visibility(&self) -> Visibility {
+    pub fn visibility(&self) -> Option<Visibility> {
         self.visibility
     }
 
@@ -190,31 +252,31 @@ impl Repository {
 
     /// Returns the repository's size.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn size(&self) -> u64 {
+    pub fn size(&self) -> Option<u64> {
         self.size
     }
 
     /// Returns the repository's stargazers count.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn stargazers_count(&self) -> u64 {
+    pub fn stargazers_count(&self) -> Option<u64> {
         self.stargazers_count
     }
 
     /// Returns the repository's watchers count.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn watchers_count(&self) -> u64 {
+    pub fn watchers_count(&self) -> Option<u64> {
         self.watchers_count
     }
 
     /// Returns the repository's forks count.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn forks_count(&self) -> u64 {
+    pub fn forks_count(&self) -> Option<u64> {
         self.forks_count
     }
 
     /// Returns the repository's open issues count.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn open_issues_count(&self) -> u64 {
+    pub fn open_issues_count(&self) -> Option<u64> {
         self.open_issues_count
     }
 
@@ -232,55 +294,55 @@ impl Repository {
 
     /// Indicates whether the issues feature is enabled for the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn has_issues(&self) -> bool {
+    pub fn has_issues(&self) -> Option<bool> {
         self.has_issues
     }
 
     /// Indicates whether the projects feature is enabled for the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn has_projects(&self) -> bool {
+    pub fn has_projects(&self) -> Option<bool> {
         self.has_projects
     }
 
     /// Indicates whether the wiki feature is enabled for the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn has_wiki(&self) -> bool {
+    pub fn has_wiki(&self) -> Option<bool> {
         self.has_wiki
     }
 
     /// Indicates whether the repository has a static website.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn has_pages(&self) -> bool {
+    pub fn has_pages(&self) -> Option<bool> {
         self.has_pages
     }
 
     /// Indicates whether the repository has been archived.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn archived(&self) -> bool {
+    pub fn archived(&self) -> Option<bool> {
         self.archived
     }
 
     /// Indicates whether the repository has been disabled.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn disabled(&self) -> bool {
+    pub fn disabled(&self) -> Option<bool> {
         self.disabled
     }
 
     /// Indicates whether the repository can be forked.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn allow_forking(&self) -> bool {
+    pub fn allow_forking(&self) -> Option<bool> {
         self.allow_forking
     }
 
     /// Indicates whether the repository can be used as a template.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn is_template(&self) -> bool {
+    pub fn is_template(&self) -> Option<bool> {
         self.is_template
     }
 
     /// Indicates whether the signoff is required for commits through GitHub's web interface.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn web_commit_signoff_required(&self) -> bool {
+    pub fn web_commit_signoff_required(&self) -> Option<bool> {
         self.web_commit_signoff_required
     }
 
@@ -298,235 +360,235 @@ impl Repository {
 
     /// Returns the API endpoint to query the repository's keys.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn keys_url(&self) -> &Url {
+    pub fn keys_url(&self) -> &Option<Url> {
         &self.keys_url
     }
 
     /// Returns the API endpoint to query the repository's collaborators.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn collaborators_url(&self) -> &Url {
+    pub fn collaborators_url(&self) -> &Option<Url> {
         &self.collaborators_url
     }
 
     /// Returns the API endpoint to query the repository's teams.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn teams_url(&self) -> &Url {
+    pub fn teams_url(&self) -> &Option<Url> {
         &self.teams_url
     }
 
     /// Returns the API endpoint to query the repository's hooks.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn hooks_url(&self) -> &Url {
+    pub fn hooks_url(&self) -> &Option<Url> {
         &self.hooks_url
     }
 
     /// Returns the API endpoint to query the repository's issue events.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn issue_events_url(&self) -> &Url {
+    pub fn issue_events_url(&self) -> &Option<Url> {
         &self.issue_events_url
     }
 
     /// Returns the API endpoint to query the repository's events.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn events_url(&self) -> &Url {
+    pub fn events_url(&self) -> &Option<Url> {
         &self.events_url
     }
 
     /// Returns the API endpoint to query the repository's assignees.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn assignees_url(&self) -> &Url {
+    pub fn assignees_url(&self) -> &Option<Url> {
         &self.assignees_url
     }
 
     /// Returns the API endpoint to query the repository's branches.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn branches_url(&self) -> &Url {
+    pub fn branches_url(&self) -> &Option<Url> {
         &self.branches_url
     }
 
     /// Returns the API endpoint to query the repository's tags.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn tags_url(&self) -> &Url {
+    pub fn tags_url(&self) -> &Option<Url> {
         &self.tags_url
     }
 
     /// Returns the API endpoint to query the repository's blobs.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn blobs_url(&self) -> &Url {
+    pub fn blobs_url(&self) -> &Option<Url> {
         &self.blobs_url
     }
 
     /// Returns the API endpoint to query the repository's git tags.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn git_tags_url(&self) -> &Url {
+    pub fn git_tags_url(&self) -> &Option<Url> {
         &self.git_tags_url
     }
 
     /// Returns the API endpoint to query the repository's git refs.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn git_refs_url(&self) -> &Url {
+    pub fn git_refs_url(&self) -> &Option<Url> {
         &self.git_refs_url
     }
 
     /// Returns the API endpoint to query the repository's git trees.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn trees_url(&self) -> &Url {
+    pub fn trees_url(&self) -> &Option<Url> {
         &self.trees_url
     }
 
     /// Returns the API endpoint to query the repository's statuses.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn statuses_url(&self) -> &Url {
+    pub fn statuses_url(&self) -> &Option<Url> {
         &self.statuses_url
     }
 
     /// Returns the API endpoint to query the repository's programming languages.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn languages_url(&self) -> &Url {
+    pub fn languages_url(&self) -> &Option<Url> {
         &self.languages_url
     }
 
     /// Returns the API endpoint to query the repository's stargazers.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn stargazers_url(&self) -> &Url {
+    pub fn stargazers_url(&self) -> &Option<Url> {
         &self.stargazers_url
     }
 
     /// Returns the API endpoint to query the repository's contributors.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn contributors_url(&self) -> &Url {
+    pub fn contributors_url(&self) -> &Option<Url> {
         &self.contributors_url
     }
 
     /// Returns the API endpoint to query the repository's subscribers.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn subscribers_url(&self) -> &Url {
+    pub fn subscribers_url(&self) -> &Option<Url> {
         &self.subscribers_url
     }
 
     /// Returns the API endpoint to query the repository's subscriptions.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn subscription_url(&self) -> &Url {
+    pub fn subscription_url(&self) -> &Option<Url> {
         &self.subscription_url
     }
 
     /// Returns the API endpoint to query the repository's commits.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn commits_url(&self) -> &Url {
+    pub fn commits_url(&self) -> &Option<Url> {
         &self.commits_url
     }
 
     /// Returns the API endpoint to query the repository's git commits.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn git_commits_url(&self) -> &Url {
+    pub fn git_commits_url(&self) -> &Option<Url> {
         &self.git_commits_url
     }
 
     /// Returns the API endpoint to query the repository's comments.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn comments_url(&self) -> &Url {
+    pub fn comments_url(&self) -> &Option<Url> {
         &self.comments_url
     }
 
     /// Returns the API endpoint to query the repository's issue comments.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn issue_comment_url(&self) -> &Url {
+    pub fn issue_comment_url(&self) -> &Option<Url> {
         &self.issue_comment_url
     }
 
     /// Returns the API endpoint to query the repository's contents.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn contents_url(&self) -> &Url {
+    pub fn contents_url(&self) -> &Option<Url> {
         &self.contents_url
     }
 
     /// Returns the API endpoint to compare refs in the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn compare_url(&self) -> &Url {
+    pub fn compare_url(&self) -> &Option<Url> {
         &self.compare_url
     }
 
     /// Returns the API endpoint to query the repository's merges.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn merges_url(&self) -> &Url {
+    pub fn merges_url(&self) -> &Option<Url> {
         &self.merges_url
     }
 
     /// Returns the API endpoint to retrieve the repository's archive.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn archive_url(&self) -> &Url {
+    pub fn archive_url(&self) -> &Option<Url> {
         &self.archive_url
     }
 
     /// Returns the API endpoint to query the repository's downloads.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn downloads_url(&self) -> &Url {
+    pub fn downloads_url(&self) -> &Option<Url> {
         &self.downloads_url
     }
 
     /// Returns the API endpoint to query the repository's issues.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn issues_url(&self) -> &Url {
+    pub fn issues_url(&self) -> &Option<Url> {
         &self.issues_url
     }
 
     /// Returns the API endpoint to query the repository's pull requests.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn pulls_url(&self) -> &Url {
+    pub fn pulls_url(&self) -> &Option<Url> {
         &self.pulls_url
     }
 
     /// Returns the API endpoint to query the repository's milestones.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn milestones_url(&self) -> &Url {
+    pub fn milestones_url(&self) -> &Option<Url> {
         &self.milestones_url
     }
 
     /// Returns the API endpoint to query the repository's notifications.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn notifications_url(&self) -> &Url {
+    pub fn notifications_url(&self) -> &Option<Url> {
         &self.notifications_url
     }
 
     /// Returns the API endpoint to query the repository's labels.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn labels_url(&self) -> &Url {
+    pub fn labels_url(&self) -> &Option<Url> {
         &self.labels_url
     }
 
     /// Returns the API endpoint to query the repository's releases.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn releases_url(&self) -> &Url {
+    pub fn releases_url(&self) -> &Option<Url> {
         &self.releases_url
     }
 
     /// Returns the API endpoint to query the repository's deployments.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn deployments_url(&self) -> &Url {
+    pub fn deployments_url(&self) -> &Option<Url> {
         &self.deployments_url
     }
 
     /// Returns the Git URL to clone the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn git_url(&self) -> &Url {
+    pub fn git_url(&self) -> &Option<Url> {
         &self.git_url
     }
 
     /// Returns the SSH URL to clone the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn ssh_url(&self) -> &str {
+    pub fn ssh_url(&self) -> &Option<String> {
         &self.ssh_url
     }
 
     /// Returns the HTTP URL to clone the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn clone_url(&self) -> &Url {
+    pub fn clone_url(&self) -> &Option<Url> {
         &self.clone_url
     }
 
     /// Returns the SVN URL to clone the repository.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn svn_url(&self) -> &Url {
+    pub fn svn_url(&self) -> &Option<Url> {
         &self.svn_url
     }
 
@@ -550,7 +612,7 @@ impl Repository {
 
     /// Returns the date when the repository was last pushed.
     #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn pushed_at(&self) -> &DateTime<Utc> {
+    pub fn pushed_at(&self) -> &Option<DateTime<Utc>> {
         &self.pushed_at
     }
 }
@@ -563,7 +625,60 @@ impl Display for Repository {
 
 #[cfg(test)]
 mod tests {
-    use super::Repository;
+    use std::str::FromStr;
+
+    use automatons::Error;
+
+    use crate::resource::Login;
+
+    use super::{Repository, RepositoryFullName, RepositoryName};
+
+    #[test]
+    fn full_name_from_parts() {
+        let full_name =
+            RepositoryFullName::from((Login::new("devxbots"), RepositoryName::new("automatons")));
+
+        assert_eq!("devxbots/automatons", full_name.get());
+    }
+
+    #[test]
+    fn full_name_owner_and_name() {
+        let full_name = RepositoryFullName::new("devxbots/automatons");
+
+        assert_eq!(Some(Login::new("devxbots")), full_name.owner());
+        assert_eq!(Some(RepositoryName::new("automatons")), full_name.name());
+    }
+
+    #[test]
+    fn full_name_owner_and_name_of_invalid_full_name() {
+        let full_name = RepositoryFullName::new("automatons");
+
+        assert_eq!(None, full_name.owner());
+        assert_eq!(None, full_name.name());
+    }
+
+    #[test]
+    fn full_name_from_str() {
+        let full_name = RepositoryFullName::from_str("devxbots/automatons").unwrap();
+
+        assert_eq!("devxbots/automatons", full_name.get());
+    }
+
+    #[test]
+    fn full_name_from_str_rejects_invalid_full_names() {
+        for invalid in [
+            "automatons",
+            "/automatons",
+            "devxbots/",
+            "a/b/c",
+            "devx bots/a",
+        ] {
+            assert!(matches!(
+                RepositoryFullName::from_str(invalid),
+                Err(Error::Serialization(_))
+            ));
+        }
+    }
 
     #[test]
     fn trait_deserialize() {
@@ -575,6 +690,51 @@ mod tests {
         assert_eq!("automatons", repository.name().get());
     }
 
+    #[test]
+    fn trait_deserialize_without_optional_fields() {
+        let repository: Repository = serde_json::from_str(
+            r#"
+            {
+              "id": 518377950,
+              "name": "automatons",
+              "url": "https://api.github.com/repos/devxbots/automatons",
+              "node_id": "R_kgDOHuWsng",
+              "owner": {
+                "login": "devxbots",
+                "id": 104442885,
+                "node_id": "O_kgDOBjmeBQ",
+                "avatar_url": "https://avatars.githubusercontent.com/u/104442885?v=4",
+                "gravatar_id": "",
+                "url": "https://api.github.com/users/devxbots",
+                "html_url": "https://github.com/devxbots",
+                "followers_url": "https://api.github.com/users/devxbots/followers",
+                "following_url": "https://api.github.com/users/devxbots/following{/other_user}",
+                "gists_url": "https://api.github.com/users/devxbots/gists{/gist_id}",
+                "starred_url": "https://api.github.com/users/devxbots/starred{/owner}{/repo}",
+                "subscriptions_url": "https://api.github.com/users/devxbots/subscriptions",
+                "organizations_url": "https://api.github.com/users/devxbots/orgs",
+                "repos_url": "https://api.github.com/users/devxbots/repos",
+                "events_url": "https://api.github.com/users/devxbots/events{/privacy}",
+                "received_events_url": "https://api.github.com/users/devxbots/received_events",
+                "type": "Organization",
+                "site_admin": false
+              },
+              "full_name": "devxbots/automatons",
+              "private": false,
+              "fork": false,
+              "html_url": "https://github.com/devxbots/automatons",
+              "default_branch": "main",
+              "created_at": "2022-07-27T08:18:41Z",
+              "updated_at": "2022-07-27T08:18:41Z"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(None, repository.visibility());
+        assert!(repository.topics().is_empty());
+    }
+
     #[test]
     fn trait_display() {
         let repository: Repository = serde_json::from_str(include_str!(
@@ -0,0 +1,58 @@
+use crate::resource::{Login, Repository, RepositoryFullName, RepositoryName};
+
+/// Resource or event that belongs to a repository
+///
+/// Most automatons need the owner and the name of a repository to send requests to GitHub's API,
+/// and they get them from the event that they react to. The [`RepositoryScoped`] trait is
+/// implemented by every event that always belongs to a repository, so that automatons can extract
+/// the owner and name the same way regardless of the event.
+///
+/// Events that only sometimes belong to a repository, e.g. ping events for organization webhooks,
+/// do not implement the trait.
+pub trait RepositoryScoped {
+    /// Returns the repository that the resource or event belongs to.
+    fn repository(&self) -> &Repository;
+
+    /// Returns the login of the repository's owner.
+    fn owner_login(&self) -> &Login {
+        self.repository().owner().login()
+    }
+
+    /// Returns the name of the repository.
+    fn repository_name(&self) -> &RepositoryName {
+        self.repository().name()
+    }
+
+    /// Returns the full name of the repository, e.g. `devxbots/automatons`.
+    fn repository_full_name(&self) -> &RepositoryFullName {
+        self.repository().full_name()
+    }
+}
+
+impl RepositoryScoped for Repository {
+    fn repository(&self) -> &Repository {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::Repository;
+
+    use super::RepositoryScoped;
+
+    #[test]
+    fn repository() {
+        let repository: Repository = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/repository.json"
+        ))
+        .unwrap();
+
+        assert_eq!("devxbots", repository.owner_login().get());
+        assert_eq!("automatons", repository.repository_name().get());
+        assert_eq!(
+            "devxbots/automatons",
+            repository.repository_full_name().get()
+        );
+    }
+}
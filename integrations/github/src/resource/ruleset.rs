@@ -0,0 +1,295 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::resource::NodeId;
+use crate::{id, name};
+
+id!(
+    /// Ruleset id
+    ///
+    /// The [`RulesetId`] is a unique, numerical id that is used to interact with a ruleset through
+    /// [GitHub's REST API](https://docs.github.com/en/rest).
+    RulesetId
+);
+
+name!(
+    /// Ruleset name
+    ///
+    /// Rulesets have a human-readable name that is unique within their repository or organization.
+    RulesetName
+);
+
+/// Target of a ruleset
+///
+/// Rulesets apply either to branches or to tags, or they restrict the files that can be pushed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesetTarget {
+    /// The ruleset applies to branches
+    Branch,
+
+    /// The ruleset applies to tags
+    Tag,
+
+    /// The ruleset applies to pushes
+    Push,
+}
+
+/// Source of a ruleset
+///
+/// Rulesets can be created for a single repository, or for an organization to apply to many of
+/// its repositories.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum RulesetSourceType {
+    /// The ruleset belongs to a repository
+    Repository,
+
+    /// The ruleset belongs to an organization
+    Organization,
+}
+
+/// Enforcement of a ruleset
+///
+/// Rulesets can be evaluated before they are enforced, which reports the rules that would have
+/// been violated without blocking anyone.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesetEnforcement {
+    /// The ruleset is not enforced
+    Disabled,
+
+    /// The ruleset is enforced
+    Active,
+
+    /// The ruleset is evaluated, but not enforced
+    Evaluate,
+}
+
+/// Ruleset
+///
+/// Rulesets are the successor to branch protection rules. A ruleset contains rules, e.g. that
+/// pull requests require reviews or that commits must be signed, and the conditions that select
+/// the branches or tags to which the rules apply. Actors can be allowed to bypass the ruleset.
+///
+/// Lists of rulesets contain a summary of each ruleset without its rules, bypass actors, and
+/// conditions.
+///
+/// Read more: https://docs.github.com/en/rest/repos/rules
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Ruleset {
+    id: RulesetId,
+    node_id: Option<NodeId>,
+    name: RulesetName,
+    target: Option<RulesetTarget>,
+    source_type: Option<RulesetSourceType>,
+    source: String,
+    enforcement: RulesetEnforcement,
+    #[serde(default)]
+    bypass_actors: Vec<RulesetBypassActor>,
+    conditions: Option<Value>,
+    #[serde(default)]
+    rules: Vec<RulesetRule>,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+/// Actor that can bypass a ruleset
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RulesetBypassActor {
+    actor_id: Option<u64>,
+    actor_type: String,
+    bypass_mode: String,
+}
+
+/// Rule of a ruleset
+///
+/// Each rule has a type, e.g. `pull_request` or `required_signatures`, and parameters that
+/// depend on the type.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RulesetRule {
+    #[serde(rename = "type")]
+    rule_type: String,
+    parameters: Option<Value>,
+}
+
+impl Ruleset {
+    /// Returns the ruleset's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> RulesetId {
+        self.id
+    }
+
+    /// Returns the ruleset's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &Option<NodeId> {
+        &self.node_id
+    }
+
+    /// Returns the ruleset's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &RulesetName {
+        &self.name
+    }
+
+    /// Returns the ruleset's target.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn target(&self) -> Option<RulesetTarget> {
+        self.target
+    }
+
+    /// Returns whether the ruleset belongs to a repository or an organization.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn source_type(&self) -> Option<RulesetSourceType> {
+        self.source_type
+    }
+
+    /// Returns the name of the repository or organization that the ruleset belongs to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the ruleset's enforcement.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn enforcement(&self) -> RulesetEnforcement {
+        self.enforcement
+    }
+
+    /// Returns the actors that can bypass the ruleset.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn bypass_actors(&self) -> &Vec<RulesetBypassActor> {
+        &self.bypass_actors
+    }
+
+    /// Returns the conditions that select the branches, tags, or repositories of the ruleset.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn conditions(&self) -> &Option<Value> {
+        &self.conditions
+    }
+
+    /// Returns the ruleset's rules.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rules(&self) -> &Vec<RulesetRule> {
+        &self.rules
+    }
+
+    /// Returns the date when the ruleset was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &Option<DateTime<Utc>> {
+        &self.created_at
+    }
+
+    /// Returns the date when the ruleset was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &Option<DateTime<Utc>> {
+        &self.updated_at
+    }
+}
+
+impl RulesetBypassActor {
+    /// Returns the id of the actor, which is empty for organization admins.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn actor_id(&self) -> Option<u64> {
+        self.actor_id
+    }
+
+    /// Returns the type of the actor, e.g. `Team` or `Integration`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn actor_type(&self) -> &str {
+        &self.actor_type
+    }
+
+    /// Returns when the actor can bypass the ruleset, either `always` or `pull_request`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn bypass_mode(&self) -> &str {
+        &self.bypass_mode
+    }
+}
+
+impl RulesetRule {
+    /// Returns the rule's type.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn rule_type(&self) -> &str {
+        &self.rule_type
+    }
+
+    /// Returns the rule's parameters.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn parameters(&self) -> &Option<Value> {
+        &self.parameters
+    }
+}
+
+impl Display for RulesetEnforcement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            RulesetEnforcement::Disabled => "disabled",
+            RulesetEnforcement::Active => "active",
+            RulesetEnforcement::Evaluate => "evaluate",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Ruleset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ruleset, RulesetEnforcement, RulesetSourceType, RulesetTarget};
+
+    fn ruleset() -> Ruleset {
+        serde_json::from_str(include_str!("../../tests/fixtures/resource/ruleset.json")).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let ruleset = ruleset();
+
+        assert_eq!(Some(RulesetTarget::Branch), ruleset.target());
+        assert_eq!(Some(RulesetSourceType::Repository), ruleset.source_type());
+        assert_eq!(RulesetEnforcement::Active, ruleset.enforcement());
+        assert_eq!("pull_request", ruleset.rules()[1].rule_type());
+        assert_eq!("Team", ruleset.bypass_actors()[0].actor_type());
+    }
+
+    #[test]
+    fn trait_deserialize_summary() {
+        let ruleset: Ruleset = serde_json::from_str(
+            r#"{
+                "id": 42,
+                "name": "protect-main",
+                "source_type": "Repository",
+                "source": "devxbots/automatons",
+                "enforcement": "evaluate"
+            }"#,
+        )
+        .unwrap();
+
+        assert!(ruleset.rules().is_empty());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("protect-main", ruleset().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Ruleset>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Ruleset>();
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::name;
+
+name!(
+    /// SARIF upload id
+    ///
+    /// GitHub processes uploaded SARIF files asynchronously, and identifies each upload with a
+    /// unique id that can be used to query the status of its processing.
+    SarifId
+);
+
+/// SARIF upload
+///
+/// When an analysis uploads its results to code scanning, GitHub returns a reference to the upload.
+/// The upload is processed asynchronously, and its status can be queried using the `url`.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SarifUpload {
+    id: SarifId,
+    url: Url,
+}
+
+impl SarifUpload {
+    /// Returns the upload's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> &SarifId {
+        &self.id
+    }
+
+    /// Returns the API endpoint to query the upload's processing status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+impl Display for SarifUpload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SarifUpload;
+
+    const JSON: &str = r#"
+    {
+        "id": "47177e22-5596-11eb-80a1-c1e54ef945c6",
+        "url": "https://api.github.com/repos/github/hello-world/code-scanning/sarifs/47177e22-5596-11eb-80a1-c1e54ef945c6"
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let upload: SarifUpload = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("47177e22-5596-11eb-80a1-c1e54ef945c6", upload.id().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        let upload: SarifUpload = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("47177e22-5596-11eb-80a1-c1e54ef945c6", upload.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SarifUpload>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SarifUpload>();
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::Account;
+
+pub use self::state::{SecretScanningAlertResolution, SecretScanningAlertState};
+
+mod state;
+
+id!(
+    /// Secret scanning alert number
+    ///
+    /// Every [`SecretScanningAlert`] has a unique, monotonically increasing number that identifies
+    /// the alert within its repository.
+    SecretScanningAlertNumber
+);
+
+/// Secret scanning alert
+///
+/// GitHub scans repositories for known types of secrets, e.g. access tokens of cloud providers,
+/// and opens an alert when it finds one. The alert does not contain the secret itself, only its
+/// type and the alert's state.
+///
+/// Read more: https://docs.github.com/en/rest/secret-scanning
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SecretScanningAlert {
+    number: SecretScanningAlertNumber,
+    url: Url,
+    html_url: Url,
+    state: SecretScanningAlertState,
+    resolution: Option<SecretScanningAlertResolution>,
+    resolved_at: Option<DateTime<Utc>>,
+    resolved_by: Option<Account>,
+    secret_type: String,
+    secret_type_display_name: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl SecretScanningAlert {
+    /// Returns the alert's number.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn number(&self) -> SecretScanningAlertNumber {
+        self.number
+    }
+
+    /// Returns the API endpoint to query the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the alert's state.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn state(&self) -> SecretScanningAlertState {
+        self.state
+    }
+
+    /// Returns the alert's resolution.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resolution(&self) -> Option<SecretScanningAlertResolution> {
+        self.resolution
+    }
+
+    /// Returns the date when the alert was resolved.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resolved_at(&self) -> &Option<DateTime<Utc>> {
+        &self.resolved_at
+    }
+
+    /// Returns the account that resolved the alert.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resolved_by(&self) -> &Option<Account> {
+        &self.resolved_by
+    }
+
+    /// Returns the type of the detected secret.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn secret_type(&self) -> &str {
+        &self.secret_type
+    }
+
+    /// Returns the human-readable name of the detected secret's type.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn secret_type_display_name(&self) -> &Option<String> {
+        &self.secret_type_display_name
+    }
+
+    /// Returns the date when the alert was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+impl Display for SecretScanningAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretScanningAlert, SecretScanningAlertResolution};
+
+    #[test]
+    fn trait_deserialize() {
+        let alert: SecretScanningAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/secret_scanning_alert.json"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Some(SecretScanningAlertResolution::Revoked),
+            alert.resolution()
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        let alert: SecretScanningAlert = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/secret_scanning_alert.json"
+        ))
+        .unwrap();
+
+        assert_eq!("#42", alert.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SecretScanningAlert>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SecretScanningAlert>();
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Secret scanning alert state
+///
+/// Secret scanning alerts are opened when GitHub detects a secret in a repository. They remain open
+/// until a user resolves them.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretScanningAlertState {
+    /// Alert is open
+    Open,
+
+    /// Alert has been resolved
+    Resolved,
+}
+
+/// Resolution of a secret scanning alert
+///
+/// When a secret scanning alert is resolved, the resolution explains why the alert was closed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretScanningAlertResolution {
+    /// Detected secret is not a real secret
+    FalsePositive,
+
+    /// Secret has been revoked
+    Revoked,
+
+    /// Secret is only used in tests
+    UsedInTests,
+
+    /// Alert will not be fixed
+    WontFix,
+}
+
+impl Display for SecretScanningAlertState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            SecretScanningAlertState::Open => "open",
+            SecretScanningAlertState::Resolved => "resolved",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for SecretScanningAlertResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            SecretScanningAlertResolution::FalsePositive => "false positive",
+            SecretScanningAlertResolution::Revoked => "revoked",
+            SecretScanningAlertResolution::UsedInTests => "used in tests",
+            SecretScanningAlertResolution::WontFix => "won't fix",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretScanningAlertResolution, SecretScanningAlertState};
+
+    #[test]
+    fn trait_deserialize() {
+        let state: SecretScanningAlertState = serde_json::from_str(r#""resolved""#).unwrap();
+
+        assert!(matches!(state, SecretScanningAlertState::Resolved));
+    }
+
+    #[test]
+    fn trait_deserialize_resolution() {
+        let resolution: SecretScanningAlertResolution =
+            serde_json::from_str(r#""used_in_tests""#).unwrap();
+
+        assert!(matches!(
+            resolution,
+            SecretScanningAlertResolution::UsedInTests
+        ));
+    }
+
+    #[test]
+    fn trait_display() {
+        let resolution = SecretScanningAlertResolution::WontFix;
+
+        assert_eq!("won't fix", resolution.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SecretScanningAlertState>();
+        assert_send::<SecretScanningAlertResolution>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SecretScanningAlertState>();
+        assert_sync::<SecretScanningAlertResolution>();
+    }
+}
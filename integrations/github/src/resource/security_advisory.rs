@@ -0,0 +1,179 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::name;
+
+name!(
+    /// GitHub Security Advisory id
+    ///
+    /// Security advisories in the [GitHub Advisory Database](https://github.com/advisories) are
+    /// identified by a unique GHSA id, e.g. `GHSA-rf4j-j272-fj86`.
+    GhsaId
+);
+
+name!(
+    /// Common Vulnerabilities and Exposures id
+    ///
+    /// Many security advisories reference an entry in the [CVE](https://www.cve.org) list, e.g.
+    /// `CVE-2018-6188`.
+    CveId
+);
+
+/// Severity of a security advisory
+///
+/// Security advisories are classified by the severity of the vulnerability that they describe.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityAdvisorySeverity {
+    /// Low severity
+    Low,
+
+    /// Medium severity
+    Medium,
+
+    /// High severity
+    High,
+
+    /// Critical severity
+    Critical,
+}
+
+/// Security advisory
+///
+/// Security advisories describe a vulnerability in a package. GitHub uses them to alert users when
+/// a dependency in their repository is affected by a known vulnerability.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SecurityAdvisory {
+    ghsa_id: GhsaId,
+    cve_id: Option<CveId>,
+    summary: String,
+    description: String,
+    severity: SecurityAdvisorySeverity,
+    published_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    withdrawn_at: Option<DateTime<Utc>>,
+}
+
+impl SecurityAdvisory {
+    /// Returns the security advisory's GHSA id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn ghsa_id(&self) -> &GhsaId {
+        &self.ghsa_id
+    }
+
+    /// Returns the security advisory's CVE id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn cve_id(&self) -> &Option<CveId> {
+        &self.cve_id
+    }
+
+    /// Returns the security advisory's summary.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Returns the security advisory's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the security advisory's severity.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn severity(&self) -> SecurityAdvisorySeverity {
+        self.severity
+    }
+
+    /// Returns the date when the security advisory was published.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn published_at(&self) -> &DateTime<Utc> {
+        &self.published_at
+    }
+
+    /// Returns the date when the security advisory was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Returns the date when the security advisory was withdrawn.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn withdrawn_at(&self) -> &Option<DateTime<Utc>> {
+        &self.withdrawn_at
+    }
+}
+
+impl Display for SecurityAdvisorySeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            SecurityAdvisorySeverity::Low => "low",
+            SecurityAdvisorySeverity::Medium => "medium",
+            SecurityAdvisorySeverity::High => "high",
+            SecurityAdvisorySeverity::Critical => "critical",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for SecurityAdvisory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ghsa_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecurityAdvisory, SecurityAdvisorySeverity};
+
+    const JSON: &str = r#"
+    {
+        "ghsa_id": "GHSA-rf4j-j272-fj86",
+        "cve_id": "CVE-2018-6188",
+        "summary": "Django allows remote attackers to obtain potentially sensitive information by leveraging data exposure from the confirm_login_allowed() method, as demonstrated by discovering whether a user account is inactive",
+        "description": "django.contrib.auth.forms.AuthenticationForm in Django 2.0 before 2.0.2, and 1.11.8 and 1.11.9, allows remote attackers to obtain potentially sensitive information by leveraging data exposure from the confirm_login_allowed() method, as demonstrated by discovering whether a user account is inactive.",
+        "severity": "high",
+        "identifiers": [
+            {
+                "value": "GHSA-rf4j-j272-fj86",
+                "type": "GHSA"
+            }
+        ],
+        "published_at": "2018-10-03T21:13:54Z",
+        "updated_at": "2022-04-26T18:35:37Z",
+        "withdrawn_at": null
+    }
+    "#;
+
+    #[test]
+    fn trait_deserialize() {
+        let advisory: SecurityAdvisory = serde_json::from_str(JSON).unwrap();
+
+        assert!(matches!(
+            advisory.severity(),
+            SecurityAdvisorySeverity::High
+        ));
+    }
+
+    #[test]
+    fn trait_display() {
+        let advisory: SecurityAdvisory = serde_json::from_str(JSON).unwrap();
+
+        assert_eq!("GHSA-rf4j-j272-fj86", advisory.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SecurityAdvisory>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SecurityAdvisory>();
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::serde::ts_seconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Commit activity in a week
+///
+/// GitHub reports the commit activity of a repository for the last year, grouped by week. Each
+/// week contains the number of commits per day, starting on Sunday.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/statistics
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CommitActivity {
+    days: Vec<u64>,
+    total: u64,
+    #[serde(with = "ts_seconds")]
+    week: DateTime<Utc>,
+}
+
+impl CommitActivity {
+    /// Returns the number of commits per day, starting on Sunday.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn days(&self) -> &Vec<u64> {
+        &self.days
+    }
+
+    /// Returns the number of commits in the week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the start of the week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn week(&self) -> &DateTime<Utc> {
+        &self.week
+    }
+}
+
+impl Display for CommitActivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} commits", self.week, self.total)
+    }
+}
+
+/// Code frequency in a week
+///
+/// GitHub reports the number of lines that were added and deleted in a repository, grouped by
+/// week. The API returns each week as an array of the week's timestamp, the additions, and the
+/// deletions as a negative number.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/statistics
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CodeFrequency(#[serde(with = "ts_seconds")] DateTime<Utc>, u64, i64);
+
+impl CodeFrequency {
+    /// Returns the start of the week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn week(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+
+    /// Returns the number of lines that were added in the week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn additions(&self) -> u64 {
+        self.1
+    }
+
+    /// Returns the number of lines that were deleted in the week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn deletions(&self) -> u64 {
+        self.2.unsigned_abs()
+    }
+}
+
+impl Display for CodeFrequency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: +{} -{}", self.0, self.additions(), self.deletions())
+    }
+}
+
+/// Weekly participation in a repository
+///
+/// GitHub counts the commits in a repository for the last 52 weeks, both for all contributors and
+/// for the repository's owner. The oldest week comes first.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/statistics
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Participation {
+    all: Vec<u64>,
+    owner: Vec<u64>,
+}
+
+impl Participation {
+    /// Returns the number of commits per week by all contributors.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn all(&self) -> &Vec<u64> {
+        &self.all
+    }
+
+    /// Returns the number of commits per week by the repository's owner.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn owner(&self) -> &Vec<u64> {
+        &self.owner
+    }
+}
+
+impl Display for Participation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} commits", self.all.iter().sum::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeFrequency, CommitActivity, Participation};
+
+    fn commit_activity() -> CommitActivity {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/commit_activity.json"
+        ))
+        .unwrap()
+    }
+
+    fn code_frequency() -> CodeFrequency {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/code_frequency.json"
+        ))
+        .unwrap()
+    }
+
+    fn participation() -> Participation {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/participation.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let commit_activity = commit_activity();
+        let code_frequency = code_frequency();
+        let participation = participation();
+
+        assert_eq!(7, commit_activity.days().len());
+        assert_eq!(1336280400, commit_activity.week().timestamp());
+        assert_eq!(1124, code_frequency.additions());
+        assert_eq!(435, code_frequency.deletions());
+        assert_eq!(52, participation.all().len());
+        assert_eq!(52, participation.owner().len());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(
+            "2012-05-06 05:00:00 UTC: 89 commits",
+            commit_activity().to_string()
+        );
+        assert_eq!(
+            "2011-04-17 00:00:00 UTC: +1124 -435",
+            code_frequency().to_string()
+        );
+        assert_eq!("1523 commits", participation().to_string());
+    }
+
+    #[test]
+    fn trait_serialize() {
+        let json = serde_json::to_string(&code_frequency()).unwrap();
+
+        assert_eq!("[1302998400,1124,-435]", json);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CodeFrequency>();
+        assert_send::<CommitActivity>();
+        assert_send::<Participation>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CodeFrequency>();
+        assert_sync::<CommitActivity>();
+        assert_sync::<Participation>();
+    }
+}
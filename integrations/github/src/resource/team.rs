@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::NodeId;
+use crate::{id, name};
+
+id!(
+    /// Team id
+    ///
+    /// The [`TeamId`] is a unique, numerical id that is used to interact with a team through
+    /// [GitHub's REST API](https://docs.github.com/en/rest).
+    TeamId
+);
+
+name!(
+    /// Team name
+    ///
+    /// Teams have a human-readable name that is unique within their organization.
+    TeamName
+);
+
+name!(
+    /// Team slug
+    ///
+    /// The slug is a URL-friendly version of the team's name, and is used to reference the team in
+    /// mentions and in the URLs of GitHub's REST API.
+    TeamSlug
+);
+
+/// Team privacy
+///
+/// Visible teams can be seen and mentioned by every member of the organization, while secret teams
+/// are only visible to their members and the organization's owners.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamPrivacy {
+    /// Visible to all members of the organization
+    Closed,
+
+    /// Only visible to the team's members and the organization's owners
+    Secret,
+}
+
+/// Team
+///
+/// Teams are groups of organization members that reflect the company's or group's structure. They
+/// can be granted access to repositories, and can be nested below a parent team.
+///
+/// Read more: https://docs.github.com/en/rest/teams/teams
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Team {
+    id: TeamId,
+    node_id: NodeId,
+    name: TeamName,
+    slug: TeamSlug,
+    description: Option<String>,
+    privacy: TeamPrivacy,
+    url: Url,
+    html_url: Url,
+    members_url: Url,
+    repositories_url: Url,
+    permission: String,
+    parent: Option<Box<Team>>,
+}
+
+impl Team {
+    /// Returns the team's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> TeamId {
+        self.id
+    }
+
+    /// Returns the team's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the team's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &TeamName {
+        &self.name
+    }
+
+    /// Returns the team's slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn slug(&self) -> &TeamSlug {
+        &self.slug
+    }
+
+    /// Returns the team's description.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    /// Returns the team's privacy.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn privacy(&self) -> TeamPrivacy {
+        self.privacy
+    }
+
+    /// Returns the API endpoint to query the team.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the team.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the API endpoint to query the team's members.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn members_url(&self) -> &Url {
+        &self.members_url
+    }
+
+    /// Returns the API endpoint to query the team's repositories.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn repositories_url(&self) -> &Url {
+        &self.repositories_url
+    }
+
+    /// Returns the default permission of the team on its repositories, e.g. `pull` or `push`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn permission(&self) -> &str {
+        &self.permission
+    }
+
+    /// Returns the team's parent, if the team is nested.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn parent(&self) -> Option<&Team> {
+        self.parent.as_deref()
+    }
+}
+
+impl Display for TeamPrivacy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            TeamPrivacy::Closed => "closed",
+            TeamPrivacy::Secret => "secret",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+impl Display for Team {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Team, TeamPrivacy};
+
+    fn team() -> Team {
+        serde_json::from_str(include_str!("../../tests/fixtures/resource/team.json")).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let team = team();
+
+        assert_eq!("maintainers", team.slug().get());
+        assert_eq!(TeamPrivacy::Closed, team.privacy());
+        assert_eq!("core", team.parent().unwrap().slug().get());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("maintainers", team().to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Team>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Team>();
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Traffic in a period of time
+///
+/// GitHub groups the traffic of a repository into buckets of a day or a week. Each bucket counts
+/// the total number of clones or views, and the number of unique visitors that caused them.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TrafficBucket {
+    timestamp: DateTime<Utc>,
+    count: u64,
+    uniques: u64,
+}
+
+impl TrafficBucket {
+    /// Returns the start of the period.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn timestamp(&self) -> &DateTime<Utc> {
+        &self.timestamp
+    }
+
+    /// Returns the total number of clones or views in the period.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of unique cloners or visitors in the period.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn uniques(&self) -> u64 {
+        self.uniques
+    }
+}
+
+impl Display for TrafficBucket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} unique)",
+            self.timestamp, self.count, self.uniques
+        )
+    }
+}
+
+/// Clones of a repository
+///
+/// GitHub counts the clones of a repository over the last 14 days, both in total and per day or
+/// week. Traffic data is only available to users and apps with push access to the repository.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/traffic
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CloneTraffic {
+    count: u64,
+    uniques: u64,
+    clones: Vec<TrafficBucket>,
+}
+
+impl CloneTraffic {
+    /// Returns the total number of clones in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of unique cloners in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn uniques(&self) -> u64 {
+        self.uniques
+    }
+
+    /// Returns the clones per day or week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn clones(&self) -> &Vec<TrafficBucket> {
+        &self.clones
+    }
+}
+
+impl Display for CloneTraffic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} clones ({} unique)", self.count, self.uniques)
+    }
+}
+
+/// Views of a repository
+///
+/// GitHub counts the page views of a repository over the last 14 days, both in total and per day
+/// or week. Traffic data is only available to users and apps with push access to the repository.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/traffic
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ViewTraffic {
+    count: u64,
+    uniques: u64,
+    views: Vec<TrafficBucket>,
+}
+
+impl ViewTraffic {
+    /// Returns the total number of views in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of unique visitors in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn uniques(&self) -> u64 {
+        self.uniques
+    }
+
+    /// Returns the views per day or week.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn views(&self) -> &Vec<TrafficBucket> {
+        &self.views
+    }
+}
+
+impl Display for ViewTraffic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} views ({} unique)", self.count, self.uniques)
+    }
+}
+
+/// Popular path in a repository
+///
+/// GitHub ranks the pages of a repository by their views over the last 14 days, and reports the
+/// ten most popular ones.
+///
+/// Read more: https://docs.github.com/en/rest/metrics/traffic#get-top-referral-paths
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct PopularPath {
+    path: String,
+    title: String,
+    count: u64,
+    uniques: u64,
+}
+
+impl PopularPath {
+    /// Returns the path of the page.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the title of the page.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the total number of views of the page in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of unique visitors of the page in the last 14 days.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn uniques(&self) -> u64 {
+        self.uniques
+    }
+}
+
+impl Display for PopularPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CloneTraffic, PopularPath, TrafficBucket, ViewTraffic};
+
+    fn clone_traffic() -> CloneTraffic {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/clone_traffic.json"
+        ))
+        .unwrap()
+    }
+
+    fn view_traffic() -> ViewTraffic {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/view_traffic.json"
+        ))
+        .unwrap()
+    }
+
+    fn popular_path() -> PopularPath {
+        serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/popular_path.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let clone_traffic = clone_traffic();
+        let view_traffic = view_traffic();
+        let popular_path = popular_path();
+
+        assert_eq!(3, clone_traffic.clones().len());
+        assert_eq!(154, clone_traffic.clones()[2].count());
+        assert_eq!(414, view_traffic.views()[1].uniques());
+        assert_eq!("/devxbots/automatons", popular_path.path());
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!("173 clones (128 unique)", clone_traffic().to_string());
+        assert_eq!("14850 views (3782 unique)", view_traffic().to_string());
+        assert_eq!("/devxbots/automatons", popular_path().to_string());
+        assert_eq!(
+            "2022-10-10 00:00:00 UTC: 2 (1 unique)",
+            clone_traffic().clones()[0].to_string()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CloneTraffic>();
+        assert_send::<PopularPath>();
+        assert_send::<TrafficBucket>();
+        assert_send::<ViewTraffic>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CloneTraffic>();
+        assert_sync::<PopularPath>();
+        assert_sync::<TrafficBucket>();
+        assert_sync::<ViewTraffic>();
+    }
+}
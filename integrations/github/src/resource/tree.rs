@@ -0,0 +1,182 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::resource::GitSha;
+
+/// Git tree
+///
+/// Trees are the objects in Git's database that store the hierarchy of files and directories in a
+/// repository. Each tree lists its entries, which are either blobs (files), other trees
+/// (directories), or commits (submodules).
+///
+/// GitHub limits the number of entries that it returns for a tree. When the limit is exceeded, the
+/// tree is marked as truncated and only contains a subset of its entries.
+///
+/// Read more: https://docs.github.com/en/rest/git/trees
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Tree {
+    sha: GitSha,
+    url: Url,
+    tree: Vec<TreeEntry>,
+    truncated: bool,
+}
+
+/// Entry in a Git tree
+///
+/// Entries in a tree have a path relative to the root of the tree, and point to another object in
+/// Git's database.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TreeEntry {
+    path: String,
+    mode: String,
+    #[serde(rename = "type")]
+    kind: TreeEntryType,
+    sha: GitSha,
+    size: Option<u64>,
+    url: Option<Url>,
+}
+
+/// Type of a tree entry
+///
+/// Entries in a tree point to a file, a directory, or a submodule.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeEntryType {
+    /// The entry is a file.
+    Blob,
+
+    /// The entry is a submodule.
+    Commit,
+
+    /// The entry is a directory.
+    Tree,
+}
+
+impl Tree {
+    /// Returns the tree's SHA.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sha(&self) -> &GitSha {
+        &self.sha
+    }
+
+    /// Returns the API endpoint to query the tree.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the tree's entries.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn entries(&self) -> &Vec<TreeEntry> {
+        &self.tree
+    }
+
+    /// Indicates whether GitHub truncated the tree's entries.
+    ///
+    /// Truncated trees only contain a subset of their entries. Query the subtrees individually to
+    /// get the missing entries.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl TreeEntry {
+    /// Returns the entry's path.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the entry's file mode, e.g. `100644` for a file.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Returns the entry's type.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn kind(&self) -> TreeEntryType {
+        self.kind
+    }
+
+    /// Returns the SHA of the object that the entry points to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sha(&self) -> &GitSha {
+        &self.sha
+    }
+
+    /// Returns the size of the file in bytes, or `None` if the entry is not a file.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Returns the API endpoint to query the object that the entry points to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Option<Url> {
+        &self.url
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.sha)
+    }
+}
+
+impl Display for TreeEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path)
+    }
+}
+
+impl Display for TreeEntryType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            TreeEntryType::Blob => "blob",
+            TreeEntryType::Commit => "commit",
+            TreeEntryType::Tree => "tree",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tree, TreeEntryType};
+
+    #[test]
+    fn trait_deserialize() {
+        let tree: Tree =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/tree.json")).unwrap();
+
+        assert_eq!(3, tree.entries().len());
+        assert_eq!(TreeEntryType::Tree, tree.entries()[1].kind());
+        assert_eq!(None, tree.entries()[1].size());
+        assert!(!tree.truncated());
+    }
+
+    #[test]
+    fn trait_display() {
+        let tree: Tree =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/tree.json")).unwrap();
+
+        assert_eq!("lib/octokit.rb", tree.entries()[2].to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Tree>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Tree>();
+    }
+}
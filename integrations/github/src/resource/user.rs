@@ -0,0 +1,223 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use automatons::Error;
+
+use crate::resource::{Account, AccountId, AccountType, Login, NodeId};
+
+/// User
+///
+/// Users are the humans that build software on GitHub. Events reference users as an [`Account`],
+/// which can be converted into a [`User`] that only contains the account's information. The
+/// user's profile, e.g. their name and company, is only returned when the user is queried with the
+/// [`GetUser`](crate::task::GetUser) task.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct User {
+    login: Login,
+    id: AccountId,
+    node_id: NodeId,
+    avatar_url: Url,
+    url: Url,
+    html_url: Url,
+    site_admin: bool,
+
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    company: Option<String>,
+    #[serde(default)]
+    blog: Option<String>,
+    #[serde(default)]
+    location: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    bio: Option<String>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl User {
+    /// Returns the user's unique [`Login`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn login(&self) -> &Login {
+        &self.login
+    }
+
+    /// Returns the user's unique [`AccountId`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> AccountId {
+        self.id
+    }
+
+    /// Returns the user's unique [`NodeId`].
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the URL to the user's avatar.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn avatar_url(&self) -> &Url {
+        &self.avatar_url
+    }
+
+    /// Returns the API endpoint to query the user.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the user.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Indicates whether the user is a site admin.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn site_admin(&self) -> bool {
+        self.site_admin
+    }
+
+    /// Returns the user's name, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// Returns the user's company, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn company(&self) -> &Option<String> {
+        &self.company
+    }
+
+    /// Returns the user's blog, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn blog(&self) -> &Option<String> {
+        &self.blog
+    }
+
+    /// Returns the user's location, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn location(&self) -> &Option<String> {
+        &self.location
+    }
+
+    /// Returns the user's email address, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn email(&self) -> &Option<String> {
+        &self.email
+    }
+
+    /// Returns the user's bio, if it is public.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn bio(&self) -> &Option<String> {
+        &self.bio
+    }
+
+    /// Returns the date when the user signed up.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &Option<DateTime<Utc>> {
+        &self.created_at
+    }
+}
+
+impl TryFrom<Account> for User {
+    type Error = Error;
+
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        if account.account_type() != AccountType::User {
+            return Err(Error::Serialization(format!(
+                "failed to convert {:?} account {} to a user",
+                account.account_type(),
+                account.login()
+            )));
+        }
+
+        Ok(Self {
+            login: account.login().clone(),
+            id: account.id(),
+            node_id: account.node_id().clone(),
+            avatar_url: account.avatar_url().clone(),
+            url: account.url().clone(),
+            html_url: account.html_url().clone(),
+            site_admin: account.site_admin(),
+            name: None,
+            company: None,
+            blog: None,
+            location: None,
+            email: None,
+            bio: None,
+            created_at: None,
+        })
+    }
+}
+
+impl Display for User {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.login)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::resource::Account;
+
+    use super::User;
+
+    #[test]
+    fn trait_deserialize() {
+        let user: User =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/user.json")).unwrap();
+
+        assert_eq!("octocat", user.login().get());
+        assert_eq!(&Some("GitHub".into()), user.company());
+    }
+
+    #[test]
+    fn trait_display() {
+        let user: User =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/user.json")).unwrap();
+
+        assert_eq!("octocat", user.to_string());
+    }
+
+    #[test]
+    fn trait_try_from_account() {
+        let account: Account =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/user.json")).unwrap();
+
+        let user = User::try_from(account).unwrap();
+
+        assert_eq!(1, user.id().get());
+        assert!(user.name().is_none());
+    }
+
+    #[test]
+    fn trait_try_from_bot_account() {
+        let account: Account =
+            serde_json::from_str(include_str!("../../tests/fixtures/resource/bot.json")).unwrap();
+
+        let error = User::try_from(account).unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<User>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<User>();
+    }
+}
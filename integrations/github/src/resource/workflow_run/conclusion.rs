@@ -0,0 +1,88 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Workflow run conclusion
+///
+/// When a workflow run finishes, its conclusion indicates the success or failure of its jobs.
+/// Workflow runs share most conclusions with check runs, but can also fail to start.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunConclusion {
+    /// Workflow run finished successfully
+    Success,
+
+    /// Workflow run failed
+    Failure,
+
+    /// Workflow run finished in a neutral state
+    Neutral,
+
+    /// Workflow run was skipped
+    Skipped,
+
+    /// Workflow run was cancelled
+    Cancelled,
+
+    /// Workflow run timed out
+    TimedOut,
+
+    /// Workflow run requested an action from the user
+    ActionRequired,
+
+    /// Workflow run was marked as stale by GitHub
+    Stale,
+
+    /// Workflow run failed to start, e.g. because the workflow file is invalid
+    StartupFailure,
+}
+
+impl Display for WorkflowRunConclusion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            WorkflowRunConclusion::Success => "success",
+            WorkflowRunConclusion::Failure => "failure",
+            WorkflowRunConclusion::Neutral => "neutral",
+            WorkflowRunConclusion::Skipped => "skipped",
+            WorkflowRunConclusion::Cancelled => "cancelled",
+            WorkflowRunConclusion::TimedOut => "timed out",
+            WorkflowRunConclusion::ActionRequired => "action required",
+            WorkflowRunConclusion::Stale => "stale",
+            WorkflowRunConclusion::StartupFailure => "startup failure",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkflowRunConclusion;
+
+    #[test]
+    fn trait_deserialize() {
+        let conclusion: WorkflowRunConclusion =
+            serde_json::from_str(r#""startup_failure""#).unwrap();
+
+        assert!(matches!(conclusion, WorkflowRunConclusion::StartupFailure));
+    }
+
+    #[test]
+    fn trait_display() {
+        let conclusion = WorkflowRunConclusion::StartupFailure;
+
+        assert_eq!("startup failure", conclusion.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WorkflowRunConclusion>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WorkflowRunConclusion>();
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::id;
+use crate::resource::{CheckSuiteId, GitRef, GitSha, NodeId};
+
+pub use self::conclusion::WorkflowRunConclusion;
+pub use self::status::WorkflowRunStatus;
+
+mod conclusion;
+mod status;
+
+id!(
+    /// Workflow run id
+    ///
+    /// The [`WorkflowRunId`] is a unique, numerical id that is used to interact with a workflow run
+    /// through [GitHub's REST API](https://docs.github.com/en/rest).
+    WorkflowRunId
+);
+
+id!(
+    /// Workflow id
+    ///
+    /// The [`WorkflowId`] is a unique, numerical id that identifies a workflow file in a
+    /// repository.
+    WorkflowId
+);
+
+/// Workflow run
+///
+/// GitHub Actions runs a workflow when one of its triggers occurs, e.g. a push to a branch. Each
+/// workflow run executes the jobs of the workflow for a specific commit, and can be cancelled or
+/// re-run through the API.
+///
+/// Read more: https://docs.github.com/en/rest/actions/workflow-runs
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct WorkflowRun {
+    id: WorkflowRunId,
+    node_id: NodeId,
+    name: Option<String>,
+    head_branch: Option<GitRef>,
+    head_sha: GitSha,
+    run_number: u64,
+    run_attempt: Option<u64>,
+    event: String,
+    status: Option<WorkflowRunStatus>,
+    conclusion: Option<WorkflowRunConclusion>,
+    workflow_id: WorkflowId,
+    check_suite_id: Option<CheckSuiteId>,
+    url: Url,
+    html_url: Url,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl WorkflowRun {
+    /// Returns the workflow run's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn id(&self) -> WorkflowRunId {
+        self.id
+    }
+
+    /// Returns the workflow run's node id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the workflow run's name.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// Returns the branch that the workflow run was triggered for.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn head_branch(&self) -> &Option<GitRef> {
+        &self.head_branch
+    }
+
+    /// Returns the SHA of the commit that the workflow run was triggered for.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn head_sha(&self) -> &GitSha {
+        &self.head_sha
+    }
+
+    /// Returns the workflow run's number, which increases with every run of the workflow.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn run_number(&self) -> u64 {
+        self.run_number
+    }
+
+    /// Returns the workflow run's attempt, which increases when the run is re-run.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn run_attempt(&self) -> Option<u64> {
+        self.run_attempt
+    }
+
+    /// Returns the event that triggered the workflow run, e.g. `push` or `pull_request`.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Returns the workflow run's status.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn status(&self) -> Option<WorkflowRunStatus> {
+        self.status
+    }
+
+    /// Returns the workflow run's conclusion.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn conclusion(&self) -> Option<WorkflowRunConclusion> {
+        self.conclusion
+    }
+
+    /// Returns the id of the workflow that was run.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn workflow_id(&self) -> WorkflowId {
+        self.workflow_id
+    }
+
+    /// Returns the id of the check suite that belongs to the workflow run.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn check_suite_id(&self) -> Option<CheckSuiteId> {
+        self.check_suite_id
+    }
+
+    /// Returns the API endpoint to query the workflow run.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the URL to the workflow run.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn html_url(&self) -> &Url {
+        &self.html_url
+    }
+
+    /// Returns the date when the workflow run was created.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Returns the date when the workflow run was last updated.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+impl Display for WorkflowRun {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} #{}", name, self.run_number),
+            None => write!(f, "#{}", self.run_number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorkflowRun, WorkflowRunStatus};
+
+    #[test]
+    fn trait_deserialize() {
+        let workflow_run: WorkflowRun = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/workflow_run.json"
+        ))
+        .unwrap();
+
+        assert_eq!(30433642, workflow_run.id().get());
+        assert_eq!(Some(WorkflowRunStatus::Queued), workflow_run.status());
+    }
+
+    #[test]
+    fn trait_display() {
+        let workflow_run: WorkflowRun = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/resource/workflow_run.json"
+        ))
+        .unwrap();
+
+        assert_eq!("Build #562", workflow_run.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WorkflowRun>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WorkflowRun>();
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Workflow run status
+///
+/// Workflow runs are `queued` when they are created, and can wait for an approval or a concurrency
+/// group before they start. Once the run is finished, the status is set to `completed` and the
+/// conclusion of the run is set.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunStatus {
+    /// Requested state
+    Requested,
+
+    /// Queued state
+    Queued,
+
+    /// Pending state, e.g. while waiting for a concurrency group
+    Pending,
+
+    /// Waiting state, e.g. while waiting for the approval of a deployment
+    Waiting,
+
+    /// In progress state
+    InProgress,
+
+    /// Completed state
+    Completed,
+}
+
+impl Display for WorkflowRunStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            WorkflowRunStatus::Requested => "requested",
+            WorkflowRunStatus::Queued => "queued",
+            WorkflowRunStatus::Pending => "pending",
+            WorkflowRunStatus::Waiting => "waiting",
+            WorkflowRunStatus::InProgress => "in progress",
+            WorkflowRunStatus::Completed => "completed",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkflowRunStatus;
+
+    #[test]
+    fn trait_deserialize() {
+        let status: WorkflowRunStatus = serde_json::from_str(r#""in_progress""#).unwrap();
+
+        assert!(matches!(status, WorkflowRunStatus::InProgress));
+    }
+
+    #[test]
+    fn trait_display() {
+        let status = WorkflowRunStatus::InProgress;
+
+        assert_eq!("in progress", status.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WorkflowRunStatus>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WorkflowRunStatus>();
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueNumber, Label, LabelName, Login, RepositoryName};
+
+/// Add labels to an issue
+///
+/// Adds labels to an issue or pull request, which share their numbers. Labels that do not exist in
+/// the repository yet are created. The GitHub App must have the `issues:write` or
+/// `pull_requests:write` permission to add labels.
+///
+/// https://docs.github.com/en/rest/issues/labels#add-labels-to-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct AddLabels<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+    labels: &'a [LabelName],
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct AddLabelsPayload<'a> {
+    labels: &'a [LabelName],
+}
+
+impl<'a> AddLabels<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+        labels: &'a [LabelName],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+            labels,
+        }
+    }
+
+    /// Add labels to an issue
+    ///
+    /// Returns all labels of the issue after the labels have been added.
+    pub async fn execute(&self) -> Result<Vec<Label>, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/labels",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let payload = AddLabelsPayload {
+            labels: self.labels,
+        };
+
+        let labels = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to add labels")?;
+
+        Ok(labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, LabelName, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_add_labels;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::AddLabels;
+
+    #[tokio::test]
+    async fn task_returns_labels() {
+        let _token_mock = mock_installation_access_tokens();
+        let _labels_mock = mock_add_labels();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let labels = [LabelName::new("bug")];
+
+        let task = AddLabels::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(27),
+            &labels,
+        );
+
+        let labels = task.execute().await.unwrap();
+
+        assert_eq!("bug", labels[0].name().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<AddLabels>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<AddLabels>();
+    }
+}
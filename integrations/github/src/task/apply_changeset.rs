@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use base64::encode;
+use serde::{Deserialize, Serialize};
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitCommit, GitRef, GitSha, Login, RepositoryName, Tree};
+use crate::task::{CommitSigner, CreateCommit, CreateCommitArgs, GetTree, GitUserArgs};
+
+/// Mode of new files in a Git tree
+const FILE_MODE: &str = "100644";
+
+/// Apply a changeset as a single commit on a new branch
+///
+/// Writes and deletes files in a repository with a single commit, which is created on a new branch
+/// that starts at the head of a base branch. The task composes the calls to GitHub's Git database
+/// API: it uploads a blob for every written file, creates a tree on top of the base commit's
+/// tree, creates a commit for the tree, and finally creates the branch. The base branch itself is
+/// not changed, so that the new branch can be proposed as a pull request.
+///
+/// Files that are replaced keep their mode in the base tree, e.g. executable files stay
+/// executable. New files are added as regular files. GitHub truncates very large trees, in which
+/// case files that are missing from the base tree are written as regular files as well.
+///
+/// The commit is signed when the task is given a [`CommitSigner`], in the same way as with the
+/// [`CreateCommit`] task.
+///
+/// https://docs.github.com/en/rest/git
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyChangeset<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    changeset_args: &'a ChangesetArgs,
+    signer: Option<&'a dyn CommitSigner>,
+}
+
+/// Input for apply changeset task
+///
+/// The changeset describes the files that are written or deleted, the commit that records the
+/// changes, and the branches that the commit is based on and created on.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ChangesetArgs {
+    /// The name of the branch that the changes are based on, e.g. `main`.
+    pub base: GitRef,
+
+    /// The name of the branch that is created for the changes.
+    pub branch: GitRef,
+
+    /// The commit message.
+    pub message: String,
+
+    /// The author of the commit, which defaults to the authenticated user or app.
+    pub author: Option<GitUserArgs>,
+
+    /// The changes to the files in the repository.
+    pub changes: Vec<FileChange>,
+}
+
+/// Change to a file in a changeset
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FileChange {
+    /// Adds the file, or replaces its content if it exists.
+    Write {
+        /// The path of the file in the repository.
+        path: String,
+
+        /// The new content of the file.
+        content: Vec<u8>,
+    },
+
+    /// Deletes the file.
+    Delete {
+        /// The path of the file in the repository.
+        path: String,
+    },
+}
+
+/// Result of applying a changeset
+///
+/// The [`AppliedChangeset`] contains the branch that was created for the changeset, and the commit
+/// that the branch points to.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct AppliedChangeset {
+    branch: GitRef,
+    commit: GitCommit,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct GitReference {
+    object: GitReferenceObject,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct GitReferenceObject {
+    sha: GitSha,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct GitBlob {
+    sha: GitSha,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CreateBlobPayload {
+    content: String,
+    encoding: &'static str,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CreateTreePayload<'a> {
+    base_tree: &'a GitSha,
+    tree: Vec<TreeEntryPayload<'a>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct TreeEntryPayload<'a> {
+    path: &'a str,
+    mode: &'a str,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    sha: Option<GitSha>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CreateReferencePayload<'a> {
+    #[serde(rename = "ref")]
+    git_ref: String,
+    sha: &'a GitSha,
+}
+
+impl<'a> ApplyChangeset<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        changeset_args: &'a ChangesetArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            changeset_args,
+            signer: None,
+        }
+    }
+
+    /// Signs the commit with the given signer.
+    pub fn signer(mut self, signer: &'a dyn CommitSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Apply a changeset as a single commit on a new branch
+    pub async fn execute(&self) -> Result<AppliedChangeset, Error> {
+        if self.changeset_args.changes.is_empty() {
+            return Err(Error::Configuration(
+                "failed to apply changeset without changes".into(),
+            ));
+        }
+
+        let base_sha = self.get_base_sha().await?;
+        let base_commit: GitCommit = self
+            .github_client
+            .get(&self.url(&format!("git/commits/{}", base_sha)))
+            .await
+            .context("failed to query base commit")?;
+
+        let tree = self.create_tree(base_commit.tree()).await?;
+
+        let commit_args = CreateCommitArgs {
+            message: self.changeset_args.message.clone(),
+            tree: tree.sha().clone(),
+            parents: vec![base_sha],
+            author: self.changeset_args.author.clone(),
+            committer: None,
+        };
+
+        let mut create_commit = CreateCommit::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            &commit_args,
+        );
+        if let Some(signer) = self.signer {
+            create_commit = create_commit.signer(signer);
+        }
+        let commit = create_commit.execute().await?;
+
+        let _reference: GitReference = self
+            .github_client
+            .post(
+                &self.url("git/refs"),
+                Some(CreateReferencePayload {
+                    git_ref: format!("refs/heads/{}", self.changeset_args.branch),
+                    sha: commit.sha(),
+                }),
+            )
+            .await
+            .context("failed to create branch")?;
+
+        Ok(AppliedChangeset {
+            branch: self.changeset_args.branch.clone(),
+            commit,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "/repos/{}/{}/{}",
+            self.owner.get(),
+            self.repository.get(),
+            path
+        )
+    }
+
+    async fn get_base_sha(&self) -> Result<GitSha, Error> {
+        let reference: GitReference = self
+            .github_client
+            .get(&self.url(&format!("git/ref/heads/{}", self.changeset_args.base)))
+            .await
+            .context("failed to query base branch")?;
+
+        Ok(reference.object.sha)
+    }
+
+    async fn create_tree(&self, base_tree: &GitSha) -> Result<Tree, Error> {
+        let existing_tree =
+            GetTree::new(self.github_client, self.owner, self.repository, base_tree)
+                .recursive(true)
+                .execute()
+                .await?;
+        let modes: HashMap<&str, &str> = existing_tree
+            .entries()
+            .iter()
+            .map(|entry| (entry.path(), entry.mode()))
+            .collect();
+        let mode = |path: &str| modes.get(path).copied().unwrap_or(FILE_MODE);
+
+        let mut entries = Vec::with_capacity(self.changeset_args.changes.len());
+
+        for change in &self.changeset_args.changes {
+            let entry = match change {
+                FileChange::Write { path, content } => {
+                    let blob: GitBlob = self
+                        .github_client
+                        .post(
+                            &self.url("git/blobs"),
+                            Some(CreateBlobPayload {
+                                content: encode(content),
+                                encoding: "base64",
+                            }),
+                        )
+                        .await
+                        .context("failed to create blob")?;
+
+                    TreeEntryPayload {
+                        path,
+                        mode: mode(path),
+                        kind: "blob",
+                        sha: Some(blob.sha),
+                    }
+                }
+                FileChange::Delete { path } => TreeEntryPayload {
+                    path,
+                    mode: mode(path),
+                    kind: "blob",
+                    sha: None,
+                },
+            };
+
+            entries.push(entry);
+        }
+
+        let tree = self
+            .github_client
+            .post(
+                &self.url("git/trees"),
+                Some(CreateTreePayload {
+                    base_tree,
+                    tree: entries,
+                }),
+            )
+            .await
+            .context("failed to create tree")?;
+
+        Ok(tree)
+    }
+}
+
+impl AppliedChangeset {
+    /// Returns the branch that was created for the changeset.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn branch(&self) -> &GitRef {
+        &self.branch
+    }
+
+    /// Returns the commit that contains the changes.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn commit(&self) -> &GitCommit {
+        &self.commit
+    }
+
+    /// Returns the SHA of the commit that contains the changes.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn sha(&self) -> &GitSha {
+        self.commit.sha()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::resource::{GitRef, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::git::{
+        mock_create_blob, mock_create_changeset_commit, mock_create_reference, mock_create_tree,
+        mock_get_base_tree, mock_get_commit, mock_get_reference,
+    };
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{ApplyChangeset, ChangesetArgs, FileChange};
+
+    fn changeset_args(changes: Vec<FileChange>) -> ChangesetArgs {
+        ChangesetArgs {
+            base: GitRef::new("main"),
+            branch: GitRef::new("fix-readme"),
+            message: "Fix README".into(),
+            author: None,
+            changes,
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_branch_and_commit() {
+        let _token_mock = mock_installation_access_tokens();
+        let _reference_mock = mock_get_reference();
+        let _commit_mock = mock_get_commit();
+        let _base_tree_mock = mock_get_base_tree();
+        let _blob_mock = mock_create_blob();
+        let _tree_mock = mock_create_tree();
+        let _create_commit_mock = mock_create_changeset_commit();
+        let _create_reference_mock = mock_create_reference();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let changeset_args = changeset_args(vec![
+            FileChange::Write {
+                path: "README.md".into(),
+                content: b"Hello, World!".to_vec(),
+            },
+            FileChange::Delete {
+                path: "CHANGELOG.md".into(),
+            },
+            FileChange::Write {
+                path: "NOTICE".into(),
+                content: b"Hello, World!".to_vec(),
+            },
+        ]);
+
+        let task = ApplyChangeset::new(&github_client, &login, &repository, &changeset_args);
+
+        let changeset = task.execute().await.unwrap();
+
+        assert_eq!("fix-readme", changeset.branch().get());
+        assert_eq!(
+            "7638417db6d59f3c431d3e1f261cc637155684cd",
+            changeset.sha().get()
+        );
+    }
+
+    #[tokio::test]
+    async fn task_fails_without_changes() {
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let changeset_args = changeset_args(Vec::new());
+
+        let task = ApplyChangeset::new(&github_client, &login, &repository, &changeset_args);
+
+        let error = task.execute().await.unwrap_err();
+
+        assert!(matches!(error, Error::Configuration(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ApplyChangeset>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ApplyChangeset>();
+    }
+}
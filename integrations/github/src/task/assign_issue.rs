@@ -0,0 +1,114 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Issue, IssueNumber, Login, RepositoryName};
+
+/// Assign users to an issue
+///
+/// Adds up to 10 assignees to an issue or pull request, without replacing the existing assignees.
+/// Users that cannot be assigned to the issue are silently ignored. The GitHub App must have the
+/// `issues:write` permission to assign issues.
+///
+/// https://docs.github.com/en/rest/issues/assignees#add-assignees-to-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct AssignIssue<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+    assignees: &'a [Login],
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct AssignIssuePayload<'a> {
+    assignees: &'a [Login],
+}
+
+impl<'a> AssignIssue<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+        assignees: &'a [Login],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+            assignees,
+        }
+    }
+
+    /// Assign users to an issue
+    pub async fn execute(&self) -> Result<Issue, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/assignees",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let payload = AssignIssuePayload {
+            assignees: self.assignees,
+        };
+
+        let issue = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to assign issue")?;
+
+        Ok(issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_assign_issue;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::AssignIssue;
+
+    #[tokio::test]
+    async fn task_returns_issue() {
+        let _token_mock = mock_installation_access_tokens();
+        let _assign_mock = mock_assign_issue();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let assignees = [Login::new("octocat")];
+
+        let task = AssignIssue::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1347),
+            &assignees,
+        );
+
+        let issue = task.execute().await.unwrap();
+
+        assert_eq!(1347, issue.number().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<AssignIssue>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<AssignIssue>();
+    }
+}
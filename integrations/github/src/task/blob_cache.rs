@@ -0,0 +1,221 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+
+use parking_lot::Mutex;
+
+use crate::resource::{File, GitSha};
+
+/// Default number of blobs that the in-memory cache holds
+const DEFAULT_CAPACITY: usize = 128;
+
+/// Cache for file contents
+///
+/// Git identifies the content of a file by its SHA, which changes whenever the content changes. A
+/// [`BlobCache`] stores the contents of files under their SHA, so that tasks can skip downloading
+/// files that have not changed since they were last read. Implement the trait to share the cache
+/// between processes, or use the [`MemoryBlobCache`].
+///
+/// The cache also remembers the entity tag of the last response for a file, so that tasks can ask
+/// GitHub with a conditional request whether the file has changed. Conditional requests for files
+/// that have not changed do not count against the rate limit.
+pub trait BlobCache: Debug + Send + Sync {
+    /// Returns the content of the blob with the given SHA, or `None` if it is not cached.
+    fn get(&self, sha: &GitSha) -> Option<Vec<u8>>;
+
+    /// Stores the content of the blob with the given SHA.
+    fn insert(&self, sha: GitSha, content: Vec<u8>);
+
+    /// Returns the entity tag of the last response from the URL and the file that it held.
+    fn get_entity_tag(&self, _url: &str) -> Option<(String, File)> {
+        None
+    }
+
+    /// Stores the entity tag of the response from the URL, and the file that it contained.
+    ///
+    /// The URL includes the GitHub host, since the same endpoint returns different files on
+    /// different hosts. The content of the file is not stored with the entity tag, but looked up by
+    /// its SHA.
+    fn insert_entity_tag(&self, _url: &str, _entity_tag: String, _file: File) {}
+}
+
+/// In-memory cache for file contents
+///
+/// The [`MemoryBlobCache`] holds a fixed number of blobs in memory, and evicts the least recently
+/// used blob when it is full. Entity tags are kept only as long as the blob of their file.
+#[derive(Debug)]
+pub struct MemoryBlobCache {
+    capacity: usize,
+    blobs: Mutex<MemoryBlobs>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryBlobs {
+    contents: HashMap<GitSha, Vec<u8>>,
+    recently_used: VecDeque<GitSha>,
+    entity_tags: HashMap<String, (String, File)>,
+}
+
+impl MemoryBlobCache {
+    /// Initializes a cache that holds up to `capacity` blobs
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            blobs: Mutex::new(MemoryBlobs::default()),
+        }
+    }
+
+    /// Returns the number of blobs in the cache.
+    pub fn len(&self) -> usize {
+        self.blobs.lock().contents.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl MemoryBlobs {
+    fn touch(&mut self, sha: &GitSha) {
+        if let Some(position) = self.recently_used.iter().position(|used| used == sha) {
+            self.recently_used.remove(position);
+        }
+
+        self.recently_used.push_back(sha.clone());
+    }
+
+    fn evict_entity_tags(&mut self) {
+        let contents = &self.contents;
+
+        self.entity_tags
+            .retain(|_, (_, file)| contents.contains_key(file.sha()));
+    }
+}
+
+impl BlobCache for MemoryBlobCache {
+    fn get(&self, sha: &GitSha) -> Option<Vec<u8>> {
+        let mut blobs = self.blobs.lock();
+
+        let content = blobs.contents.get(sha).cloned()?;
+        blobs.touch(sha);
+
+        Some(content)
+    }
+
+    fn insert(&self, sha: GitSha, content: Vec<u8>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut blobs = self.blobs.lock();
+
+        blobs.touch(&sha);
+        blobs.contents.insert(sha, content);
+
+        while blobs.contents.len() > self.capacity {
+            match blobs.recently_used.pop_front() {
+                Some(evicted) => blobs.contents.remove(&evicted),
+                None => break,
+            };
+        }
+
+        blobs.evict_entity_tags();
+    }
+
+    fn get_entity_tag(&self, url: &str) -> Option<(String, File)> {
+        self.blobs.lock().entity_tags.get(url).cloned()
+    }
+
+    fn insert_entity_tag(&self, url: &str, entity_tag: String, file: File) {
+        let mut blobs = self.blobs.lock();
+
+        if blobs.contents.contains_key(file.sha()) {
+            blobs.entity_tags.insert(url.into(), (entity_tag, file));
+        }
+    }
+}
+
+impl Default for MemoryBlobCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::resource::{File, GitSha};
+
+    use super::{BlobCache, MemoryBlobCache};
+
+    #[test]
+    fn get_returns_cached_content() {
+        let cache = MemoryBlobCache::default();
+        cache.insert(GitSha::new("a"), b"content".to_vec());
+
+        assert_eq!(Some(b"content".to_vec()), cache.get(&GitSha::new("a")));
+        assert_eq!(None, cache.get(&GitSha::new("b")));
+    }
+
+    #[test]
+    fn insert_evicts_least_recently_used() {
+        let cache = MemoryBlobCache::new(2);
+        cache.insert(GitSha::new("a"), b"a".to_vec());
+        cache.insert(GitSha::new("b"), b"b".to_vec());
+
+        cache.get(&GitSha::new("a"));
+        cache.insert(GitSha::new("c"), b"c".to_vec());
+
+        assert_eq!(2, cache.len());
+        assert!(cache.get(&GitSha::new("a")).is_some());
+        assert!(cache.get(&GitSha::new("b")).is_none());
+    }
+
+    #[test]
+    fn insert_without_capacity() {
+        let cache = MemoryBlobCache::new(0);
+        cache.insert(GitSha::new("a"), b"a".to_vec());
+
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn entity_tags_are_evicted_with_their_blob() {
+        let url = Url::parse("https://api.github.com/repos/octokit/octokit.rb/contents/README.md")
+            .unwrap();
+        let file = File::new(
+            "README.md".into(),
+            "README.md".into(),
+            Vec::new(),
+            GitSha::new("a"),
+            url.clone(),
+            url.clone(),
+            url.clone(),
+            url,
+        );
+
+        let cache = MemoryBlobCache::new(1);
+        cache.insert(GitSha::new("a"), b"a".to_vec());
+        cache.insert_entity_tag("/readme", r#""a""#.into(), file);
+
+        assert!(cache.get_entity_tag("/readme").is_some());
+
+        cache.insert(GitSha::new("b"), b"b".to_vec());
+
+        assert!(cache.get_entity_tag("/readme").is_none());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MemoryBlobCache>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MemoryBlobCache>();
+    }
+}
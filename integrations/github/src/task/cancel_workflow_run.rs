@@ -0,0 +1,102 @@
+use anyhow::Context;
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, WorkflowRunId};
+
+/// Cancel a workflow run
+///
+/// Cancels a workflow run that is queued or in progress, e.g. because a newer commit was pushed to
+/// the same branch. The GitHub App must have the `actions:write` permission to cancel workflow
+/// runs.
+///
+/// https://docs.github.com/en/rest/actions/workflow-runs#cancel-a-workflow-run
+#[derive(Copy, Clone, Debug)]
+pub struct CancelWorkflowRun<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    workflow_run_id: WorkflowRunId,
+}
+
+impl<'a> CancelWorkflowRun<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        workflow_run_id: WorkflowRunId,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            workflow_run_id,
+        }
+    }
+
+    /// Cancel a workflow run
+    pub async fn execute(&self) -> Result<(), Error> {
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/cancel",
+            self.owner.get(),
+            self.repository.get(),
+            self.workflow_run_id
+        );
+
+        // We need to explicitly declare the type of the body somewhere to silence a compiler error.
+        let body: Option<()> = None;
+
+        self.github_client
+            .post::<Value>(&url, body)
+            .await
+            .context("failed to cancel workflow run")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName, WorkflowRunId};
+    use crate::testing::actions::mock_cancel_workflow_run;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CancelWorkflowRun;
+
+    #[tokio::test]
+    async fn task_cancels_workflow_run() {
+        let _token_mock = mock_installation_access_tokens();
+        let cancel_mock = mock_cancel_workflow_run();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = CancelWorkflowRun::new(
+            &github_client,
+            &login,
+            &repository,
+            WorkflowRunId::new(30433642),
+        );
+
+        task.execute().await.unwrap();
+
+        cancel_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CancelWorkflowRun>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CancelWorkflowRun>();
+    }
+}
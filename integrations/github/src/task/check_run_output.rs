@@ -0,0 +1,220 @@
+use std::fmt::{Display, Formatter};
+
+use crate::resource::{CheckRunOutputSummary, CheckRunOutputTitle};
+use crate::task::{CheckRunOutputArgs, CheckRunOutputOverflow, CHECK_RUN_OUTPUT_LIMIT};
+
+/// Markdown document
+///
+/// The summary and the text of a check run's output are rendered as Markdown. This type provides
+/// helpers for the elements that are commonly used in reports, and keeps track of the document's
+/// length so that it can be checked against GitHub's limit before it is sent.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Markdown {
+    content: String,
+}
+
+impl Markdown {
+    /// Initializes an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a heading of the given level, from 1 to 6.
+    pub fn heading(mut self, level: usize, heading: &str) -> Self {
+        let level = level.clamp(1, 6);
+        self.push_block(&format!("{} {}", "#".repeat(level), heading));
+        self
+    }
+
+    /// Appends a paragraph.
+    pub fn paragraph(mut self, paragraph: &str) -> Self {
+        self.push_block(paragraph);
+        self
+    }
+
+    /// Appends an unordered list.
+    pub fn list<T: AsRef<str>>(mut self, items: &[T]) -> Self {
+        let list: Vec<String> = items
+            .iter()
+            .map(|item| format!("- {}", item.as_ref()))
+            .collect();
+
+        self.push_block(&list.join("\n"));
+        self
+    }
+
+    /// Appends a table with the given header and rows.
+    ///
+    /// Pipes and line breaks in the cells are escaped, so that they don't break the table.
+    pub fn table<H, C>(mut self, header: &[H], rows: &[Vec<C>]) -> Self
+    where
+        H: AsRef<str>,
+        C: AsRef<str>,
+    {
+        let mut lines = vec![table_row(header), table_row(&vec!["---"; header.len()])];
+        lines.extend(rows.iter().map(|row| table_row(row)));
+
+        self.push_block(&lines.join("\n"));
+        self
+    }
+
+    /// Appends a code block with the given language.
+    pub fn code_block(mut self, language: &str, code: &str) -> Self {
+        let fence = if code.contains("```") { "````" } else { "```" };
+        self.push_block(&format!("{}{}\n{}\n{}", fence, language, code, fence));
+        self
+    }
+
+    /// Appends a collapsible section that shows the summary and hides the body.
+    pub fn details(mut self, summary: &str, body: &Markdown) -> Self {
+        self.push_block(&format!(
+            "<details>\n<summary>{}</summary>\n\n{}\n\n</details>",
+            summary, body.content
+        ));
+        self
+    }
+
+    /// Returns the length of the document in characters.
+    pub fn len(&self) -> usize {
+        self.content.chars().count()
+    }
+
+    /// Indicates whether the document is empty.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Returns the number of characters that can be added before GitHub's limit is reached.
+    pub fn remaining(&self) -> usize {
+        CHECK_RUN_OUTPUT_LIMIT.saturating_sub(self.len())
+    }
+
+    /// Indicates whether the document exceeds GitHub's limit.
+    pub fn exceeds_limit(&self) -> bool {
+        self.len() > CHECK_RUN_OUTPUT_LIMIT
+    }
+
+    fn push_block(&mut self, block: &str) {
+        if !self.content.is_empty() {
+            self.content.push_str("\n\n");
+        }
+
+        self.content.push_str(block);
+    }
+}
+
+fn table_row<T: AsRef<str>>(cells: &[T]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| cell.as_ref().replace('|', "\\|").replace('\n', "<br>"))
+        .collect();
+
+    format!("| {} |", cells.join(" | "))
+}
+
+impl Display for Markdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
+/// Builder for check run output
+///
+/// The builder assembles the [`CheckRunOutputArgs`] from a title, a Markdown summary, and an
+/// optional Markdown text.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct CheckRunOutputBuilder {
+    title: CheckRunOutputTitle,
+    summary: Markdown,
+    text: Option<Markdown>,
+    overflow: CheckRunOutputOverflow,
+}
+
+impl CheckRunOutputBuilder {
+    /// Initializes a builder for output with the given title.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: CheckRunOutputTitle::new(title),
+            summary: Markdown::new(),
+            text: None,
+            overflow: CheckRunOutputOverflow::default(),
+        }
+    }
+
+    /// Sets the summary of the output.
+    pub fn summary(mut self, summary: Markdown) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Sets the text of the output.
+    pub fn text(mut self, text: Markdown) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Sets the policy for a summary or text that exceeds GitHub's limit.
+    pub fn overflow(mut self, overflow: CheckRunOutputOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Builds the output.
+    pub fn build(self) -> CheckRunOutputArgs {
+        CheckRunOutputArgs {
+            title: self.title,
+            summary: CheckRunOutputSummary::new(&self.summary.content),
+            text: self.text.map(|text| text.content),
+            overflow: self.overflow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::task::CHECK_RUN_OUTPUT_LIMIT;
+
+    use super::{CheckRunOutputBuilder, Markdown};
+
+    #[test]
+    fn markdown_table_escapes_cells() {
+        let markdown = Markdown::new().table(&["Check", "Result"], &[vec!["lint | fmt", "ok"]]);
+
+        assert_eq!(
+            "| Check | Result |\n| --- | --- |\n| lint \\| fmt | ok |",
+            markdown.to_string()
+        );
+    }
+
+    #[test]
+    fn markdown_details() {
+        let body = Markdown::new().code_block("rust", "fn main() {}");
+        let markdown = Markdown::new()
+            .heading(2, "Logs")
+            .details("Show logs", &body);
+
+        assert_eq!(
+            "## Logs\n\n<details>\n<summary>Show logs</summary>\n\n```rust\nfn main() {}\n```\n\n</details>",
+            markdown.to_string()
+        );
+    }
+
+    #[test]
+    fn markdown_remaining() {
+        let markdown = Markdown::new().paragraph("hello");
+
+        assert_eq!(CHECK_RUN_OUTPUT_LIMIT - 5, markdown.remaining());
+        assert!(!markdown.exceeds_limit());
+    }
+
+    #[test]
+    fn builder_builds_output() {
+        let output = CheckRunOutputBuilder::new("Checks")
+            .summary(Markdown::new().paragraph("3/5 checks succeeded"))
+            .text(Markdown::new().list(&["lint", "test"]))
+            .build();
+
+        assert_eq!("3/5 checks succeeded", output.summary.get());
+        assert_eq!(Some("- lint\n- test".into()), output.text);
+    }
+}
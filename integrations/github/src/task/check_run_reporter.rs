@@ -0,0 +1,431 @@
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{
+    CheckRun, CheckRunConclusion, CheckRunId, CheckRunName, CheckRunStatus, GitSha, Login,
+    RepositoryName,
+};
+use crate::task::{
+    CheckRunOutputArgs, CheckRunOutputBuilder, CreateCheckRun, CreateCheckRunArgs, Markdown,
+    UpdateCheckRun, UpdateCheckRunArgs,
+};
+
+/// Default minimum time between two progress updates
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reporter for the progress of a check run
+///
+/// Long-running automatons, e.g. static analysis, update their check run several times: they start
+/// it, report partial results while they work, and complete it with the final conclusion. The
+/// [`CheckRunReporter`] owns the check run for this flow. It creates the check run when it is
+/// started, and updates the same check run afterwards.
+///
+/// # Throttling
+///
+/// Every update is a request that counts against the installation's rate limit. Progress updates
+/// that are sent less than the reporter's interval after the previous update are skipped, so that
+/// automatons can report progress as often as they like. Starting and completing the check run are
+/// never throttled.
+#[derive(Clone, Debug)]
+pub struct CheckRunReporter<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    name: CheckRunName,
+    head_sha: GitSha,
+    interval: Duration,
+    check_run_id: Option<CheckRunId>,
+    last_update: Option<Instant>,
+}
+
+impl<'a> CheckRunReporter<'a> {
+    /// Initializes the reporter for a check run on the commit
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        name: CheckRunName,
+        head_sha: GitSha,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            name,
+            head_sha,
+            interval: DEFAULT_INTERVAL,
+            check_run_id: None,
+            last_update: None,
+        }
+    }
+
+    /// Sets the minimum time between two progress updates.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Reports on an existing check run, e.g. one that was queued when the event was received.
+    pub fn check_run(mut self, check_run_id: CheckRunId) -> Self {
+        self.check_run_id = Some(check_run_id);
+        self
+    }
+
+    /// Returns the id of the check run, or `None` if the check run has not been created yet.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn check_run_id(&self) -> Option<CheckRunId> {
+        self.check_run_id
+    }
+
+    /// Marks the check run as in progress.
+    ///
+    /// Creates the check run if the reporter has not been given an existing one.
+    pub async fn start(&mut self) -> Result<CheckRun, Error> {
+        self.begin(None).await
+    }
+
+    /// Reports partial results as the summary of the check run.
+    ///
+    /// Returns `None` if the update was skipped, because the previous update was sent less than
+    /// the reporter's interval ago. Starts the check run with the summary if it has not been
+    /// started yet.
+    pub async fn progress(&mut self, summary: Markdown) -> Result<Option<CheckRun>, Error> {
+        let output = CheckRunOutputBuilder::new(self.name.get())
+            .summary(summary)
+            .build();
+
+        let (check_run_id, last_update) = match (self.check_run_id, self.last_update) {
+            (Some(check_run_id), Some(last_update)) => (check_run_id, last_update),
+            _ => return self.begin(Some(output)).await.map(Some),
+        };
+
+        if last_update.elapsed() < self.interval {
+            return Ok(None);
+        }
+
+        let check_run_args = UpdateCheckRunArgs {
+            status: Some(CheckRunStatus::InProgress),
+            output: Some(output),
+            ..self.update_args(check_run_id)
+        };
+
+        self.update(&check_run_args).await.map(Some)
+    }
+
+    /// Completes the check run with the conclusion and the final output.
+    ///
+    /// Creates the check run if it has not been started, so that automatons that finish quickly
+    /// only send a single request.
+    pub async fn complete(
+        &mut self,
+        conclusion: CheckRunConclusion,
+        output: CheckRunOutputArgs,
+    ) -> Result<CheckRun, Error> {
+        let completed_at = Some(Utc::now());
+
+        match self.check_run_id {
+            Some(check_run_id) => {
+                let check_run_args = UpdateCheckRunArgs {
+                    conclusion: Some(conclusion),
+                    completed_at,
+                    output: Some(output),
+                    ..self.update_args(check_run_id)
+                };
+
+                self.update(&check_run_args).await
+            }
+            None => {
+                let check_run_args = CreateCheckRunArgs {
+                    conclusion: Some(conclusion),
+                    completed_at,
+                    output: Some(output),
+                    ..self.create_args()
+                };
+
+                self.create(&check_run_args).await
+            }
+        }
+    }
+
+    /// Marks the check run as in progress, and reports the output if one is given.
+    async fn begin(&mut self, output: Option<CheckRunOutputArgs>) -> Result<CheckRun, Error> {
+        let started_at = Some(Utc::now());
+
+        let check_run = match self.check_run_id {
+            Some(check_run_id) => {
+                let check_run_args = UpdateCheckRunArgs {
+                    started_at,
+                    status: Some(CheckRunStatus::InProgress),
+                    output,
+                    ..self.update_args(check_run_id)
+                };
+
+                self.update(&check_run_args).await?
+            }
+            None => {
+                let check_run_args = CreateCheckRunArgs {
+                    started_at,
+                    status: Some(CheckRunStatus::InProgress),
+                    output,
+                    ..self.create_args()
+                };
+
+                self.create(&check_run_args).await?
+            }
+        };
+
+        Ok(check_run)
+    }
+
+    fn create_args(&self) -> CreateCheckRunArgs {
+        CreateCheckRunArgs {
+            name: self.name.clone(),
+            head_sha: self.head_sha.clone(),
+            details_url: None,
+            external_id: None,
+            status: None,
+            started_at: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+        }
+    }
+
+    fn update_args(&self, check_run_id: CheckRunId) -> UpdateCheckRunArgs {
+        UpdateCheckRunArgs {
+            check_run_id,
+            name: None,
+            details_url: None,
+            external_id: None,
+            started_at: None,
+            status: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+        }
+    }
+
+    async fn create(&mut self, check_run_args: &CreateCheckRunArgs) -> Result<CheckRun, Error> {
+        let check_run = CreateCheckRun::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            check_run_args,
+        )
+        .execute()
+        .await?;
+
+        self.check_run_id = Some(check_run.id());
+        self.last_update = Some(Instant::now());
+
+        Ok(check_run)
+    }
+
+    async fn update(&mut self, check_run_args: &UpdateCheckRunArgs) -> Result<CheckRun, Error> {
+        let check_run = UpdateCheckRun::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            check_run_args,
+        )
+        .execute()
+        .await?;
+
+        self.last_update = Some(Instant::now());
+
+        Ok(check_run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{
+        CheckRunConclusion, CheckRunId, CheckRunName, GitSha, Login, RepositoryName,
+    };
+    use crate::task::{CheckRunOutputBuilder, Markdown};
+    use crate::testing::check_run::{mock_create_check_run, mock_update_check_run};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CheckRunReporter;
+
+    #[tokio::test]
+    async fn start_creates_check_run() {
+        let _token_mock = mock_installation_access_tokens();
+        let create_mock = mock_create_check_run();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let mut reporter = CheckRunReporter::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunName::new("mighty_readme"),
+            GitSha::new("ce587453ced02b1526dfb4cb910479d431683101"),
+        );
+
+        reporter.start().await.unwrap();
+
+        assert_eq!(Some(CheckRunId::new(4)), reporter.check_run_id());
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn progress_is_throttled() {
+        let _token_mock = mock_installation_access_tokens();
+        let update_mock = mock("PATCH", "/repos/github/hello-world/check-runs/4")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"status":"in_progress"}"#.into(),
+            ))
+            .with_status(200)
+            .with_body(include_str!("../../tests/fixtures/resource/check_run.json"))
+            .expect(2)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let mut reporter = CheckRunReporter::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunName::new("mighty_readme"),
+            GitSha::new("ce587453ced02b1526dfb4cb910479d431683101"),
+        )
+        .check_run(CheckRunId::new(4))
+        .interval(Duration::from_secs(60));
+
+        reporter.start().await.unwrap();
+        let progress = reporter
+            .progress(Markdown::new().paragraph("1/3 files analyzed"))
+            .await
+            .unwrap();
+
+        assert!(progress.is_none());
+
+        let mut reporter = reporter.interval(Duration::ZERO);
+        let progress = reporter
+            .progress(Markdown::new().paragraph("2/3 files analyzed"))
+            .await
+            .unwrap();
+
+        assert!(progress.is_some());
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn progress_starts_check_run_with_summary() {
+        let _token_mock = mock_installation_access_tokens();
+        let create_mock = mock("POST", "/repos/github/hello-world/check-runs")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::PartialJsonString(r#"{"status":"in_progress"}"#.into()),
+                Matcher::Regex("1/3 files analyzed".into()),
+            ]))
+            .with_status(201)
+            .with_body(include_str!("../../tests/fixtures/resource/check_run.json"))
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let mut reporter = CheckRunReporter::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunName::new("mighty_readme"),
+            GitSha::new("ce587453ced02b1526dfb4cb910479d431683101"),
+        )
+        .interval(Duration::from_secs(60));
+
+        let progress = reporter
+            .progress(Markdown::new().paragraph("1/3 files analyzed"))
+            .await
+            .unwrap();
+
+        assert!(progress.is_some());
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn complete_is_not_throttled() {
+        let _token_mock = mock_installation_access_tokens();
+        let _create_mock = mock_create_check_run();
+        let update_mock = mock_update_check_run();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let mut reporter = CheckRunReporter::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunName::new("mighty_readme"),
+            GitSha::new("ce587453ced02b1526dfb4cb910479d431683101"),
+        )
+        .interval(Duration::from_secs(60));
+
+        reporter.start().await.unwrap();
+        reporter
+            .complete(
+                CheckRunConclusion::Success,
+                CheckRunOutputBuilder::new("Mighty Readme report").build(),
+            )
+            .await
+            .unwrap();
+
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn complete_creates_check_run() {
+        let _token_mock = mock_installation_access_tokens();
+        let create_mock = mock_create_check_run();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let mut reporter = CheckRunReporter::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunName::new("mighty_readme"),
+            GitSha::new("ce587453ced02b1526dfb4cb910479d431683101"),
+        );
+
+        reporter
+            .complete(
+                CheckRunConclusion::Success,
+                CheckRunOutputBuilder::new("Mighty Readme report").build(),
+            )
+            .await
+            .unwrap();
+
+        create_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CheckRunReporter>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CheckRunReporter>();
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::{Debug, Formatter};
+
+use ssh_key::{HashAlg, LineEnding, PrivateKey};
+
+use automatons::Error;
+
+/// Namespace of SSH signatures for Git objects
+const GIT_NAMESPACE: &str = "git";
+
+/// Signer for Git commits
+///
+/// Commits that are created through GitHub's Git database API can carry a signature, which GitHub
+/// verifies against the keys of the commit's author. A [`CommitSigner`] signs the raw Git commit
+/// object, and returns the ASCII-armored signature that is sent along with the commit.
+pub trait CommitSigner: Debug + Send + Sync {
+    /// Signs the payload of a commit, and returns the ASCII-armored signature.
+    fn sign(&self, payload: &str) -> Result<String, Error>;
+}
+
+/// SSH signer for Git commits
+///
+/// The [`SshCommitSigner`] signs commits with an SSH key, the same way that `git` does when
+/// `gpg.format` is set to `ssh`. The public key must be added as a signing key to the account of
+/// the commit's author for GitHub to show the commit as verified.
+pub struct SshCommitSigner {
+    private_key: PrivateKey,
+}
+
+impl SshCommitSigner {
+    /// Initializes a signer from an unencrypted private key in the OpenSSH format.
+    ///
+    /// Returns [`Error::Configuration`] if the key cannot be parsed.
+    pub fn from_openssh(private_key: &str) -> Result<Self, Error> {
+        let private_key = PrivateKey::from_openssh(private_key)
+            .map_err(|_| Error::Configuration("failed to parse SSH signing key".into()))?;
+
+        Ok(Self { private_key })
+    }
+}
+
+impl CommitSigner for SshCommitSigner {
+    fn sign(&self, payload: &str) -> Result<String, Error> {
+        let signature = self
+            .private_key
+            .sign(GIT_NAMESPACE, HashAlg::Sha512, payload.as_bytes())
+            .map_err(|error| Error::Unknown(anyhow::anyhow!("failed to sign commit: {}", error)))?;
+
+        signature.to_pem(LineEnding::LF).map_err(|error| {
+            Error::Serialization(format!("failed to encode commit signature: {}", error))
+        })
+    }
+}
+
+impl Debug for SshCommitSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SshCommitSigner")
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ssh_key::SshSig;
+
+    use automatons::Error;
+
+    use super::{CommitSigner, SshCommitSigner, GIT_NAMESPACE};
+
+    fn signer() -> SshCommitSigner {
+        SshCommitSigner::from_openssh(include_str!("../../tests/fixtures/ssh-signing-key")).unwrap()
+    }
+
+    #[test]
+    fn sign_returns_verifiable_signature() {
+        let signer = signer();
+        let payload = "tree 827efc6d56897b048c772eb4087f854f46256132\n";
+
+        let signature = signer.sign(payload).unwrap();
+
+        let signature: SshSig = signature.parse().unwrap();
+        signer
+            .private_key
+            .public_key()
+            .verify(GIT_NAMESPACE, payload.as_bytes(), &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn from_openssh_rejects_invalid_key() {
+        let error = SshCommitSigner::from_openssh("not a key").unwrap_err();
+
+        assert!(matches!(error, Error::Configuration(_)));
+    }
+
+    #[test]
+    fn trait_debug_redacts_key() {
+        assert_eq!(
+            r#"SshCommitSigner { private_key: "[REDACTED]" }"#,
+            format!("{:?}", signer())
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SshCommitSigner>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SshCommitSigner>();
+    }
+}
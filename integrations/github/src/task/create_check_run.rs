@@ -101,9 +101,14 @@ impl<'a> CreateCheckRun<'a> {
             self.repository.get(),
         );
 
+        let mut check_run_args = self.check_run_args.clone();
+        if let Some(output) = &mut check_run_args.output {
+            output.enforce_limit()?;
+        }
+
         let check_run = self
             .github_client
-            .post(&url, Some(self.check_run_args))
+            .post(&url, Some(&check_run_args))
             .await
             .context("failed to create check run")?;
 
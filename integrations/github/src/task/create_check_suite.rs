@@ -0,0 +1,107 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CheckSuite, GitSha, Login, RepositoryName};
+
+/// Create a check suite
+///
+/// Creates a check suite for a commit. GitHub creates check suites automatically when code is
+/// pushed, unless the app has disabled it with the
+/// [`UpdateCheckSuitePreferences`](crate::task::UpdateCheckSuitePreferences) task. The GitHub App
+/// must have the `checks:write` permission to create check suites.
+///
+/// https://docs.github.com/en/rest/checks/suites#create-a-check-suite
+#[derive(Copy, Clone, Debug)]
+pub struct CreateCheckSuite<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    head_sha: &'a GitSha,
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct CreateCheckSuitePayload<'a> {
+    head_sha: &'a GitSha,
+}
+
+impl<'a> CreateCheckSuite<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        head_sha: &'a GitSha,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            head_sha,
+        }
+    }
+
+    /// Create a check suite
+    ///
+    /// Returns the existing check suite if the app has already created one for the commit.
+    pub async fn execute(&self) -> Result<CheckSuite, Error> {
+        let url = format!(
+            "/repos/{}/{}/check-suites",
+            self.owner.get(),
+            self.repository.get()
+        );
+
+        let payload = CreateCheckSuitePayload {
+            head_sha: self.head_sha,
+        };
+
+        let check_suite = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to create check suite")?;
+
+        Ok(check_suite)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{GitSha, Login, RepositoryName};
+    use crate::testing::check_suite::mock_create_check_suite;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CreateCheckSuite;
+
+    #[tokio::test]
+    async fn task_returns_check_suite() {
+        let _token_mock = mock_installation_access_tokens();
+        let _check_suite_mock = mock_create_check_suite();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let head_sha = GitSha::new("7fb3254b029acb55db7f8134d1526a080cd63c48");
+
+        let task = CreateCheckSuite::new(&github_client, &login, &repository, &head_sha);
+
+        let check_suite = task.execute().await.unwrap();
+
+        assert_eq!(7663255123, check_suite.id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateCheckSuite>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateCheckSuite>();
+    }
+}
@@ -0,0 +1,331 @@
+use anyhow::Context;
+use chrono::{DateTime, SubsecRound, Utc};
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitCommit, GitSha, Login, RepositoryName};
+use crate::task::CommitSigner;
+
+/// Create a Git commit
+///
+/// Creates a commit in the Git database of a repository. The commit is not added to a branch, which
+/// requires updating the branch's reference to point to the new commit.
+///
+/// # Signing
+///
+/// When the task is given a [`CommitSigner`], it signs the commit so that GitHub can verify it.
+/// The signature covers the commit's author and committer, which is why signed commits require an
+/// author. The committer defaults to the author, and missing dates default to the current time.
+///
+/// https://docs.github.com/en/rest/git/commits#create-a-commit
+#[derive(Copy, Clone, Debug)]
+pub struct CreateCommit<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    commit_args: &'a CreateCommitArgs,
+    signer: Option<&'a dyn CommitSigner>,
+}
+
+/// Input for create commit task
+///
+/// The input for the task that creates a commit represents the different parameters that GitHub's
+/// API accepts.
+///
+/// https://docs.github.com/en/rest/git/commits#create-a-commit
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct CreateCommitArgs {
+    /// The commit message.
+    pub message: String,
+
+    /// The SHA of the tree that the commit points to.
+    pub tree: GitSha,
+
+    /// The SHAs of the commit's parents.
+    pub parents: Vec<GitSha>,
+
+    /// The author of the commit, which defaults to the authenticated user or app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<GitUserArgs>,
+
+    /// The committer of the commit, which defaults to the author.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<GitUserArgs>,
+}
+
+/// Input for the author or committer of a commit
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct GitUserArgs {
+    /// The name of the user.
+    pub name: String,
+
+    /// The email address of the user.
+    pub email: String,
+
+    /// The date of the commit, which defaults to the current time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CreateCommitPayload<'a> {
+    #[serde(flatten)]
+    commit_args: &'a CreateCommitArgs,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+}
+
+impl<'a> CreateCommit<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        commit_args: &'a CreateCommitArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            commit_args,
+            signer: None,
+        }
+    }
+
+    /// Signs the commit with the given signer.
+    pub fn signer(mut self, signer: &'a dyn CommitSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Create a Git commit
+    pub async fn execute(&self) -> Result<GitCommit, Error> {
+        let url = format!(
+            "/repos/{}/{}/git/commits",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let commit = match self.signer {
+            Some(signer) => {
+                let commit_args = self.signed_args()?;
+                let signature = signer.sign(&commit_args.payload())?;
+
+                self.github_client
+                    .post(
+                        &url,
+                        Some(CreateCommitPayload {
+                            commit_args: &commit_args,
+                            signature: Some(signature),
+                        }),
+                    )
+                    .await
+            }
+            None => {
+                self.github_client
+                    .post(
+                        &url,
+                        Some(CreateCommitPayload {
+                            commit_args: self.commit_args,
+                            signature: None,
+                        }),
+                    )
+                    .await
+            }
+        }
+        .context("failed to create commit")?;
+
+        Ok(commit)
+    }
+
+    fn signed_args(&self) -> Result<CreateCommitArgs, Error> {
+        let now = Utc::now().trunc_subsecs(0);
+
+        let mut author = self
+            .commit_args
+            .author
+            .clone()
+            .ok_or_else(|| Error::Configuration("signed commits require an author".into()))?;
+        author.date = Some(author.date.unwrap_or(now).trunc_subsecs(0));
+
+        let mut committer = self
+            .commit_args
+            .committer
+            .clone()
+            .unwrap_or_else(|| author.clone());
+        committer.date = Some(committer.date.unwrap_or(now).trunc_subsecs(0));
+
+        Ok(CreateCommitArgs {
+            author: Some(author),
+            committer: Some(committer),
+            ..self.commit_args.clone()
+        })
+    }
+}
+
+impl CreateCommitArgs {
+    /// Returns the raw Git commit object, which is the payload of the commit's signature.
+    fn payload(&self) -> String {
+        let mut payload = format!("tree {}\n", self.tree);
+
+        for parent in &self.parents {
+            payload.push_str(&format!("parent {}\n", parent));
+        }
+        if let Some(author) = &self.author {
+            payload.push_str(&format!("author {}\n", author.signature()));
+        }
+        if let Some(committer) = &self.committer {
+            payload.push_str(&format!("committer {}\n", committer.signature()));
+        }
+
+        payload.push('\n');
+        payload.push_str(&self.message);
+
+        payload
+    }
+}
+
+impl GitUserArgs {
+    fn signature(&self) -> String {
+        let timestamp = self.date.map(|date| date.timestamp()).unwrap_or_default();
+
+        format!("{} <{}> {} +0000", self.name, self.email, timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mockito::{mock, Matcher};
+
+    use automatons::Error;
+
+    use crate::resource::{GitSha, Login, RepositoryName};
+    use crate::task::SshCommitSigner;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{CreateCommit, CreateCommitArgs, GitUserArgs};
+
+    fn commit_args() -> CreateCommitArgs {
+        CreateCommitArgs {
+            message: "my commit message".into(),
+            tree: GitSha::new("827efc6d56897b048c772eb4087f854f46256132"),
+            parents: vec![GitSha::new("7d1b31e74ee336d15cbd21741bc88a537ed063a0")],
+            author: Some(GitUserArgs {
+                name: "Monalisa Octocat".into(),
+                email: "octocat@github.com".into(),
+                date: Some(Utc.with_ymd_and_hms(2014, 11, 7, 22, 1, 45).unwrap()),
+            }),
+            committer: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_commit() {
+        let _token_mock = mock_installation_access_tokens();
+        let _commit_mock = mock("POST", "/repos/octocat/unsigned/git/commits")
+            .match_body(Matcher::PartialJsonString(
+                r#"{ "message": "my commit message" }"#.into(),
+            ))
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/git_commit.json"
+            ))
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("unsigned");
+        let commit_args = commit_args();
+
+        let task = CreateCommit::new(&github_client, &login, &repository, &commit_args);
+
+        let commit = task.execute().await.unwrap();
+
+        assert_eq!("my commit message", commit.message());
+    }
+
+    #[tokio::test]
+    async fn task_signs_commit() {
+        let _token_mock = mock_installation_access_tokens();
+        let commit_mock = mock("POST", "/repos/octocat/signed/git/commits")
+            .match_body(Matcher::Regex(
+                r#""signature":"-----BEGIN SSH SIGNATURE-----"#.into(),
+            ))
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/git_commit.json"
+            ))
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("signed");
+        let commit_args = commit_args();
+        let signer =
+            SshCommitSigner::from_openssh(include_str!("../../tests/fixtures/ssh-signing-key"))
+                .unwrap();
+
+        let task =
+            CreateCommit::new(&github_client, &login, &repository, &commit_args).signer(&signer);
+
+        task.execute().await.unwrap();
+
+        commit_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_requires_author_for_signed_commit() {
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("signed");
+        let commit_args = CreateCommitArgs {
+            author: None,
+            ..commit_args()
+        };
+        let signer =
+            SshCommitSigner::from_openssh(include_str!("../../tests/fixtures/ssh-signing-key"))
+                .unwrap();
+
+        let task =
+            CreateCommit::new(&github_client, &login, &repository, &commit_args).signer(&signer);
+
+        let error = task.execute().await.unwrap_err();
+
+        assert!(matches!(error, Error::Configuration(_)));
+    }
+
+    #[test]
+    fn payload_matches_git_commit_object() {
+        let commit_args = CreateCommitArgs {
+            committer: commit_args().author,
+            ..commit_args()
+        };
+
+        assert_eq!(
+            "tree 827efc6d56897b048c772eb4087f854f46256132\n\
+             parent 7d1b31e74ee336d15cbd21741bc88a537ed063a0\n\
+             author Monalisa Octocat <octocat@github.com> 1415397705 +0000\n\
+             committer Monalisa Octocat <octocat@github.com> 1415397705 +0000\n\
+             \n\
+             my commit message",
+            commit_args.payload()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateCommit>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateCommit>();
+    }
+}
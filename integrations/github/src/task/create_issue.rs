@@ -0,0 +1,137 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Issue, Login, RepositoryName};
+
+/// Create an issue
+///
+/// Creates an issue in a repository. The GitHub App must have the `issues:write` permission to
+/// create issues. Assignees and labels are silently dropped if the app is not allowed to set them.
+///
+/// https://docs.github.com/en/rest/issues/issues#create-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct CreateIssue<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_args: &'a CreateIssueArgs,
+}
+
+/// Input for create issue task
+///
+/// The input for the task that creates an issue represents the different parameters that GitHub's
+/// API accepts.
+///
+/// https://docs.github.com/en/rest/issues/issues#create-an-issue
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct CreateIssueArgs {
+    /// The title of the issue.
+    pub title: String,
+
+    /// The contents of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The logins of the users to assign to the issue.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<Login>,
+
+    /// The names of the labels to add to the issue.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+}
+
+impl<'a> CreateIssue<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_args: &'a CreateIssueArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_args,
+        }
+    }
+
+    /// Create an issue
+    pub async fn execute(&self) -> Result<Issue, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues",
+            self.owner.get(),
+            self.repository.get()
+        );
+
+        let issue = self
+            .github_client
+            .post(&url, Some(self.issue_args))
+            .await
+            .context("failed to create issue")?;
+
+        Ok(issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_create_issue;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{CreateIssue, CreateIssueArgs};
+
+    fn input() -> CreateIssueArgs {
+        CreateIssueArgs {
+            title: "Found a bug".into(),
+            body: Some("I'm having a problem with this.".into()),
+            assignees: vec![Login::new("octocat")],
+            labels: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_issue() {
+        let _token_mock = mock_installation_access_tokens();
+        let _issue_mock = mock_create_issue();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let input = input();
+
+        let task = CreateIssue::new(&github_client, &login, &repository, &input);
+
+        let issue = task.execute().await.unwrap();
+
+        assert_eq!(1347, issue.number().get());
+    }
+
+    #[test]
+    fn args_skip_empty_fields() {
+        let json = serde_json::to_string(&input()).unwrap();
+
+        assert_eq!(
+            r#"{"title":"Found a bug","body":"I'm having a problem with this.","assignees":["octocat"]}"#,
+            json
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateIssue>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateIssue>();
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueComment, IssueNumber, Login, RepositoryName};
+
+/// Create a comment on an issue
+///
+/// Creates a comment on an issue or pull request. GitHub Apps must have the `issues:write`
+/// permission to comment on an issue, and the `pull_requests:write` permission to comment on a
+/// pull request.
+///
+/// https://docs.github.com/en/rest/issues/comments#create-an-issue-comment
+#[derive(Copy, Clone, Debug)]
+pub struct CreateIssueComment<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+    body: &'a str,
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct CreateIssueCommentPayload<'a> {
+    body: &'a str,
+}
+
+impl<'a> CreateIssueComment<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+        body: &'a str,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+            body,
+        }
+    }
+
+    /// Create a comment on an issue
+    pub async fn execute(&self) -> Result<IssueComment, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/comments",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let comment = self
+            .github_client
+            .post(&url, Some(CreateIssueCommentPayload { body: self.body }))
+            .await
+            .context("failed to create issue comment")?;
+
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_create_issue_comment;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CreateIssueComment;
+
+    #[tokio::test]
+    async fn task_returns_comment() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_create_issue_comment();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = CreateIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1347),
+            "Me too",
+        );
+
+        let comment = task.execute().await.unwrap();
+
+        assert_eq!(1, comment.id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateIssueComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateIssueComment>();
+    }
+}
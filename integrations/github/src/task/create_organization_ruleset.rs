@@ -0,0 +1,103 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, Ruleset};
+use crate::task::RulesetArgs;
+
+/// Create a ruleset for an organization
+///
+/// Creates a ruleset that applies to the repositories of an organization, which are selected with
+/// the `repository_name` condition. The GitHub App must have the
+/// `organization_administration:write` permission to create rulesets.
+///
+/// https://docs.github.com/en/rest/orgs/rules#create-an-organization-repository-ruleset
+#[derive(Copy, Clone, Debug)]
+pub struct CreateOrganizationRuleset<'a> {
+    github_client: &'a GitHubClient,
+    organization: &'a Login,
+    ruleset_args: &'a RulesetArgs,
+}
+
+impl<'a> CreateOrganizationRuleset<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        organization: &'a Login,
+        ruleset_args: &'a RulesetArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            organization,
+            ruleset_args,
+        }
+    }
+
+    /// Create a ruleset for an organization
+    pub async fn execute(&self) -> Result<Ruleset, Error> {
+        let url = format!("/orgs/{}/rulesets", self.organization.get());
+
+        let ruleset = self
+            .github_client
+            .post(&url, Some(self.ruleset_args))
+            .await
+            .context("failed to create organization ruleset")?;
+
+        Ok(ruleset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::resource::{Login, RulesetEnforcement};
+    use crate::task::{RulesetArgs, RulesetRuleArgs};
+    use crate::testing::client::github_client;
+    use crate::testing::ruleset::mock_create_organization_ruleset;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CreateOrganizationRuleset;
+
+    #[tokio::test]
+    async fn task_returns_ruleset() {
+        let _token_mock = mock_installation_access_tokens();
+        let _ruleset_mock = mock_create_organization_ruleset();
+
+        let github_client = github_client();
+        let organization = Login::new("devxbots");
+        let ruleset_args = RulesetArgs {
+            name: "protect-main".into(),
+            target: None,
+            enforcement: RulesetEnforcement::Evaluate,
+            bypass_actors: Vec::new(),
+            conditions: Some(json!({
+                "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": [] },
+                "repository_name": { "include": ["~ALL"], "exclude": [] }
+            })),
+            rules: vec![RulesetRuleArgs {
+                rule_type: "required_signatures".into(),
+                parameters: None,
+            }],
+        };
+
+        let task = CreateOrganizationRuleset::new(&github_client, &organization, &ruleset_args);
+
+        let ruleset = task.execute().await.unwrap();
+
+        assert_eq!("protect-main", ruleset.name().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateOrganizationRuleset>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateOrganizationRuleset>();
+    }
+}
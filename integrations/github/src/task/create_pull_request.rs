@@ -0,0 +1,126 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitRef, Login, PullRequest, RepositoryName};
+
+/// Create a pull request
+///
+/// Opens a pull request to merge a head branch into a base branch of the same repository. The
+/// GitHub App must have the `pull_requests:write` permission to create pull requests.
+///
+/// https://docs.github.com/en/rest/pulls/pulls#create-a-pull-request
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePullRequest<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    pull_request_args: &'a CreatePullRequestArgs,
+}
+
+/// Input for create pull request task
+///
+/// The input for the task that creates a pull request represents the different parameters that
+/// GitHub's API accepts.
+///
+/// https://docs.github.com/en/rest/pulls/pulls#create-a-pull-request
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct CreatePullRequestArgs {
+    /// The title of the pull request.
+    pub title: String,
+
+    /// The name of the branch that contains the changes.
+    pub head: GitRef,
+
+    /// The name of the branch that the changes are merged into.
+    pub base: GitRef,
+
+    /// The contents of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Whether the pull request is opened as a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+}
+
+impl<'a> CreatePullRequest<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        pull_request_args: &'a CreatePullRequestArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            pull_request_args,
+        }
+    }
+
+    /// Create a pull request
+    pub async fn execute(&self) -> Result<PullRequest, Error> {
+        let url = format!(
+            "/repos/{}/{}/pulls",
+            self.owner.get(),
+            self.repository.get()
+        );
+
+        let pull_request = self
+            .github_client
+            .post(&url, Some(self.pull_request_args))
+            .await
+            .context("failed to create pull request")?;
+
+        Ok(pull_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{GitRef, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::pull_request::mock_create_pull_request;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{CreatePullRequest, CreatePullRequestArgs};
+
+    #[tokio::test]
+    async fn task_returns_pull_request() {
+        let _token_mock = mock_installation_access_tokens();
+        let _pull_request_mock = mock_create_pull_request();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let pull_request_args = CreatePullRequestArgs {
+            title: "Fix README".into(),
+            head: GitRef::new("fix-readme"),
+            base: GitRef::new("main"),
+            body: None,
+            draft: None,
+        };
+
+        let task = CreatePullRequest::new(&github_client, &login, &repository, &pull_request_args);
+
+        let pull_request = task.execute().await.unwrap();
+
+        assert_eq!(27, pull_request.number().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreatePullRequest>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreatePullRequest>();
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, Ruleset};
+use crate::task::RulesetArgs;
+
+/// Create a ruleset for a repository
+///
+/// Creates a ruleset that applies to the branches or tags of a repository. The GitHub App must
+/// have the `administration:write` permission to create rulesets.
+///
+/// https://docs.github.com/en/rest/repos/rules#create-a-repository-ruleset
+#[derive(Copy, Clone, Debug)]
+pub struct CreateRepositoryRuleset<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    ruleset_args: &'a RulesetArgs,
+}
+
+impl<'a> CreateRepositoryRuleset<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        ruleset_args: &'a RulesetArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            ruleset_args,
+        }
+    }
+
+    /// Create a ruleset for a repository
+    pub async fn execute(&self) -> Result<Ruleset, Error> {
+        let url = format!(
+            "/repos/{}/{}/rulesets",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let ruleset = self
+            .github_client
+            .post(&url, Some(self.ruleset_args))
+            .await
+            .context("failed to create repository ruleset")?;
+
+        Ok(ruleset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::resource::{Login, RepositoryName, RulesetEnforcement, RulesetTarget};
+    use crate::task::{RulesetArgs, RulesetBypassActorArgs, RulesetRuleArgs};
+    use crate::testing::client::github_client;
+    use crate::testing::ruleset::mock_create_repository_ruleset;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::CreateRepositoryRuleset;
+
+    fn input() -> RulesetArgs {
+        RulesetArgs {
+            name: "protect-main".into(),
+            target: Some(RulesetTarget::Branch),
+            enforcement: RulesetEnforcement::Active,
+            bypass_actors: vec![RulesetBypassActorArgs {
+                actor_id: Some(6404283),
+                actor_type: "Team".into(),
+                bypass_mode: Some("always".into()),
+            }],
+            conditions: Some(json!({
+                "ref_name": { "include": ["refs/heads/main"], "exclude": [] }
+            })),
+            rules: vec![RulesetRuleArgs {
+                rule_type: "deletion".into(),
+                parameters: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_ruleset() {
+        let _token_mock = mock_installation_access_tokens();
+        let _ruleset_mock = mock_create_repository_ruleset();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let ruleset_args = input();
+
+        let task = CreateRepositoryRuleset::new(&github_client, &login, &repository, &ruleset_args);
+
+        let ruleset = task.execute().await.unwrap();
+
+        assert_eq!("protect-main", ruleset.name().get());
+    }
+
+    #[test]
+    fn args_serialize_rule_type() {
+        let json = serde_json::to_value(input()).unwrap();
+
+        assert_eq!("deletion", json["rules"][0]["type"]);
+        assert!(json["rules"][0].get("parameters").is_none());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateRepositoryRuleset>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateRepositoryRuleset>();
+    }
+}
@@ -0,0 +1,140 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Hook, Login, RepositoryName};
+use crate::task::HookConfigArgs;
+
+/// Create a repository webhook
+///
+/// Creates a webhook that delivers the events of a repository to an external URL. The GitHub App
+/// must have the `repository_hooks:write` permission to create webhooks.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook
+#[derive(Copy, Clone, Debug)]
+pub struct CreateRepositoryWebhook<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    webhook_args: &'a CreateRepositoryWebhookArgs,
+}
+
+/// Input for create repository webhook task
+///
+/// The input for the task that creates a repository webhook represents the different parameters
+/// that GitHub's API accepts.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRepositoryWebhookArgs {
+    /// The configuration of the webhook.
+    pub config: HookConfigArgs,
+
+    /// The events that trigger the webhook. `push` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+
+    /// Whether the webhook is active. `true` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+impl<'a> CreateRepositoryWebhook<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        webhook_args: &'a CreateRepositoryWebhookArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            webhook_args,
+        }
+    }
+
+    /// Create a repository webhook
+    pub async fn execute(&self) -> Result<Hook, Error> {
+        let url = format!(
+            "/repos/{}/{}/hooks",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let hook = self
+            .github_client
+            .post(&url, Some(self.webhook_args))
+            .await
+            .context("failed to create repository webhook")?;
+
+        Ok(hook)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::resource::{HookType, Login, RepositoryName};
+    use crate::task::HookConfigArgs;
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_create_repository_webhook;
+    use crate::testing::token::mock_installation_access_tokens;
+    use crate::webhook::GitHubWebhookSecret;
+
+    use super::{CreateRepositoryWebhook, CreateRepositoryWebhookArgs};
+
+    fn input() -> CreateRepositoryWebhookArgs {
+        CreateRepositoryWebhookArgs {
+            config: HookConfigArgs {
+                url: Url::parse("https://example.com/webhook").unwrap(),
+                content_type: Some("json".into()),
+                secret: Some(GitHubWebhookSecret::new("It's a Secret to Everybody")),
+                insecure_ssl: None,
+            },
+            events: Some(vec!["push".into(), "pull_request".into()]),
+            active: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_hook() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_create_repository_webhook();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let webhook_args = input();
+
+        let task = CreateRepositoryWebhook::new(&github_client, &login, &repository, &webhook_args);
+
+        let hook = task.execute().await.unwrap();
+
+        assert!(matches!(hook.hook_type(), HookType::Repository));
+    }
+
+    #[test]
+    fn input_serializes_secret() {
+        let json = serde_json::to_value(input()).unwrap();
+
+        assert_eq!("It's a Secret to Everybody", json["config"]["secret"]);
+        assert!(json.get("active").is_none());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<CreateRepositoryWebhook>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<CreateRepositoryWebhook>();
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter};
+
+use tokio::io::AsyncWrite;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitRef, Login, RepositoryName};
+
+/// Format of a repository archive
+///
+/// GitHub can package the files in a repository either as a gzipped tarball or as a zip archive.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum ArchiveFormat {
+    /// A gzipped tarball
+    #[default]
+    Tarball,
+
+    /// A zip archive
+    Zipball,
+}
+
+/// Download an archive of a repository
+///
+/// Downloads the files in a repository at the given Git reference as an archive, and streams it
+/// into a writer, e.g. a file in a temporary directory. The archive contains the whole tree of the
+/// repository, which makes it useful for automatons that analyze the full source code, for example
+/// linters or license scanners.
+///
+/// https://docs.github.com/en/rest/repos/contents#download-a-repository-archive-tar
+#[derive(Copy, Clone, Debug)]
+pub struct DownloadRepositoryArchive<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    git_ref: &'a GitRef,
+    format: ArchiveFormat,
+}
+
+impl<'a> DownloadRepositoryArchive<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        git_ref: &'a GitRef,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            git_ref,
+            format: ArchiveFormat::default(),
+        }
+    }
+
+    /// Sets the format of the archive.
+    pub fn format(mut self, format: ArchiveFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Download an archive of a repository
+    ///
+    /// Writes the archive into the writer, and returns its size in bytes.
+    pub async fn execute<W>(&self, writer: &mut W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let url = format!(
+            "/repos/{}/{}/{}/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.format,
+            self.git_ref.get()
+        );
+
+        self.github_client.download(&url, writer).await
+    }
+}
+
+impl Display for ArchiveFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            ArchiveFormat::Tarball => "tarball",
+            ArchiveFormat::Zipball => "zipball",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use automatons::Error;
+
+    use crate::resource::{GitRef, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{ArchiveFormat, DownloadRepositoryArchive};
+
+    #[tokio::test]
+    async fn task_follows_redirect() {
+        let _token_mock = mock_installation_access_tokens();
+        let _redirect_mock = mock("GET", "/repos/devxbots/automatons/tarball/main")
+            .with_status(302)
+            .with_header(
+                "location",
+                &format!(
+                    "{}/codeload/devxbots/automatons/tar.gz/main",
+                    mockito::server_url()
+                ),
+            )
+            .create();
+        let _archive_mock = mock("GET", "/codeload/devxbots/automatons/tar.gz/main")
+            .with_status(200)
+            .with_body("archive")
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let git_ref = GitRef::new("main");
+
+        let task = DownloadRepositoryArchive::new(&github_client, &login, &repository, &git_ref);
+
+        let mut archive = Vec::new();
+        let size = task.execute(&mut archive).await.unwrap();
+
+        assert_eq!(7, size);
+        assert_eq!(b"archive", archive.as_slice());
+    }
+
+    #[tokio::test]
+    async fn task_downloads_zipball() {
+        let _token_mock = mock_installation_access_tokens();
+        let _archive_mock = mock("GET", "/repos/devxbots/automatons/zipball/v0.3.0")
+            .with_status(200)
+            .with_body("zip")
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let git_ref = GitRef::new("v0.3.0");
+
+        let task = DownloadRepositoryArchive::new(&github_client, &login, &repository, &git_ref)
+            .format(ArchiveFormat::Zipball);
+
+        let mut archive = Vec::new();
+        task.execute(&mut archive).await.unwrap();
+
+        assert_eq!(b"zip", archive.as_slice());
+    }
+
+    #[tokio::test]
+    async fn task_returns_not_found() {
+        let _token_mock = mock_installation_access_tokens();
+        let _archive_mock = mock("GET", "/repos/devxbots/automatons/tarball/missing")
+            .with_status(404)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let git_ref = GitRef::new("missing");
+
+        let task = DownloadRepositoryArchive::new(&github_client, &login, &repository, &git_ref);
+
+        let error = task.execute(&mut Vec::new()).await.unwrap_err();
+
+        assert!(matches!(error, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<DownloadRepositoryArchive>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DownloadRepositoryArchive>();
+    }
+}
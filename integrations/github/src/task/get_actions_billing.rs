@@ -0,0 +1,110 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{AccountType, ActionsBilling, Login};
+
+/// Get the Actions billing of an account
+///
+/// Gets the minutes that an organization or user has used for GitHub Actions in the current billing
+/// cycle. Getting the billing of an organization requires the `organization_administration:read`
+/// permission, while users can only get their own billing.
+///
+/// https://docs.github.com/en/rest/billing/billing#get-github-actions-billing-for-an-organization
+#[derive(Copy, Clone, Debug)]
+pub struct GetActionsBilling<'a> {
+    github_client: &'a GitHubClient,
+    account: &'a Login,
+    account_type: AccountType,
+}
+
+impl<'a> GetActionsBilling<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        account: &'a Login,
+        account_type: AccountType,
+    ) -> Self {
+        Self {
+            github_client,
+            account,
+            account_type,
+        }
+    }
+
+    /// Get the Actions billing of an account
+    pub async fn execute(&self) -> Result<ActionsBilling, Error> {
+        let url = match self.account_type {
+            AccountType::Organization => {
+                format!("/orgs/{}/settings/billing/actions", self.account.get())
+            }
+            AccountType::Bot | AccountType::User => {
+                format!("/users/{}/settings/billing/actions", self.account.get())
+            }
+        };
+
+        let billing = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query actions billing")?;
+
+        Ok(billing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{AccountType, Login};
+    use crate::testing::billing::{
+        mock_get_organization_actions_billing, mock_get_user_actions_billing,
+    };
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetActionsBilling;
+
+    #[tokio::test]
+    async fn task_returns_organization_billing() {
+        let _token_mock = mock_installation_access_tokens();
+        let billing_mock = mock_get_organization_actions_billing();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+
+        let task = GetActionsBilling::new(&github_client, &login, AccountType::Organization);
+
+        let billing = task.execute().await.unwrap();
+
+        assert_eq!(305, billing.total_minutes_used());
+        billing_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_returns_user_billing() {
+        let _token_mock = mock_installation_access_tokens();
+        let billing_mock = mock_get_user_actions_billing();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+
+        let task = GetActionsBilling::new(&github_client, &login, AccountType::User);
+
+        task.execute().await.unwrap();
+
+        billing_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetActionsBilling>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetActionsBilling>();
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::App;
+
+/// Get the authenticated app
+///
+/// Gets the GitHub App that the client authenticates as, e.g. to find out its id or the login of
+/// its bot user. The app must authenticate as itself to get its information, which is why the task
+/// requires a client for a GitHub App.
+///
+/// https://docs.github.com/en/rest/apps/apps#get-the-authenticated-app
+#[derive(Copy, Clone, Debug)]
+pub struct GetApp<'a> {
+    github_client: &'a GitHubClient,
+}
+
+impl<'a> GetApp<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Get the authenticated app
+    pub async fn execute(&self) -> Result<App, Error> {
+        let app = self
+            .github_client
+            .as_app()?
+            .get("/app")
+            .await
+            .context("failed to query app")?;
+
+        Ok(app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::app::mock_get_app;
+    use crate::testing::client::github_client;
+
+    use super::GetApp;
+
+    #[tokio::test]
+    async fn task_returns_app() {
+        let _app_mock = mock_get_app();
+
+        let github_client = github_client();
+
+        let task = GetApp::new(&github_client);
+
+        let app = task.execute().await.unwrap();
+
+        assert_eq!(202198, app.id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetApp>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetApp>();
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CheckRun, CheckRunId, Login, RepositoryName};
+
+/// Get a check run
+///
+/// Gets a single check run using its id. The GitHub App must have the `checks:read` permission on
+/// a private repository to get check runs.
+///
+/// https://docs.github.com/en/rest/checks/runs#get-a-check-run
+#[derive(Copy, Clone, Debug)]
+pub struct GetCheckRun<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    check_run_id: CheckRunId,
+}
+
+impl<'a> GetCheckRun<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        check_run_id: CheckRunId,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            check_run_id,
+        }
+    }
+
+    /// Get a check run
+    pub async fn execute(&self) -> Result<CheckRun, Error> {
+        let url = format!(
+            "/repos/{}/{}/check-runs/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.check_run_id
+        );
+
+        let check_run = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query check run")?;
+
+        Ok(check_run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{CheckRunId, CheckRunStatus, Login, RepositoryName};
+    use crate::testing::check_run::mock_get_check_run;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetCheckRun;
+
+    #[tokio::test]
+    async fn task_returns_check_run() {
+        let _token_mock = mock_installation_access_tokens();
+        let _check_run_mock = mock_get_check_run();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetCheckRun::new(
+            &github_client,
+            &login,
+            &repository,
+            CheckRunId::new(7669942377),
+        );
+
+        let check_run = task.execute().await.unwrap();
+
+        assert_eq!(CheckRunStatus::Completed, check_run.status());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetCheckRun>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetCheckRun>();
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CodeFrequency, Login, RepositoryName};
+use crate::task::StatisticsRetry;
+
+/// Get the code frequency of a repository
+///
+/// Gets the number of additions and deletions per week. GitHub does not compute the code frequency
+/// for repositories with 10,000 or more commits, and returns no statistics for empty repositories.
+///
+/// GitHub computes the statistics in the background, and the task retries the request according to
+/// its [`StatisticsRetry`] policy until they are ready.
+///
+/// https://docs.github.com/en/rest/metrics/statistics#get-the-weekly-commit-activity
+#[derive(Copy, Clone, Debug)]
+pub struct GetCodeFrequency<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    retry: StatisticsRetry,
+}
+
+impl<'a> GetCodeFrequency<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            retry: StatisticsRetry::default(),
+        }
+    }
+
+    /// Sets how often and how long the task waits for GitHub to compute the statistics.
+    pub fn retry(mut self, retry: StatisticsRetry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Get the code frequency of a repository
+    pub async fn execute(&self) -> Result<Vec<CodeFrequency>, Error> {
+        let url = format!(
+            "/repos/{}/{}/stats/code_frequency",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let statistics: Option<Vec<CodeFrequency>> = self
+            .github_client
+            .get_computed(&url, self.retry.attempts, self.retry.delay)
+            .await
+            .context("failed to query code frequency")?;
+
+        Ok(statistics.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::statistics::mock_get_code_frequency;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetCodeFrequency;
+
+    #[tokio::test]
+    async fn task_returns_code_frequency() {
+        let _token_mock = mock_installation_access_tokens();
+        let _statistics_mock = mock_get_code_frequency();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetCodeFrequency::new(&github_client, &login, &repository);
+
+        let statistics = task.execute().await.unwrap();
+
+        assert_eq!(1124, statistics[0].additions());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetCodeFrequency>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetCodeFrequency>();
+    }
+}
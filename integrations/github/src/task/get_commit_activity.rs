@@ -0,0 +1,101 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CommitActivity, Login, RepositoryName};
+use crate::task::StatisticsRetry;
+
+/// Get the commit activity of a repository
+///
+/// Gets the number of commits per day for each week of the last year. GitHub returns no statistics
+/// for empty repositories.
+///
+/// GitHub computes the statistics in the background, and the task retries the request according to
+/// its [`StatisticsRetry`] policy until they are ready.
+///
+/// https://docs.github.com/en/rest/metrics/statistics#get-the-last-year-of-commit-activity
+#[derive(Copy, Clone, Debug)]
+pub struct GetCommitActivity<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    retry: StatisticsRetry,
+}
+
+impl<'a> GetCommitActivity<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            retry: StatisticsRetry::default(),
+        }
+    }
+
+    /// Sets how often and how long the task waits for GitHub to compute the statistics.
+    pub fn retry(mut self, retry: StatisticsRetry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Get the commit activity of a repository
+    pub async fn execute(&self) -> Result<Vec<CommitActivity>, Error> {
+        let url = format!(
+            "/repos/{}/{}/stats/commit_activity",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let statistics: Option<Vec<CommitActivity>> = self
+            .github_client
+            .get_computed(&url, self.retry.attempts, self.retry.delay)
+            .await
+            .context("failed to query commit activity")?;
+
+        Ok(statistics.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::statistics::mock_get_commit_activity;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetCommitActivity;
+
+    #[tokio::test]
+    async fn task_returns_commit_activity() {
+        let _token_mock = mock_installation_access_tokens();
+        let _statistics_mock = mock_get_commit_activity();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetCommitActivity::new(&github_client, &login, &repository);
+
+        let statistics = task.execute().await.unwrap();
+
+        assert_eq!(89, statistics[0].total());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetCommitActivity>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetCommitActivity>();
+    }
+}
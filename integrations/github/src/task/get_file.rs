@@ -2,12 +2,13 @@ use anyhow::Context;
 use base64::decode;
 use serde::Deserialize;
 use serde_json::Value;
-use url::Url;
+use url::{form_urlencoded, Url};
 
 use automatons::Error;
 
 use crate::client::GitHubClient;
-use crate::resource::{File, Login, RepositoryName};
+use crate::resource::{File, GitRef, Login, RepositoryName};
+use crate::task::BlobCache;
 
 /// Gets a file in a repository
 ///
@@ -17,6 +18,13 @@ use crate::resource::{File, Login, RepositoryName};
 ///
 /// The task only supports files that are smaller than 1MB.
 ///
+/// # Caching
+///
+/// When the task is given a [`BlobCache`], it sends a conditional request with the entity tag of
+/// the last response for the file. If the file has not changed, GitHub responds with `304 Not
+/// Modified`, which does not count against the rate limit, and the content is read from the cache.
+/// Otherwise, the file is downloaded and its content is stored in the cache.
+///
 /// https://docs.github.com/en/rest/repos/contents#get-repository-content
 #[derive(Copy, Clone, Debug)]
 pub struct GetFile<'a> {
@@ -24,6 +32,8 @@ pub struct GetFile<'a> {
     owner: &'a Login,
     repository: &'a RepositoryName,
     path: &'a str,
+    git_ref: Option<&'a GitRef>,
+    cache: Option<&'a dyn BlobCache>,
 }
 
 impl<'a> GetFile<'a> {
@@ -39,40 +49,101 @@ impl<'a> GetFile<'a> {
             owner,
             repository,
             path,
+            git_ref: None,
+            cache: None,
         }
     }
 
+    /// Reads the file at the given branch, tag, or commit instead of the default branch.
+    pub fn git_ref(mut self, git_ref: &'a GitRef) -> Self {
+        self.git_ref = Some(git_ref);
+        self
+    }
+
+    /// Reads and stores the file's content in the given cache.
+    pub fn cache(mut self, cache: &'a dyn BlobCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Gets a file in a repository
     ///
     /// Gets the contents of a file in a repository.
     pub async fn execute(&self) -> Result<File, Error> {
-        let url = format!(
+        let mut endpoint = format!(
             "/repos/{}/{}/contents/{}",
             self.owner.get(),
             self.repository.get(),
             self.path
         );
+        if let Some(git_ref) = self.git_ref {
+            let git_ref: String =
+                form_urlencoded::byte_serialize(git_ref.get().as_bytes()).collect();
+            endpoint.push_str(&format!("?ref={}", git_ref));
+        }
 
-        let payload = self.github_client.get(&url).await?;
+        let cache = match self.cache {
+            Some(cache) => cache,
+            None => {
+                let payload = self.github_client.get(&endpoint).await?;
+                return parse_response(endpoint, payload);
+            }
+        };
 
-        let body = match payload {
-            GetFileResponse::Success(body) => body,
-            GetFileResponse::Error(_) => return Err(Error::NotFound(url)),
+        // Files on different GitHub hosts can share an endpoint, so the entity tag is stored under
+        // the full URL of the file.
+        let url = format!("{}{}", self.github_client.github_host().get(), endpoint);
+
+        let cached = cache.get_entity_tag(&url).and_then(|(entity_tag, file)| {
+            let content = cache.get(file.sha())?;
+            Some((entity_tag, file.with_content(content)))
+        });
+        let entity_tag = cached.as_ref().map(|(entity_tag, _)| entity_tag.as_str());
+
+        let response = self
+            .github_client
+            .get_conditional(&endpoint, entity_tag)
+            .await?;
+
+        let (payload, entity_tag) = match (response, cached) {
+            (Some(response), _) => response,
+            (None, Some((_, file))) => return Ok(file),
+            (None, None) => {
+                return Err(Error::Serialization(format!(
+                    "GitHub responded to unconditional request to {} with 304 Not Modified",
+                    endpoint
+                )))
+            }
         };
 
-        if body.is_array() {
-            Err(Error::Serialization(
-                "failed to handle unsupported directory payload".into(),
-            ))
-        } else {
-            let payload: GetFilePayload = serde_json::from_value(body).map_err(|_| {
-                Error::Serialization(
-                    "failed to deserialize payload from GitHub's contents API".into(),
-                )
-            })?;
-
-            File::try_from(payload)
+        let file = parse_response(endpoint.clone(), payload)?;
+        cache.insert(file.sha().clone(), file.content().to_vec());
+
+        if let Some(entity_tag) = entity_tag {
+            let metadata = file.clone().with_content(Vec::new());
+            cache.insert_entity_tag(&url, entity_tag, metadata);
         }
+
+        Ok(file)
+    }
+}
+
+fn parse_response(endpoint: String, payload: GetFileResponse) -> Result<File, Error> {
+    let body = match payload {
+        GetFileResponse::Success(body) => body,
+        GetFileResponse::Error(_) => return Err(Error::NotFound(endpoint)),
+    };
+
+    if body.is_array() {
+        Err(Error::Serialization(
+            "failed to handle unsupported directory payload".into(),
+        ))
+    } else {
+        let payload: GetFilePayload = serde_json::from_value(body).map_err(|_| {
+            Error::Serialization("failed to deserialize payload from GitHub's contents API".into())
+        })?;
+
+        File::try_from(payload)
     }
 }
 
@@ -157,10 +228,12 @@ mod tests {
 
     use automatons::Error;
 
-    use crate::resource::{Login, RepositoryName};
+    use crate::resource::{GitRef, GitSha, Login, RepositoryName};
+    use crate::task::{BlobCache, MemoryBlobCache};
     use crate::testing::client::github_client;
     use crate::testing::contents::{
-        mock_get_contents_directory, mock_get_contents_file, mock_get_contents_submodule,
+        mock_get_contents_directory, mock_get_contents_file, mock_get_contents_file_at,
+        mock_get_contents_file_not_modified, mock_get_contents_submodule,
         mock_get_contents_symlink,
     };
     use crate::testing::token::mock_installation_access_tokens;
@@ -184,6 +257,109 @@ mod tests {
         assert_eq!("README.md", file.name());
     }
 
+    #[tokio::test]
+    async fn get_file_with_git_ref() {
+        let _token_mock = mock_installation_access_tokens();
+        let content_mock = mock_get_contents_file_at("main");
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let git_ref = GitRef::new("main");
+
+        let task = GetFile::new(&github_client, &login, &repository, "README.md").git_ref(&git_ref);
+
+        task.execute().await.unwrap();
+
+        content_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn get_file_with_encoded_git_ref() {
+        let _token_mock = mock_installation_access_tokens();
+        let content_mock = mock_get_contents_file_at("release/1.0+hotfix&x=y");
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let git_ref = GitRef::new("release/1.0+hotfix&x=y");
+
+        let task = GetFile::new(&github_client, &login, &repository, "README.md").git_ref(&git_ref);
+
+        task.execute().await.unwrap();
+
+        content_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn get_file_with_cache_hit() {
+        let _token_mock = mock_installation_access_tokens();
+        let not_modified_mock = mock_get_contents_file_not_modified();
+        let content_mock = mock_get_contents_file();
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let path = "README.md";
+
+        let cache = MemoryBlobCache::default();
+        let task = GetFile::new(&github_client, &login, &repository, path).cache(&cache);
+
+        let downloaded = task.execute().await.unwrap();
+        let cached = task.execute().await.unwrap();
+
+        assert_eq!(downloaded, cached);
+        content_mock.assert();
+        not_modified_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn get_file_with_cache_miss() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_get_contents_file();
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let path = "README.md";
+
+        let cache = MemoryBlobCache::default();
+
+        let task = GetFile::new(&github_client, &login, &repository, path).cache(&cache);
+
+        let file = task.execute().await.unwrap();
+
+        assert_eq!(
+            Some(file.content().to_vec()),
+            cache.get(&GitSha::new("3d21ec53a331a6f037a91c368710b99387d012c1"))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_file_with_cache_stores_entity_tag_by_url() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_get_contents_file();
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let path = "README.md";
+
+        let cache = MemoryBlobCache::default();
+
+        let task = GetFile::new(&github_client, &login, &repository, path).cache(&cache);
+        task.execute().await.unwrap();
+
+        let url = format!(
+            "{}/repos/octokit/octokit.rb/contents/README.md",
+            mockito::server_url()
+        );
+        assert!(cache.get_entity_tag(&url).is_some());
+        assert!(cache
+            .get_entity_tag("/repos/octokit/octokit.rb/contents/README.md")
+            .is_none());
+    }
+
     #[tokio::test]
     async fn get_file_with_directory() {
         let _token_mock = mock_installation_access_tokens();
@@ -0,0 +1,79 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, Organization};
+
+/// Get an organization
+///
+/// Gets an organization. Events reference organizations as an
+/// [`Account`](crate::resource::Account), and the task can be used to get the information that the
+/// account does not contain, e.g. the organization's description.
+///
+/// https://docs.github.com/en/rest/orgs/orgs#get-an-organization
+#[derive(Copy, Clone, Debug)]
+pub struct GetOrganization<'a> {
+    github_client: &'a GitHubClient,
+    login: &'a Login,
+}
+
+impl<'a> GetOrganization<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient, login: &'a Login) -> Self {
+        Self {
+            github_client,
+            login,
+        }
+    }
+
+    /// Get an organization
+    pub async fn execute(&self) -> Result<Organization, Error> {
+        let url = format!("/orgs/{}", self.login.get());
+
+        let organization = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query organization")?;
+
+        Ok(organization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::Login;
+    use crate::testing::account::mock_get_organization;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetOrganization;
+
+    #[tokio::test]
+    async fn task_returns_organization() {
+        let _token_mock = mock_installation_access_tokens();
+        let _organization_mock = mock_get_organization();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+
+        let task = GetOrganization::new(&github_client, &login);
+
+        let organization = task.execute().await.unwrap();
+
+        assert_eq!(104442885, organization.id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetOrganization>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetOrganization>();
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, Participation, RepositoryName};
+use crate::task::StatisticsRetry;
+
+/// Get the participation of a repository
+///
+/// Gets the number of commits per week in the last year, both by all contributors and by the owner
+/// of the repository.
+///
+/// GitHub computes the statistics in the background, and the task retries the request according to
+/// its [`StatisticsRetry`] policy until they are ready.
+///
+/// https://docs.github.com/en/rest/metrics/statistics#get-the-weekly-commit-count
+#[derive(Copy, Clone, Debug)]
+pub struct GetParticipation<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    retry: StatisticsRetry,
+}
+
+impl<'a> GetParticipation<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            retry: StatisticsRetry::default(),
+        }
+    }
+
+    /// Sets how often and how long the task waits for GitHub to compute the statistics.
+    pub fn retry(mut self, retry: StatisticsRetry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Get the participation of a repository
+    pub async fn execute(&self) -> Result<Participation, Error> {
+        let url = format!(
+            "/repos/{}/{}/stats/participation",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let statistics = self
+            .github_client
+            .get_computed(&url, self.retry.attempts, self.retry.delay)
+            .await
+            .context("failed to query participation")?;
+
+        Ok(statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::statistics::mock_get_participation;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetParticipation;
+
+    #[tokio::test]
+    async fn task_returns_participation() {
+        let _token_mock = mock_installation_access_tokens();
+        let _statistics_mock = mock_get_participation();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetParticipation::new(&github_client, &login, &repository);
+
+        let statistics = task.execute().await.unwrap();
+
+        assert_eq!(52, statistics.all().len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetParticipation>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetParticipation>();
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::RateLimitOverview;
+
+/// Get the rate limit status
+///
+/// Gets the rate limit status of the authenticated app or user for all of GitHub's APIs. Requests
+/// to this endpoint do not count against the rate limit.
+///
+/// https://docs.github.com/en/rest/rate-limit#get-rate-limit-status-for-the-authenticated-user
+#[derive(Copy, Clone, Debug)]
+pub struct GetRateLimit<'a> {
+    github_client: &'a GitHubClient,
+}
+
+impl<'a> GetRateLimit<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Get the rate limit status
+    pub async fn execute(&self) -> Result<RateLimitOverview, Error> {
+        let rate_limit = self
+            .github_client
+            .get("/rate_limit")
+            .await
+            .context("failed to query rate limit")?;
+
+        Ok(rate_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::billing::mock_get_rate_limit;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetRateLimit;
+
+    #[tokio::test]
+    async fn task_returns_rate_limit() {
+        let _token_mock = mock_installation_access_tokens();
+        let _rate_limit_mock = mock_get_rate_limit();
+
+        let github_client = github_client();
+
+        let task = GetRateLimit::new(&github_client);
+
+        let rate_limit = task.execute().await.unwrap();
+
+        assert_eq!(5000, rate_limit.rate().limit());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetRateLimit>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetRateLimit>();
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CloneTraffic, Login, RepositoryName};
+use crate::task::TrafficInterval;
+
+/// Get the clones of a repository
+///
+/// Gets the total number of clones and unique cloners in the last 14 days, and breaks them down per
+/// day or week. The GitHub App must have the `administration:read` permission to get traffic data.
+///
+/// https://docs.github.com/en/rest/metrics/traffic#get-repository-clones
+#[derive(Copy, Clone, Debug)]
+pub struct GetRepositoryClones<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    interval: TrafficInterval,
+}
+
+impl<'a> GetRepositoryClones<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            interval: TrafficInterval::default(),
+        }
+    }
+
+    /// Sets the interval by which the clones are grouped.
+    pub fn interval(mut self, interval: TrafficInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Get the clones of a repository
+    pub async fn execute(&self) -> Result<CloneTraffic, Error> {
+        let url = format!(
+            "/repos/{}/{}/traffic/clones?per={}",
+            self.owner.get(),
+            self.repository.get(),
+            self.interval
+        );
+
+        let traffic = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query repository clones")?;
+
+        Ok(traffic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::task::TrafficInterval;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+    use crate::testing::traffic::mock_get_repository_clones;
+
+    use super::GetRepositoryClones;
+
+    #[tokio::test]
+    async fn task_returns_clones() {
+        let _token_mock = mock_installation_access_tokens();
+        let _traffic_mock = mock_get_repository_clones();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetRepositoryClones::new(&github_client, &login, &repository)
+            .interval(TrafficInterval::Day);
+
+        let traffic = task.execute().await.unwrap();
+
+        assert_eq!(173, traffic.count());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetRepositoryClones>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetRepositoryClones>();
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{ActionsPublicKey, Login, RepositoryName};
+
+/// Get the public key of a repository
+///
+/// Gets the public key that is used to encrypt the Actions secrets of a repository. Secrets must be
+/// encrypted before they can be created or updated. The GitHub App must have the `secrets:read`
+/// permission to get the public key.
+///
+/// https://docs.github.com/en/rest/actions/secrets#get-a-repository-public-key
+#[derive(Copy, Clone, Debug)]
+pub struct GetRepositoryPublicKey<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> GetRepositoryPublicKey<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// Get the public key of a repository
+    pub async fn execute(&self) -> Result<ActionsPublicKey, Error> {
+        let url = format!(
+            "/repos/{}/{}/actions/secrets/public-key",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let public_key = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query repository public key")?;
+
+        Ok(public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::actions::mock_get_repository_public_key;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetRepositoryPublicKey;
+
+    #[tokio::test]
+    async fn task_returns_public_key() {
+        let _token_mock = mock_installation_access_tokens();
+        let _public_key_mock = mock_get_repository_public_key();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = GetRepositoryPublicKey::new(&github_client, &login, &repository);
+
+        let public_key = task.execute().await.unwrap();
+
+        assert_eq!("012345678912345678", public_key.key_id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetRepositoryPublicKey>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetRepositoryPublicKey>();
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, Ruleset, RulesetId};
+
+/// Get a ruleset for a repository
+///
+/// Gets a ruleset with its rules, bypass actors, and conditions. The ruleset can belong to the
+/// repository or to its organization.
+///
+/// https://docs.github.com/en/rest/repos/rules#get-a-repository-ruleset
+#[derive(Copy, Clone, Debug)]
+pub struct GetRepositoryRuleset<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    ruleset_id: RulesetId,
+}
+
+impl<'a> GetRepositoryRuleset<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        ruleset_id: RulesetId,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            ruleset_id,
+        }
+    }
+
+    /// Get a ruleset for a repository
+    pub async fn execute(&self) -> Result<Ruleset, Error> {
+        let url = format!(
+            "/repos/{}/{}/rulesets/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.ruleset_id
+        );
+
+        let ruleset = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query repository ruleset")?;
+
+        Ok(ruleset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName, RulesetId};
+    use crate::testing::client::github_client;
+    use crate::testing::ruleset::mock_get_repository_ruleset;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetRepositoryRuleset;
+
+    #[tokio::test]
+    async fn task_returns_ruleset() {
+        let _token_mock = mock_installation_access_tokens();
+        let _ruleset_mock = mock_get_repository_ruleset();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task =
+            GetRepositoryRuleset::new(&github_client, &login, &repository, RulesetId::new(42));
+
+        let ruleset = task.execute().await.unwrap();
+
+        assert_eq!(2, ruleset.rules().len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetRepositoryRuleset>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetRepositoryRuleset>();
+    }
+}
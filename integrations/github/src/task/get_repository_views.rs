@@ -0,0 +1,101 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, ViewTraffic};
+use crate::task::TrafficInterval;
+
+/// Get the views of a repository
+///
+/// Gets the total number of views and unique visitors in the last 14 days, and breaks them down per
+/// day or week. The GitHub App must have the `administration:read` permission to get traffic data.
+///
+/// https://docs.github.com/en/rest/metrics/traffic#get-page-views
+#[derive(Copy, Clone, Debug)]
+pub struct GetRepositoryViews<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    interval: TrafficInterval,
+}
+
+impl<'a> GetRepositoryViews<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            interval: TrafficInterval::default(),
+        }
+    }
+
+    /// Sets the interval by which the views are grouped.
+    pub fn interval(mut self, interval: TrafficInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Get the views of a repository
+    pub async fn execute(&self) -> Result<ViewTraffic, Error> {
+        let url = format!(
+            "/repos/{}/{}/traffic/views?per={}",
+            self.owner.get(),
+            self.repository.get(),
+            self.interval
+        );
+
+        let traffic = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query repository views")?;
+
+        Ok(traffic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::task::TrafficInterval;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+    use crate::testing::traffic::mock_get_repository_views;
+
+    use super::GetRepositoryViews;
+
+    #[tokio::test]
+    async fn task_returns_views() {
+        let _token_mock = mock_installation_access_tokens();
+        let _traffic_mock = mock_get_repository_views();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = GetRepositoryViews::new(&github_client, &login, &repository)
+            .interval(TrafficInterval::Week);
+
+        let traffic = task.execute().await.unwrap();
+
+        assert_eq!(14850, traffic.count());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetRepositoryViews>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetRepositoryViews>();
+    }
+}
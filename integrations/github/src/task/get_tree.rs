@@ -0,0 +1,122 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitSha, Login, RepositoryName, Tree};
+
+/// Get a Git tree
+///
+/// Gets a single tree using its SHA. In recursive mode, the tree contains all the files and
+/// directories below it, which makes it possible to enumerate the files in a repository without
+/// downloading an archive.
+///
+/// # Truncation
+///
+/// GitHub limits the number of entries in a recursive tree to 100,000 entries with a maximum size
+/// of 7 MB. Check [`Tree::truncated`] to find out if entries are missing, and fetch the subtrees
+/// non-recursively if they are.
+///
+/// https://docs.github.com/en/rest/git/trees#get-a-tree
+#[derive(Copy, Clone, Debug)]
+pub struct GetTree<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    tree_sha: &'a GitSha,
+    recursive: bool,
+}
+
+impl<'a> GetTree<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        tree_sha: &'a GitSha,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            tree_sha,
+            recursive: false,
+        }
+    }
+
+    /// Returns the entries of all subtrees as well.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Get a Git tree
+    pub async fn execute(&self) -> Result<Tree, Error> {
+        let mut url = format!(
+            "/repos/{}/{}/git/trees/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.tree_sha
+        );
+
+        if self.recursive {
+            url.push_str("?recursive=1");
+        }
+
+        let tree = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query tree")?;
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{GitSha, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetTree;
+
+    #[tokio::test]
+    async fn task_returns_recursive_tree() {
+        let _token_mock = mock_installation_access_tokens();
+        let tree_mock = mock(
+            "GET",
+            "/repos/octokit/octokit.rb/git/trees/9fb037999f264ba9a7fc6274d15fa3ae2ab98312",
+        )
+        .match_query(Matcher::UrlEncoded("recursive".into(), "1".into()))
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/tree.json"))
+        .create();
+
+        let github_client = github_client();
+        let login = Login::new("octokit");
+        let repository = RepositoryName::new("octokit.rb");
+        let tree_sha = GitSha::new("9fb037999f264ba9a7fc6274d15fa3ae2ab98312");
+
+        let task = GetTree::new(&github_client, &login, &repository, &tree_sha).recursive(true);
+
+        let tree = task.execute().await.unwrap();
+
+        tree_mock.assert();
+        assert_eq!("lib/octokit.rb", tree.entries()[2].path());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetTree>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetTree>();
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, User};
+
+/// Get a user
+///
+/// Gets the public profile of a user. Events reference users as an
+/// [`Account`](crate::resource::Account), and the task can be used to get the information that the
+/// account does not contain, e.g. the user's name.
+///
+/// https://docs.github.com/en/rest/users/users#get-a-user
+#[derive(Copy, Clone, Debug)]
+pub struct GetUser<'a> {
+    github_client: &'a GitHubClient,
+    login: &'a Login,
+}
+
+impl<'a> GetUser<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient, login: &'a Login) -> Self {
+        Self {
+            github_client,
+            login,
+        }
+    }
+
+    /// Get a user
+    pub async fn execute(&self) -> Result<User, Error> {
+        let url = format!("/users/{}", self.login.get());
+
+        let user = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query user")?;
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::Login;
+    use crate::testing::account::mock_get_user;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::GetUser;
+
+    #[tokio::test]
+    async fn task_returns_user() {
+        let _token_mock = mock_installation_access_tokens();
+        let _user_mock = mock_get_user();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+
+        let task = GetUser::new(&github_client, &login);
+
+        let user = task.execute().await.unwrap();
+
+        assert_eq!(&Some("monalisa octocat".into()), user.name());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GetUser>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GetUser>();
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Contributor, Login, RepositoryName};
+
+/// List the contributors of a repository
+///
+/// Lists the accounts that have contributed to a repository, sorted by their number of commits in
+/// descending order. GitHub only returns the top 500 contributors with their accounts.
+///
+/// https://docs.github.com/en/rest/repos/repos#list-repository-contributors
+#[derive(Copy, Clone, Debug)]
+pub struct ListContributors<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListContributors<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the contributors of a repository
+    pub async fn execute(&self) -> Result<Vec<Contributor>, Error> {
+        let url = format!(
+            "/repos/{}/{}/contributors",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let contributors = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query contributors")?;
+
+        Ok(contributors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::statistics::mock_list_contributors;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListContributors;
+
+    #[tokio::test]
+    async fn task_returns_contributors() {
+        let _token_mock = mock_installation_access_tokens();
+        let _contributors_mock = mock_list_contributors();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = ListContributors::new(&github_client, &login, &repository);
+
+        let contributors = task.execute().await.unwrap();
+
+        assert_eq!(32, contributors[0].contributions());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListContributors>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListContributors>();
+    }
+}
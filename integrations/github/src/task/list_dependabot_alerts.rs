@@ -0,0 +1,133 @@
+use anyhow::Context;
+use reqwest::Method;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{
+    DependabotAlert, DependabotAlertState, Login, PackageEcosystem, PackageName, RepositoryName,
+    SecurityAdvisorySeverity,
+};
+
+/// List the Dependabot alerts for a repository
+///
+/// Lists the Dependabot alerts of a repository, optionally filtered by state, severity, or
+/// package. GitHub Apps must have the `dependabot_alerts:read` permission to list alerts.
+///
+/// https://docs.github.com/en/rest/dependabot/alerts#list-dependabot-alerts-for-a-repository
+#[derive(Copy, Clone, Debug)]
+pub struct ListDependabotAlerts<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    list_args: &'a ListDependabotAlertsArgs,
+}
+
+/// Input for list Dependabot alerts task
+///
+/// The input for the task that lists Dependabot alerts represents the filters that GitHub's API
+/// accepts. All filters are optional.
+///
+/// https://docs.github.com/en/rest/dependabot/alerts#list-dependabot-alerts-for-a-repository
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+pub struct ListDependabotAlertsArgs {
+    /// Only list alerts with this state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<DependabotAlertState>,
+
+    /// Only list alerts with this severity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<SecurityAdvisorySeverity>,
+
+    /// Only list alerts for packages in this ecosystem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ecosystem: Option<PackageEcosystem>,
+
+    /// Only list alerts for this package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageName>,
+}
+
+impl<'a> ListDependabotAlerts<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        list_args: &'a ListDependabotAlertsArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            list_args,
+        }
+    }
+
+    /// List the Dependabot alerts for a repository
+    pub async fn execute(&self) -> Result<Vec<DependabotAlert>, Error> {
+        let query = serde_urlencoded::to_string(self.list_args)
+            .map_err(|error| Error::Serialization(error.to_string()))?;
+
+        let mut url = format!(
+            "/repos/{}/{}/dependabot/alerts",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        if !query.is_empty() {
+            url = format!("{}?{}", url, query);
+        }
+
+        let alerts = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query dependabot alerts")?;
+
+        Ok(alerts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{DependabotAlertState, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::dependabot_alert::mock_list_dependabot_alerts;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{ListDependabotAlerts, ListDependabotAlertsArgs};
+
+    #[tokio::test]
+    async fn task_returns_dependabot_alerts() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_list_dependabot_alerts();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let args = ListDependabotAlertsArgs {
+            state: Some(DependabotAlertState::Dismissed),
+            ..Default::default()
+        };
+
+        let task = ListDependabotAlerts::new(&github_client, &login, &repository, &args);
+
+        let alerts = task.execute().await.unwrap();
+
+        assert_eq!(1, alerts.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListDependabotAlerts>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListDependabotAlerts>();
+    }
+}
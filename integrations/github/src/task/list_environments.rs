@@ -0,0 +1,90 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Environment, Login, RepositoryName};
+
+/// List the environments of a repository
+///
+/// Lists the deployment environments of a repository. Anyone with read access to the repository can
+/// list its environments.
+///
+/// https://docs.github.com/en/rest/deployments/environments#list-environments
+#[derive(Copy, Clone, Debug)]
+pub struct ListEnvironments<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListEnvironments<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the environments of a repository
+    pub async fn execute(&self) -> Result<Vec<Environment>, Error> {
+        let url = format!(
+            "/repos/{}/{}/environments",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let environments = self
+            .github_client
+            .paginate(Method::GET, &url, "environments")
+            .await
+            .context("failed to query environments")?;
+
+        Ok(environments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::actions::mock_list_environments;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListEnvironments;
+
+    #[tokio::test]
+    async fn task_returns_environments() {
+        let _token_mock = mock_installation_access_tokens();
+        let _environments_mock = mock_list_environments();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = ListEnvironments::new(&github_client, &login, &repository);
+
+        let environments = task.execute().await.unwrap();
+
+        assert_eq!(1, environments.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListEnvironments>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListEnvironments>();
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::Repository;
+
+/// List the repositories of an installation
+///
+/// Lists the repositories that the installation of the GitHub App can access. Organizations can
+/// grant an installation access to all of their repositories, or only to a selection of them.
+///
+/// https://docs.github.com/en/rest/apps/installations#list-repositories-accessible-to-the-app-installation
+#[derive(Copy, Clone, Debug)]
+pub struct ListInstallationRepositories<'a> {
+    github_client: &'a GitHubClient,
+}
+
+impl<'a> ListInstallationRepositories<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// List the repositories of an installation
+    pub async fn execute(&self) -> Result<Vec<Repository>, Error> {
+        let repositories = self
+            .github_client
+            .paginate(Method::GET, "/installation/repositories", "repositories")
+            .await
+            .context("failed to query installation repositories")?;
+
+        Ok(repositories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::client::github_client;
+    use crate::testing::installation::mock_list_installation_repositories;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListInstallationRepositories;
+
+    #[tokio::test]
+    async fn task_returns_repositories() {
+        let _token_mock = mock_installation_access_tokens();
+        let _repositories_mock = mock_list_installation_repositories();
+
+        let github_client = github_client();
+        let task = ListInstallationRepositories::new(&github_client);
+
+        let repositories = task.execute().await.unwrap();
+
+        assert_eq!(1, repositories.len());
+        assert_eq!("devxbots/automatons", repositories[0].full_name().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListInstallationRepositories>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListInstallationRepositories>();
+    }
+}
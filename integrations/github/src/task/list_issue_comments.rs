@@ -0,0 +1,96 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueComment, IssueNumber, Login, RepositoryName};
+
+/// List the comments on an issue
+///
+/// Lists all comments on an issue or pull request, in ascending order by their id. GitHub Apps
+/// must have the `issues:read` permission to list the comments on an issue, and the
+/// `pull_requests:read` permission to list the comments on a pull request.
+///
+/// https://docs.github.com/en/rest/issues/comments#list-issue-comments
+#[derive(Copy, Clone, Debug)]
+pub struct ListIssueComments<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+}
+
+impl<'a> ListIssueComments<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+        }
+    }
+
+    /// List the comments on an issue
+    pub async fn execute(&self) -> Result<Vec<IssueComment>, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/comments",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let comments = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query issue comments")?;
+
+        Ok(comments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_list_issue_comments;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListIssueComments;
+
+    #[tokio::test]
+    async fn task_returns_comments() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_list_issue_comments();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task =
+            ListIssueComments::new(&github_client, &login, &repository, IssueNumber::new(1347));
+
+        let comments = task.execute().await.unwrap();
+
+        assert_eq!(1, comments.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListIssueComments>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListIssueComments>();
+    }
+}
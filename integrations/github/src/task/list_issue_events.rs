@@ -0,0 +1,95 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueEvent, IssueNumber, Login, RepositoryName};
+
+/// List the events for an issue
+///
+/// Lists all events for an issue or pull request, for example when it was labeled, assigned, or
+/// closed. GitHub Apps must have the `issues:read` permission to list issue events.
+///
+/// https://docs.github.com/en/rest/issues/events#list-issue-events
+#[derive(Copy, Clone, Debug)]
+pub struct ListIssueEvents<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+}
+
+impl<'a> ListIssueEvents<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+        }
+    }
+
+    /// List the events for an issue
+    pub async fn execute(&self) -> Result<Vec<IssueEvent>, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/events",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let events = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query issue events")?;
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_list_issue_events;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListIssueEvents;
+
+    #[tokio::test]
+    async fn task_returns_issue_events() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_list_issue_events();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task =
+            ListIssueEvents::new(&github_client, &login, &repository, IssueNumber::new(1347));
+
+        let events = task.execute().await.unwrap();
+
+        assert_eq!(1, events.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListIssueEvents>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListIssueEvents>();
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueNumber, Login, RepositoryName, TimelineEvent};
+
+/// List the timeline events for an issue
+///
+/// Lists the timeline of an issue or pull request, which combines its events with comments,
+/// commits, and cross-references from other issues. GitHub Apps must have the `issues:read`
+/// permission to list timeline events.
+///
+/// https://docs.github.com/en/rest/issues/timeline#list-timeline-events-for-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct ListIssueTimelineEvents<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+}
+
+impl<'a> ListIssueTimelineEvents<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+        }
+    }
+
+    /// List the timeline events for an issue
+    pub async fn execute(&self) -> Result<Vec<TimelineEvent>, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/timeline",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let events = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query issue timeline")?;
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, Login, RepositoryName, TimelineEvent};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_list_issue_timeline_events;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListIssueTimelineEvents;
+
+    #[tokio::test]
+    async fn task_returns_timeline_events() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_list_issue_timeline_events();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = ListIssueTimelineEvents::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1347),
+        );
+
+        let events = task.execute().await.unwrap();
+
+        assert_eq!(5, events.len());
+        assert!(matches!(events[0], TimelineEvent::Labeled(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListIssueTimelineEvents>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListIssueTimelineEvents>();
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, Ruleset};
+
+/// List the rulesets for an organization
+///
+/// Lists the rulesets of an organization. The GitHub App must have the
+/// `organization_administration:read` permission to list the rulesets.
+///
+/// https://docs.github.com/en/rest/orgs/rules#get-all-organization-repository-rulesets
+#[derive(Copy, Clone, Debug)]
+pub struct ListOrganizationRulesets<'a> {
+    github_client: &'a GitHubClient,
+    organization: &'a Login,
+}
+
+impl<'a> ListOrganizationRulesets<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient, organization: &'a Login) -> Self {
+        Self {
+            github_client,
+            organization,
+        }
+    }
+
+    /// List the rulesets for an organization
+    pub async fn execute(&self) -> Result<Vec<Ruleset>, Error> {
+        let url = format!("/orgs/{}/rulesets", self.organization.get());
+
+        let rulesets = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query organization rulesets")?;
+
+        Ok(rulesets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::Login;
+    use crate::testing::client::github_client;
+    use crate::testing::ruleset::mock_list_organization_rulesets;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListOrganizationRulesets;
+
+    #[tokio::test]
+    async fn task_returns_rulesets() {
+        let _token_mock = mock_installation_access_tokens();
+        let _rulesets_mock = mock_list_organization_rulesets();
+
+        let github_client = github_client();
+        let organization = Login::new("devxbots");
+
+        let task = ListOrganizationRulesets::new(&github_client, &organization);
+
+        let rulesets = task.execute().await.unwrap();
+
+        assert_eq!(1, rulesets.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListOrganizationRulesets>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListOrganizationRulesets>();
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, PopularPath, RepositoryName};
+
+/// List the popular paths of a repository
+///
+/// Lists the ten most viewed pages of a repository in the last 14 days. The GitHub App must have
+/// the `administration:read` permission to get traffic data.
+///
+/// https://docs.github.com/en/rest/metrics/traffic#get-top-referral-paths
+#[derive(Copy, Clone, Debug)]
+pub struct ListPopularPaths<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListPopularPaths<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the popular paths of a repository
+    pub async fn execute(&self) -> Result<Vec<PopularPath>, Error> {
+        let url = format!(
+            "/repos/{}/{}/traffic/popular/paths",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let paths = self
+            .github_client
+            .get(&url)
+            .await
+            .context("failed to query popular paths")?;
+
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+    use crate::testing::traffic::mock_list_popular_paths;
+
+    use super::ListPopularPaths;
+
+    #[tokio::test]
+    async fn task_returns_popular_paths() {
+        let _token_mock = mock_installation_access_tokens();
+        let _paths_mock = mock_list_popular_paths();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = ListPopularPaths::new(&github_client, &login, &repository);
+
+        let paths = task.execute().await.unwrap();
+
+        assert_eq!(1, paths.len());
+        assert_eq!(3542, paths[0].count());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListPopularPaths>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListPopularPaths>();
+    }
+}
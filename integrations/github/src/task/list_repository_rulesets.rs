@@ -0,0 +1,91 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, Ruleset};
+
+/// List the rulesets for a repository
+///
+/// Lists the rulesets that apply to a repository, including the rulesets of its organization. The
+/// list contains a summary of each ruleset, and its rules can be queried with the
+/// [`GetRepositoryRuleset`](crate::task::GetRepositoryRuleset) task.
+///
+/// https://docs.github.com/en/rest/repos/rules#get-all-repository-rulesets
+#[derive(Copy, Clone, Debug)]
+pub struct ListRepositoryRulesets<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListRepositoryRulesets<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the rulesets for a repository
+    pub async fn execute(&self) -> Result<Vec<Ruleset>, Error> {
+        let url = format!(
+            "/repos/{}/{}/rulesets",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let rulesets = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query repository rulesets")?;
+
+        Ok(rulesets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::ruleset::mock_list_repository_rulesets;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListRepositoryRulesets;
+
+    #[tokio::test]
+    async fn task_returns_rulesets() {
+        let _token_mock = mock_installation_access_tokens();
+        let _rulesets_mock = mock_list_repository_rulesets();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = ListRepositoryRulesets::new(&github_client, &login, &repository);
+
+        let rulesets = task.execute().await.unwrap();
+
+        assert_eq!(1, rulesets.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListRepositoryRulesets>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListRepositoryRulesets>();
+    }
+}
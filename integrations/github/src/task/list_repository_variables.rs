@@ -0,0 +1,90 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{ActionsVariable, Login, RepositoryName};
+
+/// List the Actions variables of a repository
+///
+/// Lists the variables that are configured for the workflows of a repository. The GitHub App must
+/// have the `actions_variables:read` permission to list variables.
+///
+/// https://docs.github.com/en/rest/actions/variables#list-repository-variables
+#[derive(Copy, Clone, Debug)]
+pub struct ListRepositoryVariables<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListRepositoryVariables<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the Actions variables of a repository
+    pub async fn execute(&self) -> Result<Vec<ActionsVariable>, Error> {
+        let url = format!(
+            "/repos/{}/{}/actions/variables",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let variables = self
+            .github_client
+            .paginate(Method::GET, &url, "variables")
+            .await
+            .context("failed to query repository variables")?;
+
+        Ok(variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::actions::mock_list_repository_variables;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListRepositoryVariables;
+
+    #[tokio::test]
+    async fn task_returns_variables() {
+        let _token_mock = mock_installation_access_tokens();
+        let _variables_mock = mock_list_repository_variables();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = ListRepositoryVariables::new(&github_client, &login, &repository);
+
+        let variables = task.execute().await.unwrap();
+
+        assert_eq!(1, variables.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListRepositoryVariables>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListRepositoryVariables>();
+    }
+}
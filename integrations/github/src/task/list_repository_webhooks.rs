@@ -0,0 +1,90 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Hook, Login, RepositoryName};
+
+/// List the webhooks for a repository
+///
+/// Lists the webhooks that are configured for a repository. The GitHub App must have the
+/// `repository_hooks:read` permission to list webhooks.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#list-repository-webhooks
+#[derive(Copy, Clone, Debug)]
+pub struct ListRepositoryWebhooks<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+}
+
+impl<'a> ListRepositoryWebhooks<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+        }
+    }
+
+    /// List the webhooks for a repository
+    pub async fn execute(&self) -> Result<Vec<Hook>, Error> {
+        let url = format!(
+            "/repos/{}/{}/hooks",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let hooks = self
+            .github_client
+            .paginate_array(Method::GET, &url)
+            .await
+            .context("failed to query repository webhooks")?;
+
+        Ok(hooks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_list_repository_webhooks;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ListRepositoryWebhooks;
+
+    #[tokio::test]
+    async fn task_returns_hooks() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_list_repository_webhooks();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = ListRepositoryWebhooks::new(&github_client, &login, &repository);
+
+        let hooks = task.execute().await.unwrap();
+
+        assert_eq!(1, hooks.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListRepositoryWebhooks>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListRepositoryWebhooks>();
+    }
+}
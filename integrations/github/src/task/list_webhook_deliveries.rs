@@ -0,0 +1,71 @@
+use anyhow::Context;
+use reqwest::Method;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::HookDelivery;
+
+/// List the deliveries for the app's webhook
+///
+/// Lists the recent deliveries of the GitHub App's webhook, including failed deliveries that can
+/// be redelivered. The app must authenticate as itself to list deliveries, which is why the task
+/// requires a client for a GitHub App.
+///
+/// https://docs.github.com/en/rest/apps/webhooks#list-deliveries-for-an-app-webhook
+#[derive(Copy, Clone, Debug)]
+pub struct ListWebhookDeliveries<'a> {
+    github_client: &'a GitHubClient,
+}
+
+impl<'a> ListWebhookDeliveries<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// List the deliveries for the app's webhook
+    pub async fn execute(&self) -> Result<Vec<HookDelivery>, Error> {
+        let deliveries = self
+            .github_client
+            .as_app()?
+            .paginate_array(Method::GET, "/app/hook/deliveries")
+            .await
+            .context("failed to query webhook deliveries")?;
+
+        Ok(deliveries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_list_webhook_deliveries;
+
+    use super::ListWebhookDeliveries;
+
+    #[tokio::test]
+    async fn task_returns_deliveries() {
+        let _content_mock = mock_list_webhook_deliveries();
+
+        let github_client = github_client();
+
+        let task = ListWebhookDeliveries::new(&github_client);
+
+        let deliveries = task.execute().await.unwrap();
+
+        assert_eq!(1, deliveries.len());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListWebhookDeliveries>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListWebhookDeliveries>();
+    }
+}
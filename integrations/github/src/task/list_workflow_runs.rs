@@ -0,0 +1,159 @@
+use anyhow::Context;
+use reqwest::Method;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitRef, GitSha, Login, RepositoryName, WorkflowRun, WorkflowRunStatus};
+
+/// List the workflow runs for a repository
+///
+/// Lists the workflow runs of a repository, optionally filtered by branch, event, status, or
+/// commit. GitHub Apps must have the `actions:read` permission to list workflow runs.
+///
+/// https://docs.github.com/en/rest/actions/workflow-runs#list-workflow-runs-for-a-repository
+#[derive(Copy, Clone, Debug)]
+pub struct ListWorkflowRuns<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    list_args: &'a ListWorkflowRunsArgs,
+}
+
+/// Input for list workflow runs task
+///
+/// The input for the task that lists workflow runs represents the filters that GitHub's API
+/// accepts. All filters are optional.
+///
+/// https://docs.github.com/en/rest/actions/workflow-runs#list-workflow-runs-for-a-repository
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+pub struct ListWorkflowRunsArgs {
+    /// Only list workflow runs for this branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<GitRef>,
+
+    /// Only list workflow runs that were triggered by this event, e.g. `push`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+
+    /// Only list workflow runs with this status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<WorkflowRunStatus>,
+
+    /// Only list workflow runs for this commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_sha: Option<GitSha>,
+}
+
+impl<'a> ListWorkflowRuns<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        list_args: &'a ListWorkflowRunsArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            list_args,
+        }
+    }
+
+    /// List the workflow runs for a repository
+    pub async fn execute(&self) -> Result<Vec<WorkflowRun>, Error> {
+        let query = serde_urlencoded::to_string(self.list_args)
+            .map_err(|error| Error::Serialization(error.to_string()))?;
+
+        let mut url = format!(
+            "/repos/{}/{}/actions/runs",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        if !query.is_empty() {
+            url = format!("{}?{}", url, query);
+        }
+
+        let workflow_runs = self
+            .github_client
+            .paginate(Method::GET, &url, "workflow_runs")
+            .await
+            .context("failed to query workflow runs")?;
+
+        Ok(workflow_runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{GitRef, Login, RepositoryName, WorkflowRunStatus};
+    use crate::testing::actions::mock_list_workflow_runs;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{ListWorkflowRuns, ListWorkflowRunsArgs};
+
+    #[tokio::test]
+    async fn task_returns_workflow_runs() {
+        let _token_mock = mock_installation_access_tokens();
+        let _workflow_runs_mock = mock_list_workflow_runs();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let args = ListWorkflowRunsArgs::default();
+
+        let task = ListWorkflowRuns::new(&github_client, &login, &repository, &args);
+
+        let workflow_runs = task.execute().await.unwrap();
+
+        assert_eq!(1, workflow_runs.len());
+    }
+
+    #[tokio::test]
+    async fn task_filters_workflow_runs() {
+        let _token_mock = mock_installation_access_tokens();
+        let workflow_runs_mock = mock("GET", "/repos/github/filter-runs/actions/runs")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("branch".into(), "main".into()),
+                Matcher::UrlEncoded("event".into(), "push".into()),
+                Matcher::UrlEncoded("status".into(), "in_progress".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{ "total_count": 0, "workflow_runs": [] }"#)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("filter-runs");
+        let args = ListWorkflowRunsArgs {
+            branch: Some(GitRef::new("main")),
+            event: Some("push".into()),
+            status: Some(WorkflowRunStatus::InProgress),
+            head_sha: None,
+        };
+
+        let task = ListWorkflowRuns::new(&github_client, &login, &repository, &args);
+
+        task.execute().await.unwrap();
+
+        workflow_runs_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ListWorkflowRuns>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ListWorkflowRuns>();
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Context;
+use serde::Serialize;
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueLockReason, IssueNumber, Login, RepositoryName};
+
+/// Lock an issue
+///
+/// Locks the conversation of an issue or pull request, so that only collaborators can comment on
+/// it. The GitHub App must have the `issues:write` permission to lock issues.
+///
+/// https://docs.github.com/en/rest/issues/issues#lock-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct LockIssue<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+    lock_reason: Option<IssueLockReason>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct LockIssuePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_reason: Option<IssueLockReason>,
+}
+
+impl<'a> LockIssue<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+        lock_reason: Option<IssueLockReason>,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+            lock_reason,
+        }
+    }
+
+    /// Lock an issue
+    pub async fn execute(&self) -> Result<(), Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/lock",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_number
+        );
+
+        let payload = LockIssuePayload {
+            lock_reason: self.lock_reason,
+        };
+
+        self.github_client
+            .put::<Value>(&url, Some(payload))
+            .await
+            .context("failed to lock issue")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueLockReason, IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_lock_issue;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::LockIssue;
+
+    #[tokio::test]
+    async fn task_locks_issue() {
+        let _token_mock = mock_installation_access_tokens();
+        let lock_mock = mock_lock_issue();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = LockIssue::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1347),
+            Some(IssueLockReason::TooHeated),
+        );
+
+        task.execute().await.unwrap();
+
+        lock_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<LockIssue>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<LockIssue>();
+    }
+}
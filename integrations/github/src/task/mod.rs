@@ -1,24 +1,185 @@
 //! Tasks for the GitHub
 //!
 //! The GitHub integration implements tasks that can be used to create automatons.
+//!
+//! Every task is a struct that is initialized with `new` and executed with `execute`, which makes
+//! it usable on its own, e.g. in a script or a test. Inside an automaton, a task becomes a step with
+//! [`FnTask`](automatons::FnTask), which owns the task's inputs and returns the next transition.
+//!
+//! ```
+//! use automatons::{FnTask, Transition};
+//! use automatons_github::client::GitHubClient;
+//! use automatons_github::resource::{CheckRun, GitSha, Login, RepositoryName};
+//! use automatons_github::task::ListCheckRunsForGitSha;
+//!
+//! fn list_check_runs(
+//!     github_client: GitHubClient,
+//!     owner: Login,
+//!     repository: RepositoryName,
+//!     head_sha: GitSha,
+//! ) -> FnTask<Vec<CheckRun>> {
+//!     FnTask::new("list check runs", move || async move {
+//!         let check_runs =
+//!             ListCheckRunsForGitSha::new(&github_client, &owner, &repository, &head_sha)
+//!                 .execute()
+//!                 .await?;
+//!
+//!         Ok(Transition::Complete(check_runs))
+//!     })
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
-use serde::Serialize;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use url::Url;
 
-use crate::resource::{CheckRunOutputSummary, CheckRunOutputTitle};
+use automatons::Error;
 
+use crate::resource::{
+    CheckRunOutputSummary, CheckRunOutputTitle, RulesetEnforcement, RulesetName, RulesetTarget,
+};
+use crate::webhook::GitHubWebhookSecret;
+
+pub use self::add_labels::AddLabels;
+pub use self::apply_changeset::{AppliedChangeset, ApplyChangeset, ChangesetArgs, FileChange};
+pub use self::assign_issue::AssignIssue;
+pub use self::blob_cache::{BlobCache, MemoryBlobCache};
+pub use self::cancel_workflow_run::CancelWorkflowRun;
+pub use self::check_run_output::{CheckRunOutputBuilder, Markdown};
+pub use self::check_run_reporter::CheckRunReporter;
+pub use self::commit_signer::{CommitSigner, SshCommitSigner};
 pub use self::create_check_run::{CreateCheckRun, CreateCheckRunArgs};
+pub use self::create_check_suite::CreateCheckSuite;
+pub use self::create_commit::{CreateCommit, CreateCommitArgs, GitUserArgs};
+pub use self::create_issue::{CreateIssue, CreateIssueArgs};
+pub use self::create_issue_comment::CreateIssueComment;
+pub use self::create_organization_ruleset::CreateOrganizationRuleset;
+pub use self::create_pull_request::{CreatePullRequest, CreatePullRequestArgs};
+pub use self::create_repository_ruleset::CreateRepositoryRuleset;
+pub use self::create_repository_webhook::{CreateRepositoryWebhook, CreateRepositoryWebhookArgs};
+pub use self::download_repository_archive::{ArchiveFormat, DownloadRepositoryArchive};
+pub use self::get_actions_billing::GetActionsBilling;
+pub use self::get_app::GetApp;
+pub use self::get_check_run::GetCheckRun;
+pub use self::get_code_frequency::GetCodeFrequency;
+pub use self::get_commit_activity::GetCommitActivity;
 pub use self::get_file::GetFile;
+pub use self::get_organization::GetOrganization;
+pub use self::get_participation::GetParticipation;
+pub use self::get_rate_limit::GetRateLimit;
+pub use self::get_repository_clones::GetRepositoryClones;
+pub use self::get_repository_public_key::GetRepositoryPublicKey;
+pub use self::get_repository_ruleset::GetRepositoryRuleset;
+pub use self::get_repository_views::GetRepositoryViews;
+pub use self::get_tree::GetTree;
+pub use self::get_user::GetUser;
 pub use self::list_check_runs_for_check_suite::ListCheckRunsForCheckSuite;
 pub use self::list_check_runs_for_git_sha::ListCheckRunsForGitSha;
 pub use self::list_check_suites::ListCheckSuites;
-pub use self::update_check_run::{UpdateCheckRun, UpdateCheckRunArgs};
+pub use self::list_contributors::ListContributors;
+pub use self::list_dependabot_alerts::{ListDependabotAlerts, ListDependabotAlertsArgs};
+pub use self::list_environments::ListEnvironments;
+pub use self::list_installation_repositories::ListInstallationRepositories;
+pub use self::list_issue_comments::ListIssueComments;
+pub use self::list_issue_events::ListIssueEvents;
+pub use self::list_issue_timeline_events::ListIssueTimelineEvents;
+pub use self::list_organization_rulesets::ListOrganizationRulesets;
+pub use self::list_popular_paths::ListPopularPaths;
+pub use self::list_repository_rulesets::ListRepositoryRulesets;
+pub use self::list_repository_variables::ListRepositoryVariables;
+pub use self::list_repository_webhooks::ListRepositoryWebhooks;
+pub use self::list_webhook_deliveries::ListWebhookDeliveries;
+pub use self::list_workflow_runs::{ListWorkflowRuns, ListWorkflowRunsArgs};
+pub use self::lock_issue::LockIssue;
+pub use self::ping_webhook::PingWebhook;
+pub use self::propose_change::{ProposeChange, ProposeChangeArgs, ProposedChange};
+pub use self::put_repository_secret::PutRepositorySecret;
+pub use self::put_repository_variable::PutRepositoryVariable;
+pub use self::redeliver_webhook_delivery::RedeliverWebhookDelivery;
+pub use self::request_review::RequestReview;
+pub use self::request_team_review::RequestTeamReview;
+pub use self::rerun_workflow_run::RerunWorkflowRun;
+pub use self::resolve_code_owners::ResolveCodeOwners;
+pub use self::update_check_run::{CheckRunPrecondition, UpdateCheckRun, UpdateCheckRunArgs};
+pub use self::update_check_suite_preferences::{AutoTriggerCheckArgs, UpdateCheckSuitePreferences};
+pub use self::update_dependabot_alert::{UpdateDependabotAlert, UpdateDependabotAlertArgs};
+pub use self::update_issue::{UpdateIssue, UpdateIssueArgs};
+pub use self::update_issue_comment::UpdateIssueComment;
+pub use self::update_repository_webhook::{UpdateRepositoryWebhook, UpdateRepositoryWebhookArgs};
+pub use self::upload_sarif::{UploadSarif, UploadSarifArgs};
+pub use self::upsert_issue_comment::UpsertIssueComment;
 
+mod add_labels;
+mod apply_changeset;
+mod assign_issue;
+mod blob_cache;
+mod cancel_workflow_run;
+mod check_run_output;
+mod check_run_reporter;
+mod commit_signer;
 mod create_check_run;
+mod create_check_suite;
+mod create_commit;
+mod create_issue;
+mod create_issue_comment;
+mod create_organization_ruleset;
+mod create_pull_request;
+mod create_repository_ruleset;
+mod create_repository_webhook;
+mod download_repository_archive;
+mod get_actions_billing;
+mod get_app;
+mod get_check_run;
+mod get_code_frequency;
+mod get_commit_activity;
 mod get_file;
+mod get_organization;
+mod get_participation;
+mod get_rate_limit;
+mod get_repository_clones;
+mod get_repository_public_key;
+mod get_repository_ruleset;
+mod get_repository_views;
+mod get_tree;
+mod get_user;
 mod list_check_runs_for_check_suite;
 mod list_check_runs_for_git_sha;
 mod list_check_suites;
+mod list_contributors;
+mod list_dependabot_alerts;
+mod list_environments;
+mod list_installation_repositories;
+mod list_issue_comments;
+mod list_issue_events;
+mod list_issue_timeline_events;
+mod list_organization_rulesets;
+mod list_popular_paths;
+mod list_repository_rulesets;
+mod list_repository_variables;
+mod list_repository_webhooks;
+mod list_webhook_deliveries;
+mod list_workflow_runs;
+mod lock_issue;
+mod ping_webhook;
+mod propose_change;
+mod put_repository_secret;
+mod put_repository_variable;
+mod redeliver_webhook_delivery;
+mod request_review;
+mod request_team_review;
+mod rerun_workflow_run;
+mod resolve_code_owners;
 mod update_check_run;
+mod update_check_suite_preferences;
+mod update_dependabot_alert;
+mod update_issue;
+mod update_issue_comment;
+mod update_repository_webhook;
+mod upload_sarif;
+mod upsert_issue_comment;
 
 /// Input for check run output
 ///
@@ -37,4 +198,251 @@ pub struct CheckRunOutputArgs {
     /// The text with descriptive details about the check run.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+
+    /// The policy for a summary or text that exceeds GitHub's limit.
+    #[serde(skip)]
+    pub overflow: CheckRunOutputOverflow,
+}
+
+/// Input for webhook configuration
+///
+/// The configuration of a webhook determines where and how GitHub delivers the webhook's events.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook
+#[derive(Clone, Debug, Serialize)]
+pub struct HookConfigArgs {
+    /// The URL to which the payloads will be delivered.
+    pub url: Url,
+
+    /// The media type used to serialize the payloads, either `json` or `form`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// The secret that GitHub uses to sign the payloads.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_webhook_secret"
+    )]
+    pub secret: Option<GitHubWebhookSecret>,
+
+    /// Whether SSL verification is disabled, either `0` or `1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<String>,
+}
+
+/// Input for a ruleset
+///
+/// Rulesets are created with the same parameters for repositories and organizations. Rulesets of
+/// organizations additionally accept a `repository_name` condition that selects the repositories to
+/// which the ruleset applies.
+///
+/// https://docs.github.com/en/rest/repos/rules#create-a-repository-ruleset
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct RulesetArgs {
+    /// The name of the ruleset.
+    pub name: RulesetName,
+
+    /// The target of the ruleset. `branch` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<RulesetTarget>,
+
+    /// The enforcement of the ruleset.
+    pub enforcement: RulesetEnforcement,
+
+    /// The actors that can bypass the ruleset.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bypass_actors: Vec<RulesetBypassActorArgs>,
+
+    /// The conditions that select the branches, tags, or repositories of the ruleset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Value>,
+
+    /// The rules of the ruleset.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RulesetRuleArgs>,
+}
+
+/// Input for an actor that can bypass a ruleset
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct RulesetBypassActorArgs {
+    /// The id of the actor, which must be empty for organization admins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<u64>,
+
+    /// The type of the actor, e.g. `Team`, `Integration`, or `OrganizationAdmin`.
+    pub actor_type: String,
+
+    /// When the actor can bypass the ruleset, either `always` or `pull_request`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_mode: Option<String>,
+}
+
+/// Input for a rule of a ruleset
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct RulesetRuleArgs {
+    /// The type of the rule, e.g. `deletion` or `pull_request`.
+    #[serde(rename = "type")]
+    pub rule_type: String,
+
+    /// The parameters of the rule, which depend on its type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+}
+
+/// Retry policy for repository statistics
+///
+/// GitHub computes the statistics of a repository in the background, and responds with `202
+/// Accepted` until they are ready. The policy determines how often and how long the statistics
+/// tasks wait for GitHub before they give up.
+///
+/// https://docs.github.com/en/rest/metrics/statistics#a-word-about-caching
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StatisticsRetry {
+    /// The maximum number of requests that are sent.
+    pub attempts: usize,
+
+    /// The delay between two requests.
+    pub delay: Duration,
+}
+
+impl Default for StatisticsRetry {
+    fn default() -> Self {
+        Self {
+            attempts: 5,
+            delay: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Interval of traffic data
+///
+/// GitHub groups the clones and views of a repository either by day or by week.
+///
+/// https://docs.github.com/en/rest/metrics/traffic
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum TrafficInterval {
+    /// Traffic per day
+    #[default]
+    Day,
+
+    /// Traffic per week, starting on Monday
+    Week,
+}
+
+impl Display for TrafficInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_representation = match self {
+            TrafficInterval::Day => "day",
+            TrafficInterval::Week => "week",
+        };
+
+        write!(f, "{}", string_representation)
+    }
+}
+
+fn serialize_webhook_secret<S>(
+    secret: &Option<GitHubWebhookSecret>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match secret {
+        Some(secret) => serializer.serialize_some(secret.expose()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Maximum number of characters in the summary and text of a check run's output
+pub const CHECK_RUN_OUTPUT_LIMIT: usize = 65535;
+
+const TRUNCATION_NOTICE: &str =
+    "\n\n_The output has been truncated, since it exceeded GitHub's limit of 65535 characters._";
+
+/// Policy for oversized check run output
+///
+/// GitHub limits the summary and the text of a check run's output to 65535 characters each, and
+/// rejects larger output with an error. The policy determines how the tasks that create and update
+/// check runs handle output that exceeds the limit.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum CheckRunOutputOverflow {
+    /// Truncate the output and append a notice that it has been truncated.
+    #[default]
+    Truncate,
+
+    /// Return an error without sending the output to GitHub.
+    Reject,
+}
+
+impl CheckRunOutputArgs {
+    /// Applies the overflow policy to the summary and the text.
+    pub(crate) fn enforce_limit(&mut self) -> Result<(), Error> {
+        let summary = limit(self.summary.get(), self.overflow)?;
+        self.summary = CheckRunOutputSummary::new(&summary);
+
+        if let Some(text) = &self.text {
+            self.text = Some(limit(text, self.overflow)?);
+        }
+
+        Ok(())
+    }
+}
+
+fn limit(content: &str, overflow: CheckRunOutputOverflow) -> Result<String, Error> {
+    if content.chars().count() <= CHECK_RUN_OUTPUT_LIMIT {
+        return Ok(content.into());
+    }
+
+    match overflow {
+        CheckRunOutputOverflow::Truncate => {
+            let length = CHECK_RUN_OUTPUT_LIMIT - TRUNCATION_NOTICE.chars().count();
+            let mut truncated: String = content.chars().take(length).collect();
+            truncated.push_str(TRUNCATION_NOTICE);
+
+            Ok(truncated)
+        }
+        CheckRunOutputOverflow::Reject => Err(Error::Configuration(format!(
+            "check run output exceeds GitHub's limit of {} characters",
+            CHECK_RUN_OUTPUT_LIMIT
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::resource::{CheckRunOutputSummary, CheckRunOutputTitle};
+
+    use super::{CheckRunOutputArgs, CheckRunOutputOverflow, CHECK_RUN_OUTPUT_LIMIT};
+
+    fn output(overflow: CheckRunOutputOverflow) -> CheckRunOutputArgs {
+        CheckRunOutputArgs {
+            title: CheckRunOutputTitle::new("title"),
+            summary: CheckRunOutputSummary::new("summary"),
+            text: Some("x".repeat(CHECK_RUN_OUTPUT_LIMIT + 1)),
+            overflow,
+        }
+    }
+
+    #[test]
+    fn enforce_limit_truncates_text() {
+        let mut output = output(CheckRunOutputOverflow::Truncate);
+
+        output.enforce_limit().unwrap();
+
+        let text = output.text.unwrap();
+        assert_eq!(CHECK_RUN_OUTPUT_LIMIT, text.chars().count());
+        assert!(text.ends_with("characters._"));
+        assert_eq!("summary", output.summary.get());
+    }
+
+    #[test]
+    fn enforce_limit_rejects_text() {
+        let mut output = output(CheckRunOutputOverflow::Reject);
+
+        let error = output.enforce_limit().unwrap_err();
+
+        assert!(matches!(error, Error::Configuration(_)));
+    }
 }
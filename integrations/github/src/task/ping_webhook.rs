@@ -0,0 +1,96 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{HookId, Login, RepositoryName};
+
+/// Ping a repository webhook
+///
+/// Triggers a ping event that is sent to the webhook, which can be used to verify that the
+/// webhook's URL is reachable. The GitHub App must have the `repository_hooks:write` permission to
+/// ping webhooks.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#ping-a-repository-webhook
+#[derive(Copy, Clone, Debug)]
+pub struct PingWebhook<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    hook_id: HookId,
+}
+
+impl<'a> PingWebhook<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        hook_id: HookId,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            hook_id,
+        }
+    }
+
+    /// Ping a repository webhook
+    pub async fn execute(&self) -> Result<(), Error> {
+        let url = format!(
+            "/repos/{}/{}/hooks/{}/pings",
+            self.owner.get(),
+            self.repository.get(),
+            self.hook_id
+        );
+
+        // We need to explicitly declare the type of the body somewhere to silence a compiler error.
+        let body: Option<()> = None;
+
+        self.github_client
+            .post::<()>(&url, body)
+            .await
+            .context("failed to ping repository webhook")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{HookId, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_ping_webhook;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::PingWebhook;
+
+    #[tokio::test]
+    async fn task_pings_hook() {
+        let _token_mock = mock_installation_access_tokens();
+        let ping_mock = mock_ping_webhook();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = PingWebhook::new(&github_client, &login, &repository, HookId::new(12345678));
+
+        task.execute().await.unwrap();
+
+        ping_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PingWebhook>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PingWebhook>();
+    }
+}
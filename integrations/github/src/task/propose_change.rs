@@ -0,0 +1,287 @@
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueNumber, LabelName, Login, PullRequest, RepositoryName, TeamSlug};
+use crate::task::{
+    AddLabels, AppliedChangeset, ApplyChangeset, ChangesetArgs, CommitSigner, CreatePullRequest,
+    CreatePullRequestArgs, RequestReview, RequestTeamReview,
+};
+
+/// Propose a change as a pull request
+///
+/// Implements the complete flow of bots that propose changes, e.g. dependency updates: the task
+/// applies a changeset as a commit on a new branch, opens a pull request for the branch, adds
+/// labels to the pull request, and requests reviews from users and teams.
+///
+/// The title and body of the pull request are templates, in which `{branch}`, `{base}`, and `{sha}`
+/// are replaced with the new branch, the base branch, and the SHA of the commit.
+///
+/// Each step is a separate request. If a later step fails, e.g. because a reviewer is not a
+/// collaborator, the branch and the pull request have already been created.
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeChange<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    change_args: &'a ProposeChangeArgs,
+    signer: Option<&'a dyn CommitSigner>,
+}
+
+/// Input for propose change task
+///
+/// The input contains the changeset that is committed, and the pull request that proposes it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ProposeChangeArgs {
+    /// The changes and the branch that they are committed to.
+    pub changeset: ChangesetArgs,
+
+    /// The template for the title of the pull request.
+    pub title: String,
+
+    /// The template for the contents of the pull request.
+    pub body: Option<String>,
+
+    /// Whether the pull request is opened as a draft.
+    pub draft: bool,
+
+    /// The names of the labels to add to the pull request.
+    pub labels: Vec<LabelName>,
+
+    /// The logins of the users whose review is requested.
+    pub reviewers: Vec<Login>,
+
+    /// The slugs of the teams whose review is requested.
+    pub team_reviewers: Vec<TeamSlug>,
+}
+
+/// Result of proposing a change
+///
+/// The [`ProposedChange`] contains the changeset that was applied and the pull request that was
+/// opened for it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ProposedChange {
+    changeset: AppliedChangeset,
+    pull_request: PullRequest,
+}
+
+impl<'a> ProposeChange<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        change_args: &'a ProposeChangeArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            change_args,
+            signer: None,
+        }
+    }
+
+    /// Signs the commit with the given signer.
+    pub fn signer(mut self, signer: &'a dyn CommitSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Propose a change as a pull request
+    pub async fn execute(&self) -> Result<ProposedChange, Error> {
+        let changeset_args = &self.change_args.changeset;
+
+        let mut apply_changeset = ApplyChangeset::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            changeset_args,
+        );
+        if let Some(signer) = self.signer {
+            apply_changeset = apply_changeset.signer(signer);
+        }
+        let changeset = apply_changeset.execute().await?;
+
+        let pull_request_args = CreatePullRequestArgs {
+            title: self.render(&self.change_args.title, &changeset),
+            head: changeset_args.branch.clone(),
+            base: changeset_args.base.clone(),
+            body: self
+                .change_args
+                .body
+                .as_ref()
+                .map(|body| self.render(body, &changeset)),
+            draft: Some(self.change_args.draft),
+        };
+        let pull_request = CreatePullRequest::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            &pull_request_args,
+        )
+        .execute()
+        .await?;
+
+        if !self.change_args.labels.is_empty() {
+            AddLabels::new(
+                self.github_client,
+                self.owner,
+                self.repository,
+                IssueNumber::new(pull_request.number().get()),
+                &self.change_args.labels,
+            )
+            .execute()
+            .await?;
+        }
+
+        if !self.change_args.reviewers.is_empty() {
+            RequestReview::new(
+                self.github_client,
+                self.owner,
+                self.repository,
+                pull_request.number(),
+                &self.change_args.reviewers,
+            )
+            .execute()
+            .await?;
+        }
+
+        if !self.change_args.team_reviewers.is_empty() {
+            RequestTeamReview::new(
+                self.github_client,
+                self.owner,
+                self.repository,
+                pull_request.number(),
+                &self.change_args.team_reviewers,
+            )
+            .execute()
+            .await?;
+        }
+
+        Ok(ProposedChange {
+            changeset,
+            pull_request,
+        })
+    }
+
+    fn render(&self, template: &str, changeset: &AppliedChangeset) -> String {
+        template
+            .replace("{branch}", changeset.branch().get())
+            .replace("{base}", self.change_args.changeset.base.get())
+            .replace("{sha}", changeset.sha().get())
+    }
+}
+
+impl ProposedChange {
+    /// Returns the changeset that was applied.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn changeset(&self) -> &AppliedChangeset {
+        &self.changeset
+    }
+
+    /// Returns the pull request that proposes the change.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn pull_request(&self) -> &PullRequest {
+        &self.pull_request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{GitRef, LabelName, Login, RepositoryName, TeamSlug};
+    use crate::task::{ChangesetArgs, FileChange};
+    use crate::testing::client::github_client;
+    use crate::testing::git::{
+        mock_create_blob, mock_create_changeset_commit, mock_create_reference, mock_create_tree,
+        mock_get_base_tree, mock_get_commit, mock_get_reference,
+    };
+    use crate::testing::issue::mock_add_labels;
+    use crate::testing::pull_request::{mock_request_maintainers_review, mock_request_review};
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{ProposeChange, ProposeChangeArgs};
+
+    #[tokio::test]
+    async fn task_returns_proposed_change() {
+        let _token_mock = mock_installation_access_tokens();
+        let _reference_mock = mock_get_reference();
+        let _commit_mock = mock_get_commit();
+        let _base_tree_mock = mock_get_base_tree();
+        let _blob_mock = mock_create_blob();
+        let _tree_mock = mock_create_tree();
+        let _create_commit_mock = mock_create_changeset_commit();
+        let _create_reference_mock = mock_create_reference();
+        let pull_request_mock = mock("POST", "/repos/octocat/Hello-World/pulls")
+            .match_body(Matcher::Json(serde_json::json!({
+                "title": "Merge fix-readme into main",
+                "head": "fix-readme",
+                "base": "main",
+                "body": "Commit 7638417db6d59f3c431d3e1f261cc637155684cd",
+                "draft": false
+            })))
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/pull_request.json"
+            ))
+            .create();
+        let labels_mock = mock_add_labels();
+        let review_mock = mock_request_review();
+        let team_review_mock = mock_request_maintainers_review();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let change_args = ProposeChangeArgs {
+            changeset: ChangesetArgs {
+                base: GitRef::new("main"),
+                branch: GitRef::new("fix-readme"),
+                message: "Fix README".into(),
+                author: None,
+                changes: vec![
+                    FileChange::Write {
+                        path: "README.md".into(),
+                        content: b"Hello, World!".to_vec(),
+                    },
+                    FileChange::Delete {
+                        path: "CHANGELOG.md".into(),
+                    },
+                    FileChange::Write {
+                        path: "NOTICE".into(),
+                        content: b"Hello, World!".to_vec(),
+                    },
+                ],
+            },
+            title: "Merge {branch} into {base}".into(),
+            body: Some("Commit {sha}".into()),
+            draft: false,
+            labels: vec![LabelName::new("bug")],
+            reviewers: vec![Login::new("hubot")],
+            team_reviewers: vec![TeamSlug::new("maintainers")],
+        };
+
+        let task = ProposeChange::new(&github_client, &login, &repository, &change_args);
+
+        let change = task.execute().await.unwrap();
+
+        assert_eq!("fix-readme", change.changeset().branch().get());
+        assert_eq!(27, change.pull_request().number().get());
+        pull_request_mock.assert();
+        labels_mock.assert();
+        review_mock.assert();
+        team_review_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ProposeChange>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ProposeChange>();
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::{anyhow, Context};
+use base64::{decode, encode};
+use crypto_box::aead::OsRng;
+use crypto_box::PublicKey;
+use serde::Serialize;
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{
+    ActionsPublicKey, ActionsPublicKeyId, ActionsSecretName, ActionsSecretValue, Login,
+    RepositoryName,
+};
+use crate::task::GetRepositoryPublicKey;
+
+/// Create or update an Actions secret of a repository
+///
+/// Creates or updates a secret for the workflows of a repository. The task fetches the public key
+/// of the repository and encrypts the value in a libsodium sealed box, so that the plain-text value
+/// never leaves the process. The GitHub App must have the `secrets:write` permission to put
+/// secrets.
+///
+/// https://docs.github.com/en/rest/actions/secrets#create-or-update-a-repository-secret
+#[derive(Copy, Clone, Debug)]
+pub struct PutRepositorySecret<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    name: &'a ActionsSecretName,
+    value: &'a ActionsSecretValue,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct PutRepositorySecretPayload<'a> {
+    encrypted_value: String,
+    key_id: &'a ActionsPublicKeyId,
+}
+
+impl<'a> PutRepositorySecret<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        name: &'a ActionsSecretName,
+        value: &'a ActionsSecretValue,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            name,
+            value,
+        }
+    }
+
+    /// Create or update an Actions secret of a repository
+    pub async fn execute(&self) -> Result<(), Error> {
+        let public_key =
+            GetRepositoryPublicKey::new(self.github_client, self.owner, self.repository)
+                .execute()
+                .await?;
+
+        let url = format!(
+            "/repos/{}/{}/actions/secrets/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.name.get(),
+        );
+
+        let payload = PutRepositorySecretPayload {
+            encrypted_value: seal(&public_key, self.value)?,
+            key_id: public_key.key_id(),
+        };
+
+        self.github_client
+            .put::<Value>(&url, Some(payload))
+            .await
+            .context("failed to put repository secret")?;
+
+        Ok(())
+    }
+}
+
+fn seal(public_key: &ActionsPublicKey, value: &ActionsSecretValue) -> Result<String, Error> {
+    let key: [u8; 32] = decode(public_key.key())
+        .context("failed to decode Base64 encoded public key")?
+        .try_into()
+        .map_err(|_| anyhow!("public key must be 32 bytes long"))?;
+
+    let sealed = PublicKey::from(key)
+        .seal(&mut OsRng, value.expose().as_bytes())
+        .map_err(|_| anyhow!("failed to encrypt secret"))?;
+
+    Ok(encode(sealed))
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{decode, encode};
+    use crypto_box::aead::OsRng;
+    use crypto_box::SecretKey;
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{
+        ActionsPublicKey, ActionsSecretName, ActionsSecretValue, Login, RepositoryName,
+    };
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{seal, PutRepositorySecret};
+
+    #[tokio::test]
+    async fn task_puts_encrypted_secret() {
+        let _token_mock = mock_installation_access_tokens();
+        let _public_key_mock = mock("GET", "/repos/github/put-secret/actions/secrets/public-key")
+            .with_status(200)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/actions_public_key.json"
+            ))
+            .create();
+        let secret_mock = mock("PUT", "/repos/github/put-secret/actions/secrets/API_TOKEN")
+            .match_body(Matcher::PartialJsonString(
+                r#"{ "key_id": "012345678912345678" }"#.into(),
+            ))
+            .with_status(201)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("put-secret");
+        let name = ActionsSecretName::new("API_TOKEN");
+        let value = ActionsSecretValue::new("hunter2");
+
+        let task = PutRepositorySecret::new(&github_client, &login, &repository, &name, &value);
+
+        task.execute().await.unwrap();
+
+        secret_mock.assert();
+    }
+
+    #[test]
+    fn seal_encrypts_for_public_key() {
+        let secret_key = SecretKey::generate(&mut OsRng);
+        let public_key: ActionsPublicKey = serde_json::from_value(serde_json::json!({
+            "key_id": "012345678912345678",
+            "key": encode(secret_key.public_key().as_bytes()),
+        }))
+        .unwrap();
+
+        let sealed = seal(&public_key, &ActionsSecretValue::new("hunter2")).unwrap();
+
+        let value = secret_key.unseal(&decode(sealed).unwrap()).unwrap();
+        assert_eq!(b"hunter2".to_vec(), value);
+    }
+
+    #[test]
+    fn seal_rejects_invalid_key() {
+        let public_key: ActionsPublicKey = serde_json::from_value(serde_json::json!({
+            "key_id": "012345678912345678",
+            "key": encode(b"too short"),
+        }))
+        .unwrap();
+
+        assert!(seal(&public_key, &ActionsSecretValue::new("hunter2")).is_err());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PutRepositorySecret>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PutRepositorySecret>();
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::Context;
+use serde::Serialize;
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{ActionsVariableName, Login, RepositoryName};
+
+/// Create or update an Actions variable of a repository
+///
+/// GitHub's API has separate endpoints to create and to update a variable. The task updates the
+/// variable first, and creates it if it does not exist yet. The GitHub App must have the
+/// `actions_variables:write` permission to put variables.
+///
+/// https://docs.github.com/en/rest/actions/variables#create-a-repository-variable
+#[derive(Copy, Clone, Debug)]
+pub struct PutRepositoryVariable<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    name: &'a ActionsVariableName,
+    value: &'a str,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct PutRepositoryVariablePayload<'a> {
+    name: &'a ActionsVariableName,
+    value: &'a str,
+}
+
+impl<'a> PutRepositoryVariable<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        name: &'a ActionsVariableName,
+        value: &'a str,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            name,
+            value,
+        }
+    }
+
+    /// Create or update an Actions variable of a repository
+    pub async fn execute(&self) -> Result<(), Error> {
+        let url = format!(
+            "/repos/{}/{}/actions/variables",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let payload = PutRepositoryVariablePayload {
+            name: self.name,
+            value: self.value,
+        };
+
+        let updated: Result<Value, Error> = self
+            .github_client
+            .patch(&format!("{}/{}", url, self.name.get()), Some(&payload))
+            .await;
+
+        match updated {
+            Err(Error::NotFound(_)) => {
+                self.github_client
+                    .post::<Value>(&url, Some(&payload))
+                    .await
+                    .context("failed to create repository variable")?;
+            }
+            updated => {
+                updated.context("failed to update repository variable")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::resource::{ActionsVariableName, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::PutRepositoryVariable;
+
+    #[tokio::test]
+    async fn task_updates_existing_variable() {
+        let _token_mock = mock_installation_access_tokens();
+        let update_mock = mock(
+            "PATCH",
+            "/repos/github/update-variable/actions/variables/USERNAME",
+        )
+        .with_status(204)
+        .create();
+        let create_mock = mock("POST", "/repos/github/update-variable/actions/variables")
+            .with_status(201)
+            .expect(0)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("update-variable");
+        let name = ActionsVariableName::new("USERNAME");
+
+        let task =
+            PutRepositoryVariable::new(&github_client, &login, &repository, &name, "octocat");
+
+        task.execute().await.unwrap();
+
+        update_mock.assert();
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_creates_missing_variable() {
+        let _token_mock = mock_installation_access_tokens();
+        let _update_mock = mock(
+            "PATCH",
+            "/repos/github/create-variable/actions/variables/USERNAME",
+        )
+        .with_status(404)
+        .create();
+        let create_mock = mock("POST", "/repos/github/create-variable/actions/variables")
+            .with_status(201)
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("create-variable");
+        let name = ActionsVariableName::new("USERNAME");
+
+        let task =
+            PutRepositoryVariable::new(&github_client, &login, &repository, &name, "octocat");
+
+        task.execute().await.unwrap();
+
+        create_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PutRepositoryVariable>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PutRepositoryVariable>();
+    }
+}
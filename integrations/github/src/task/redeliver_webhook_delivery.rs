@@ -0,0 +1,79 @@
+use anyhow::Context;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::HookDeliveryId;
+
+/// Redeliver a delivery for the app's webhook
+///
+/// Requests GitHub to deliver an event to the GitHub App's webhook again, for example after the
+/// server that receives the webhook has recovered from an outage. The app must authenticate as
+/// itself to redeliver events, which is why the task requires a client for a GitHub App.
+///
+/// https://docs.github.com/en/rest/apps/webhooks#redeliver-a-delivery-for-an-app-webhook
+#[derive(Copy, Clone, Debug)]
+pub struct RedeliverWebhookDelivery<'a> {
+    github_client: &'a GitHubClient,
+    delivery_id: HookDeliveryId,
+}
+
+impl<'a> RedeliverWebhookDelivery<'a> {
+    /// Initializes the task
+    pub fn new(github_client: &'a GitHubClient, delivery_id: HookDeliveryId) -> Self {
+        Self {
+            github_client,
+            delivery_id,
+        }
+    }
+
+    /// Redeliver a delivery for the app's webhook
+    pub async fn execute(&self) -> Result<(), Error> {
+        let url = format!("/app/hook/deliveries/{}/attempts", self.delivery_id);
+
+        // We need to explicitly declare the type of the body somewhere to silence a compiler error.
+        let body: Option<()> = None;
+
+        self.github_client
+            .as_app()?
+            .post::<()>(&url, body)
+            .await
+            .context("failed to redeliver webhook delivery")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::HookDeliveryId;
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_redeliver_webhook_delivery;
+
+    use super::RedeliverWebhookDelivery;
+
+    #[tokio::test]
+    async fn task_redelivers_delivery() {
+        let redelivery_mock = mock_redeliver_webhook_delivery();
+
+        let github_client = github_client();
+
+        let task = RedeliverWebhookDelivery::new(&github_client, HookDeliveryId::new(12345678));
+
+        task.execute().await.unwrap();
+
+        redelivery_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RedeliverWebhookDelivery>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RedeliverWebhookDelivery>();
+    }
+}
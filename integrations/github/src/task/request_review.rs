@@ -0,0 +1,115 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, PullRequest, PullRequestNumber, RepositoryName};
+
+/// Request a review from users
+///
+/// Requests a review of a pull request from one or more users, who must be collaborators of the
+/// repository. Use the [`RequestTeamReview`](crate::task::RequestTeamReview) task to request a
+/// review from teams. The GitHub App must have the `pull_requests:write` permission to request
+/// reviews.
+///
+/// https://docs.github.com/en/rest/pulls/review-requests#request-reviewers-for-a-pull-request
+#[derive(Copy, Clone, Debug)]
+pub struct RequestReview<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    pull_request_number: PullRequestNumber,
+    reviewers: &'a [Login],
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct RequestReviewPayload<'a> {
+    reviewers: &'a [Login],
+}
+
+impl<'a> RequestReview<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        pull_request_number: PullRequestNumber,
+        reviewers: &'a [Login],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            pull_request_number,
+            reviewers,
+        }
+    }
+
+    /// Request a review from users
+    pub async fn execute(&self) -> Result<PullRequest, Error> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/requested_reviewers",
+            self.owner.get(),
+            self.repository.get(),
+            self.pull_request_number
+        );
+
+        let payload = RequestReviewPayload {
+            reviewers: self.reviewers,
+        };
+
+        let pull_request = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to request review")?;
+
+        Ok(pull_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, PullRequestNumber, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::pull_request::mock_request_review;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::RequestReview;
+
+    #[tokio::test]
+    async fn task_returns_pull_request() {
+        let _token_mock = mock_installation_access_tokens();
+        let _review_mock = mock_request_review();
+
+        let github_client = github_client();
+        let login = Login::new("octocat");
+        let repository = RepositoryName::new("Hello-World");
+        let reviewers = [Login::new("hubot")];
+
+        let task = RequestReview::new(
+            &github_client,
+            &login,
+            &repository,
+            PullRequestNumber::new(27),
+            &reviewers,
+        );
+
+        let pull_request = task.execute().await.unwrap();
+
+        assert_eq!(27, pull_request.number().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RequestReview>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RequestReview>();
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, PullRequest, PullRequestNumber, RepositoryName, TeamSlug};
+
+/// Request a review from teams
+///
+/// Requests a review of a pull request from one or more teams of the repository's organization.
+/// Teams must have at least read access to the repository. The GitHub App must have the
+/// `pull_requests:write` permission to request reviews.
+///
+/// https://docs.github.com/en/rest/pulls/review-requests#request-reviewers-for-a-pull-request
+#[derive(Copy, Clone, Debug)]
+pub struct RequestTeamReview<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    pull_request_number: PullRequestNumber,
+    teams: &'a [TeamSlug],
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct RequestTeamReviewPayload<'a> {
+    team_reviewers: &'a [TeamSlug],
+}
+
+impl<'a> RequestTeamReview<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        pull_request_number: PullRequestNumber,
+        teams: &'a [TeamSlug],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            pull_request_number,
+            teams,
+        }
+    }
+
+    /// Request a review from teams
+    pub async fn execute(&self) -> Result<PullRequest, Error> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/requested_reviewers",
+            self.owner.get(),
+            self.repository.get(),
+            self.pull_request_number
+        );
+
+        let payload = RequestTeamReviewPayload {
+            team_reviewers: self.teams,
+        };
+
+        let pull_request = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to request team review")?;
+
+        Ok(pull_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, PullRequestNumber, RepositoryName, TeamSlug};
+    use crate::testing::client::github_client;
+    use crate::testing::pull_request::mock_request_team_review;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::RequestTeamReview;
+
+    #[tokio::test]
+    async fn task_returns_pull_request() {
+        let _token_mock = mock_installation_access_tokens();
+        let _review_mock = mock_request_team_review();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let teams = [TeamSlug::new("maintainers")];
+
+        let task = RequestTeamReview::new(
+            &github_client,
+            &login,
+            &repository,
+            PullRequestNumber::new(27),
+            &teams,
+        );
+
+        let pull_request = task.execute().await.unwrap();
+
+        assert_eq!(
+            "maintainers",
+            pull_request.requested_teams()[0].slug().get()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RequestTeamReview>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RequestTeamReview>();
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::Context;
+use serde_json::Value;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Login, RepositoryName, WorkflowRunId};
+
+/// Re-run a workflow run
+///
+/// Re-runs all jobs of a workflow run, or only the jobs that failed. The GitHub App must have the
+/// `actions:write` permission to re-run workflow runs.
+///
+/// https://docs.github.com/en/rest/actions/workflow-runs#re-run-a-workflow
+#[derive(Copy, Clone, Debug)]
+pub struct RerunWorkflowRun<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    workflow_run_id: WorkflowRunId,
+    failed_jobs_only: bool,
+}
+
+impl<'a> RerunWorkflowRun<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        workflow_run_id: WorkflowRunId,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            workflow_run_id,
+            failed_jobs_only: false,
+        }
+    }
+
+    /// Only re-runs the jobs that failed, and the jobs that depend on them.
+    pub fn failed_jobs_only(mut self, failed_jobs_only: bool) -> Self {
+        self.failed_jobs_only = failed_jobs_only;
+        self
+    }
+
+    /// Re-run a workflow run
+    pub async fn execute(&self) -> Result<(), Error> {
+        let action = if self.failed_jobs_only {
+            "rerun-failed-jobs"
+        } else {
+            "rerun"
+        };
+
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.workflow_run_id,
+            action
+        );
+
+        // We need to explicitly declare the type of the body somewhere to silence a compiler error.
+        let body: Option<()> = None;
+
+        self.github_client
+            .post::<Value>(&url, body)
+            .await
+            .context("failed to re-run workflow run")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{Login, RepositoryName, WorkflowRunId};
+    use crate::testing::actions::{mock_rerun_failed_jobs, mock_rerun_workflow_run};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::RerunWorkflowRun;
+
+    #[tokio::test]
+    async fn task_reruns_workflow_run() {
+        let _token_mock = mock_installation_access_tokens();
+        let rerun_mock = mock_rerun_workflow_run();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = RerunWorkflowRun::new(
+            &github_client,
+            &login,
+            &repository,
+            WorkflowRunId::new(30433642),
+        );
+
+        task.execute().await.unwrap();
+
+        rerun_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_reruns_failed_jobs() {
+        let _token_mock = mock_installation_access_tokens();
+        let rerun_mock = mock_rerun_failed_jobs();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = RerunWorkflowRun::new(
+            &github_client,
+            &login,
+            &repository,
+            WorkflowRunId::new(30433642),
+        )
+        .failed_jobs_only(true);
+
+        task.execute().await.unwrap();
+
+        rerun_mock.assert();
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RerunWorkflowRun>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<RerunWorkflowRun>();
+    }
+}
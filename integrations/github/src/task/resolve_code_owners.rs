@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{CodeOwner, CodeOwners, GitRef, Login, RepositoryName};
+use crate::task::GetFile;
+
+/// Locations of the `CODEOWNERS` file in the order in which GitHub looks for it
+const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Resolve the code owners of a set of paths
+///
+/// Reads the `CODEOWNERS` file from the default branch of a repository, and returns the owners of
+/// each of the given paths. Automatons that review pull requests should read the file from the
+/// base branch of the pull request instead, since GitHub uses that file to request reviews. GitHub looks for the file in the `.github/`, root, and `docs/`
+/// directories, and uses the first one that it finds. If the repository does not have a
+/// `CODEOWNERS` file, none of the paths have owners.
+///
+/// https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners
+#[derive(Copy, Clone, Debug)]
+pub struct ResolveCodeOwners<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    paths: &'a [String],
+    git_ref: Option<&'a GitRef>,
+}
+
+impl<'a> ResolveCodeOwners<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        paths: &'a [String],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            paths,
+            git_ref: None,
+        }
+    }
+
+    /// Reads the `CODEOWNERS` file at the given branch, tag, or commit, e.g. the base branch of a
+    /// pull request.
+    pub fn git_ref(mut self, git_ref: &'a GitRef) -> Self {
+        self.git_ref = Some(git_ref);
+        self
+    }
+
+    /// Resolve the code owners of a set of paths
+    pub async fn execute(&self) -> Result<BTreeMap<String, Vec<CodeOwner>>, Error> {
+        let code_owners = self.code_owners().await?;
+
+        let owners = self
+            .paths
+            .iter()
+            .map(|path| (path.clone(), code_owners.owners(path).to_vec()))
+            .collect();
+
+        Ok(owners)
+    }
+
+    async fn code_owners(&self) -> Result<CodeOwners, Error> {
+        for location in LOCATIONS {
+            let mut task = GetFile::new(self.github_client, self.owner, self.repository, location);
+            if let Some(git_ref) = self.git_ref {
+                task = task.git_ref(git_ref);
+            }
+
+            let file = match task.execute().await {
+                Ok(file) => file,
+                Err(Error::NotFound(_)) => continue,
+                Err(error) => return Err(error),
+            };
+
+            let content = String::from_utf8_lossy(file.content());
+
+            return Ok(CodeOwners::parse(&content));
+        }
+
+        Ok(CodeOwners::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::resource::{GitRef, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::ResolveCodeOwners;
+
+    #[tokio::test]
+    async fn task_returns_owners() {
+        let _token_mock = mock_installation_access_tokens();
+        let _github_mock = mock(
+            "GET",
+            "/repos/devxbots/codeowners/contents/.github/CODEOWNERS",
+        )
+        .with_status(404)
+        .with_body(r#"{ "message": "Not Found" }"#)
+        .create();
+        let _root_mock = mock("GET", "/repos/devxbots/codeowners/contents/CODEOWNERS")
+            .with_status(200)
+            .with_body(
+                r#"
+                {
+                  "type": "file",
+                  "encoding": "base64",
+                  "size": 34,
+                  "name": "CODEOWNERS",
+                  "path": "CODEOWNERS",
+                  "content": "KiBAZGV2eGJvdHMKKi5ycyBAZGV2eGJvdHMvcnVzdAo=",
+                  "sha": "0c4b1e7ba5b5d2c3ad0f0ffb1e4a6a4bbba6ec25",
+                  "url": "https://api.github.com/repos/devxbots/codeowners/contents/CODEOWNERS",
+                  "git_url": "https://api.github.com/repos/devxbots/codeowners/git/blobs/0c4b1e7ba5b5d2c3ad0f0ffb1e4a6a4bbba6ec25",
+                  "html_url": "https://github.com/devxbots/codeowners/blob/main/CODEOWNERS",
+                  "download_url": "https://raw.githubusercontent.com/devxbots/codeowners/main/CODEOWNERS"
+                }
+                "#,
+            )
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("codeowners");
+        let paths = vec!["README.md".to_string(), "src/lib.rs".to_string()];
+
+        let task = ResolveCodeOwners::new(&github_client, &login, &repository, &paths);
+
+        let owners = task.execute().await.unwrap();
+
+        assert_eq!("@devxbots", owners["README.md"][0].get());
+        assert_eq!("@devxbots/rust", owners["src/lib.rs"][0].get());
+    }
+
+    #[tokio::test]
+    async fn task_reads_codeowners_at_git_ref() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock(
+            "GET",
+            "/repos/devxbots/codeowners-ref/contents/.github/CODEOWNERS",
+        )
+        .match_query(Matcher::UrlEncoded("ref".into(), "release".into()))
+        .with_status(200)
+        .with_body(
+            r#"
+            {
+              "type": "file",
+              "encoding": "base64",
+              "size": 12,
+              "name": "CODEOWNERS",
+              "path": ".github/CODEOWNERS",
+              "content": "KiBAcmVsZWFzZQo=",
+              "sha": "7d1d5a8a6f0a2e5b3c9b1d3e4f5a6b7c8d9e0f1a",
+              "url": "https://api.github.com/repos/devxbots/codeowners-ref/contents/.github/CODEOWNERS?ref=release",
+              "git_url": "https://api.github.com/repos/devxbots/codeowners-ref/git/blobs/7d1d5a8a6f0a2e5b3c9b1d3e4f5a6b7c8d9e0f1a",
+              "html_url": "https://github.com/devxbots/codeowners-ref/blob/release/.github/CODEOWNERS",
+              "download_url": "https://raw.githubusercontent.com/devxbots/codeowners-ref/release/.github/CODEOWNERS"
+            }
+            "#,
+        )
+        .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("codeowners-ref");
+        let paths = vec!["README.md".to_string()];
+        let git_ref = GitRef::new("release");
+
+        let task =
+            ResolveCodeOwners::new(&github_client, &login, &repository, &paths).git_ref(&git_ref);
+
+        let owners = task.execute().await.unwrap();
+
+        assert_eq!("@release", owners["README.md"][0].get());
+    }
+
+    #[tokio::test]
+    async fn task_without_codeowners() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/repos/devxbots/no-codeowners/contents/.*$".into()),
+        )
+        .with_status(404)
+        .with_body(r#"{ "message": "Not Found" }"#)
+        .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("no-codeowners");
+        let paths = vec!["README.md".to_string()];
+
+        let task = ResolveCodeOwners::new(&github_client, &login, &repository, &paths);
+
+        let owners = task.execute().await.unwrap();
+
+        assert!(owners["README.md"].is_empty());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ResolveCodeOwners>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ResolveCodeOwners>();
+    }
+}
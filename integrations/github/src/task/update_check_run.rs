@@ -9,13 +9,24 @@ use crate::client::GitHubClient;
 use crate::resource::{
     CheckRun, CheckRunConclusion, CheckRunId, CheckRunName, CheckRunStatus, Login, RepositoryName,
 };
-use crate::task::CheckRunOutputArgs;
+use crate::task::{CheckRunOutputArgs, GetCheckRun};
 
 /// Update a check run
 ///
 /// Updates a check run for a specific commit in a repository. The GitHub App must have the
 /// `checks:write` permission to edit check runs.
 ///
+/// # Concurrency
+///
+/// GitHub has no conditional requests for check runs, so two automatons that handle the same
+/// event, e.g. a duplicate delivery, can overwrite each other's updates. When the task is given a
+/// [`CheckRunPrecondition`], it first reads the check run and returns [`Error::Conflict`] if the
+/// check run has moved on. This narrows the window for races, but does not close it.
+///
+/// Automatons that need stronger guarantees can use the check run's `external_id` as a version:
+/// every update sets a new `external_id`, and requires the one that it has last seen in its
+/// precondition. Concurrent writers then detect each other's updates on their next write.
+///
 /// https://docs.github.com/en/rest/checks/runs#update-a-check-run
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateCheckRun<'a> {
@@ -23,6 +34,50 @@ pub struct UpdateCheckRun<'a> {
     owner: &'a Login,
     repository: &'a RepositoryName,
     check_run_args: &'a UpdateCheckRunArgs,
+    precondition: Option<&'a CheckRunPrecondition>,
+}
+
+/// Precondition for the update check run task
+///
+/// The precondition describes the state in which the check run is expected to be. The check run
+/// is only updated if it matches all fields that are set.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct CheckRunPrecondition {
+    /// The `external_id` that the check run must still have.
+    pub external_id: Option<String>,
+
+    /// The most advanced status that the check run may have. For example, a check run that has
+    /// completed is not updated when the precondition expects it to be in progress.
+    pub status: Option<CheckRunStatus>,
+}
+
+impl CheckRunPrecondition {
+    /// Returns a description of the conflict if the check run does not match the precondition.
+    fn conflict(&self, check_run: &CheckRun) -> Option<String> {
+        if let Some(external_id) = &self.external_id {
+            if external_id != check_run.external_id() {
+                return Some(format!(
+                    "expected check run {} to have external id {} but found {}",
+                    check_run.id(),
+                    external_id,
+                    check_run.external_id()
+                ));
+            }
+        }
+
+        if let Some(status) = self.status {
+            if check_run.status() > status {
+                return Some(format!(
+                    "expected check run {} to be at most {} but it is {}",
+                    check_run.id(),
+                    status,
+                    check_run.status()
+                ));
+            }
+        }
+
+        None
+    }
 }
 
 /// Input for update check run task
@@ -87,9 +142,16 @@ impl<'a> UpdateCheckRun<'a> {
             owner,
             repository,
             check_run_args: check_run_input,
+            precondition: None,
         }
     }
 
+    /// Only updates the check run if it matches the given precondition.
+    pub fn precondition(mut self, precondition: &'a CheckRunPrecondition) -> Self {
+        self.precondition = Some(precondition);
+        self
+    }
+
     /// Update a check run
     ///
     /// Updates a check run for a specific commit in a repository.
@@ -101,9 +163,29 @@ impl<'a> UpdateCheckRun<'a> {
             self.check_run_args.check_run_id
         );
 
+        if let Some(precondition) = self.precondition {
+            let check_run = GetCheckRun::new(
+                self.github_client,
+                self.owner,
+                self.repository,
+                self.check_run_args.check_run_id,
+            )
+            .execute()
+            .await?;
+
+            if let Some(conflict) = precondition.conflict(&check_run) {
+                return Err(Error::Conflict(conflict));
+            }
+        }
+
+        let mut check_run_args = self.check_run_args.clone();
+        if let Some(output) = &mut check_run_args.output {
+            output.enforce_limit()?;
+        }
+
         let check_run = self
             .github_client
-            .patch(&url, Some(self.check_run_args))
+            .patch(&url, Some(&check_run_args))
             .await
             .context("failed to update check run")?;
 
@@ -113,12 +195,16 @@ impl<'a> UpdateCheckRun<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::resource::{CheckRunId, CheckRunName, Login, RepositoryName};
-    use crate::testing::check_run::mock_update_check_run;
+    use automatons::Error;
+
+    use crate::resource::{CheckRunId, CheckRunName, CheckRunStatus, Login, RepositoryName};
+    use crate::testing::check_run::{
+        mock_get_check_run, mock_update_check_run, mock_update_completed_check_run,
+    };
     use crate::testing::client::github_client;
     use crate::testing::token::mock_installation_access_tokens;
 
-    use super::{UpdateCheckRun, UpdateCheckRunArgs};
+    use super::{CheckRunPrecondition, UpdateCheckRun, UpdateCheckRunArgs};
 
     fn input() -> UpdateCheckRunArgs {
         UpdateCheckRunArgs {
@@ -151,6 +237,60 @@ mod tests {
         assert_eq!("mighty_readme", check_run.name().get());
     }
 
+    async fn update_with_precondition(precondition: CheckRunPrecondition) -> Result<(), Error> {
+        let _token_mock = mock_installation_access_tokens();
+        let _get_mock = mock_get_check_run();
+        let _update_mock = mock_update_completed_check_run();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let input = UpdateCheckRunArgs {
+            check_run_id: CheckRunId::new(7669942377),
+            external_id: Some("a8f4d1e0".into()),
+            ..input()
+        };
+
+        UpdateCheckRun::new(&github_client, &login, &repository, &input)
+            .precondition(&precondition)
+            .execute()
+            .await
+            .map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn task_updates_check_run_that_matches_precondition() {
+        let result = update_with_precondition(CheckRunPrecondition {
+            external_id: Some("5264e576-3c6f-51f6-f055-fab409685f20".into()),
+            status: Some(CheckRunStatus::Completed),
+        })
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn task_rejects_changed_external_id() {
+        let result = update_with_precondition(CheckRunPrecondition {
+            external_id: Some("42".into()),
+            status: None,
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn task_rejects_advanced_status() {
+        let result = update_with_precondition(CheckRunPrecondition {
+            external_id: None,
+            status: Some(CheckRunStatus::InProgress),
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::Conflict(_))));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
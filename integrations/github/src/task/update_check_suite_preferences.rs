@@ -0,0 +1,130 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{AppId, CheckSuitePreferences, Login, RepositoryName};
+
+/// Update the check suite preferences of a repository
+///
+/// Changes whether GitHub automatically creates check suites for apps when code is pushed to a
+/// repository. Apps that disable the automatic creation must create check suites themselves with
+/// the [`CreateCheckSuite`](crate::task::CreateCheckSuite) task. The GitHub App must have admin
+/// permissions on the repository to change its preferences.
+///
+/// https://docs.github.com/en/rest/checks/suites#update-repository-preferences-for-check-suites
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateCheckSuitePreferences<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    auto_trigger_checks: &'a [AutoTriggerCheckArgs],
+}
+
+/// Input for the automatic creation of check suites
+///
+/// https://docs.github.com/en/rest/checks/suites#update-repository-preferences-for-check-suites
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct AutoTriggerCheckArgs {
+    /// The id of the app.
+    pub app_id: AppId,
+
+    /// Whether check suites are created automatically for the app.
+    pub setting: bool,
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct UpdateCheckSuitePreferencesPayload<'a> {
+    auto_trigger_checks: &'a [AutoTriggerCheckArgs],
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct UpdateCheckSuitePreferencesResponse {
+    preferences: CheckSuitePreferences,
+}
+
+impl<'a> UpdateCheckSuitePreferences<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        auto_trigger_checks: &'a [AutoTriggerCheckArgs],
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            auto_trigger_checks,
+        }
+    }
+
+    /// Update the check suite preferences of a repository
+    pub async fn execute(&self) -> Result<CheckSuitePreferences, Error> {
+        let url = format!(
+            "/repos/{}/{}/check-suites/preferences",
+            self.owner.get(),
+            self.repository.get()
+        );
+
+        let payload = UpdateCheckSuitePreferencesPayload {
+            auto_trigger_checks: self.auto_trigger_checks,
+        };
+
+        let response: UpdateCheckSuitePreferencesResponse = self
+            .github_client
+            .patch(&url, Some(payload))
+            .await
+            .context("failed to update check suite preferences")?;
+
+        Ok(response.preferences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{AppId, Login, RepositoryName};
+    use crate::testing::check_suite::mock_update_check_suite_preferences;
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{AutoTriggerCheckArgs, UpdateCheckSuitePreferences};
+
+    #[tokio::test]
+    async fn task_returns_preferences() {
+        let _token_mock = mock_installation_access_tokens();
+        let _preferences_mock = mock_update_check_suite_preferences();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+        let auto_trigger_checks = [AutoTriggerCheckArgs {
+            app_id: AppId::new(202198),
+            setting: false,
+        }];
+
+        let task = UpdateCheckSuitePreferences::new(
+            &github_client,
+            &login,
+            &repository,
+            &auto_trigger_checks,
+        );
+
+        let preferences = task.execute().await.unwrap();
+
+        assert!(!preferences.is_auto_triggered(AppId::new(202198)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpdateCheckSuitePreferences>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpdateCheckSuitePreferences>();
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{
+    DependabotAlert, DependabotAlertDismissedReason, DependabotAlertNumber, DependabotAlertState,
+    Login, RepositoryName,
+};
+
+/// Update a Dependabot alert
+///
+/// Dismisses or reopens a Dependabot alert in a repository. GitHub Apps must have the
+/// `dependabot_alerts:write` permission to update alerts.
+///
+/// https://docs.github.com/en/rest/dependabot/alerts#update-a-dependabot-alert
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateDependabotAlert<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    alert_args: &'a UpdateDependabotAlertArgs,
+}
+
+/// Input for update Dependabot alert task
+///
+/// The input for the task that updates a Dependabot alert represents the different parameters that
+/// GitHub's API accepts.
+///
+/// https://docs.github.com/en/rest/dependabot/alerts#update-a-dependabot-alert
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct UpdateDependabotAlertArgs {
+    /// The number of the alert.
+    #[serde(skip_serializing)]
+    pub alert_number: DependabotAlertNumber,
+
+    /// The new state of the alert. Only `dismissed` and `open` are accepted by GitHub.
+    pub state: DependabotAlertState,
+
+    /// The reason for dismissing the alert. Required when `state` is `dismissed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<DependabotAlertDismissedReason>,
+
+    /// An optional comment associated with dismissing the alert.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissed_comment: Option<String>,
+}
+
+impl<'a> UpdateDependabotAlert<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        alert_args: &'a UpdateDependabotAlertArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            alert_args,
+        }
+    }
+
+    /// Update a Dependabot alert
+    pub async fn execute(&self) -> Result<DependabotAlert, Error> {
+        let url = format!(
+            "/repos/{}/{}/dependabot/alerts/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.alert_args.alert_number
+        );
+
+        let alert = self
+            .github_client
+            .patch(&url, Some(self.alert_args))
+            .await
+            .context("failed to update dependabot alert")?;
+
+        Ok(alert)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{
+        DependabotAlertDismissedReason, DependabotAlertNumber, DependabotAlertState, Login,
+        RepositoryName,
+    };
+    use crate::testing::client::github_client;
+    use crate::testing::dependabot_alert::mock_update_dependabot_alert;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{UpdateDependabotAlert, UpdateDependabotAlertArgs};
+
+    fn input() -> UpdateDependabotAlertArgs {
+        UpdateDependabotAlertArgs {
+            alert_number: DependabotAlertNumber::new(2),
+            state: DependabotAlertState::Dismissed,
+            dismissed_reason: Some(DependabotAlertDismissedReason::TolerableRisk),
+            dismissed_comment: Some("This alert is accurate but we use a sanitizer.".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_updated_dependabot_alert() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_update_dependabot_alert();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let input = input();
+
+        let task = UpdateDependabotAlert::new(&github_client, &login, &repository, &input);
+
+        let alert = task.execute().await.unwrap();
+
+        assert!(matches!(alert.state(), DependabotAlertState::Dismissed));
+    }
+
+    #[test]
+    fn serialize_skips_alert_number() {
+        let json = serde_json::to_string(&input()).unwrap();
+
+        assert!(!json.contains("alert_number"));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpdateDependabotAlert>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpdateDependabotAlert>();
+    }
+}
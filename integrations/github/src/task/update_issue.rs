@@ -0,0 +1,150 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Issue, IssueNumber, IssueState, IssueStateReason, Login, RepositoryName};
+
+/// Update an issue
+///
+/// Updates the title, body, state, or assignees of an issue. Issues are closed by setting their
+/// state to `closed`, optionally with a reason. The GitHub App must have the `issues:write`
+/// permission to update issues.
+///
+/// https://docs.github.com/en/rest/issues/issues#update-an-issue
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateIssue<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_args: &'a UpdateIssueArgs,
+}
+
+/// Input for update issue task
+///
+/// The input for the task that updates an issue represents the different parameters that GitHub's
+/// API accepts. Fields that are not set are not changed.
+///
+/// https://docs.github.com/en/rest/issues/issues#update-an-issue
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct UpdateIssueArgs {
+    /// The number of the issue.
+    #[serde(skip)]
+    pub issue_number: IssueNumber,
+
+    /// The title of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The contents of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The state of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueState>,
+
+    /// The reason for the state change, which is ignored unless the state changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_reason: Option<IssueStateReason>,
+
+    /// The logins of the users to assign to the issue. An empty list removes all assignees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<Login>>,
+}
+
+impl<'a> UpdateIssue<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_args: &'a UpdateIssueArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_args,
+        }
+    }
+
+    /// Update an issue
+    pub async fn execute(&self) -> Result<Issue, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.issue_args.issue_number
+        );
+
+        let issue = self
+            .github_client
+            .patch(&url, Some(self.issue_args))
+            .await
+            .context("failed to update issue")?;
+
+        Ok(issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueNumber, IssueState, IssueStateReason, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_update_issue;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{UpdateIssue, UpdateIssueArgs};
+
+    fn input() -> UpdateIssueArgs {
+        UpdateIssueArgs {
+            issue_number: IssueNumber::new(1347),
+            title: None,
+            body: None,
+            state: Some(IssueState::Closed),
+            state_reason: Some(IssueStateReason::NotPlanned),
+            assignees: Some(Vec::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn task_returns_issue() {
+        let _token_mock = mock_installation_access_tokens();
+        let _issue_mock = mock_update_issue();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let input = input();
+
+        let task = UpdateIssue::new(&github_client, &login, &repository, &input);
+
+        let issue = task.execute().await.unwrap();
+
+        assert_eq!(1347, issue.number().get());
+    }
+
+    #[test]
+    fn args_serialize_changed_fields() {
+        let json = serde_json::to_string(&input()).unwrap();
+
+        assert_eq!(
+            r#"{"state":"closed","state_reason":"not_planned","assignees":[]}"#,
+            json
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpdateIssue>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpdateIssue>();
+    }
+}
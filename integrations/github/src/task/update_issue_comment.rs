@@ -0,0 +1,109 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{IssueComment, IssueCommentId, Login, RepositoryName};
+
+/// Update a comment on an issue
+///
+/// Replaces the body of a comment on an issue or pull request. GitHub Apps must have the
+/// `issues:write` permission to update a comment on an issue, and the `pull_requests:write`
+/// permission to update a comment on a pull request.
+///
+/// https://docs.github.com/en/rest/issues/comments#update-an-issue-comment
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateIssueComment<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    comment_id: IssueCommentId,
+    body: &'a str,
+}
+
+#[derive(Copy, Clone, Debug, Serialize)]
+struct UpdateIssueCommentPayload<'a> {
+    body: &'a str,
+}
+
+impl<'a> UpdateIssueComment<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        comment_id: IssueCommentId,
+        body: &'a str,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            comment_id,
+            body,
+        }
+    }
+
+    /// Update a comment on an issue
+    pub async fn execute(&self) -> Result<IssueComment, Error> {
+        let url = format!(
+            "/repos/{}/{}/issues/comments/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.comment_id
+        );
+
+        let comment = self
+            .github_client
+            .patch(&url, Some(UpdateIssueCommentPayload { body: self.body }))
+            .await
+            .context("failed to update issue comment")?;
+
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{IssueCommentId, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::issue::mock_update_issue_comment;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::UpdateIssueComment;
+
+    #[tokio::test]
+    async fn task_returns_comment() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_update_issue_comment();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+
+        let task = UpdateIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueCommentId::new(1),
+            "Me too",
+        );
+
+        let comment = task.execute().await.unwrap();
+
+        assert_eq!(1, comment.id().get());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpdateIssueComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpdateIssueComment>();
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::Context;
+use serde::Serialize;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{Hook, HookId, Login, RepositoryName};
+use crate::task::HookConfigArgs;
+
+/// Update a repository webhook
+///
+/// Updates the configuration, events, or state of a repository webhook. The GitHub App must have
+/// the `repository_hooks:write` permission to update webhooks.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateRepositoryWebhook<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    webhook_args: &'a UpdateRepositoryWebhookArgs,
+}
+
+/// Input for update repository webhook task
+///
+/// The input for the task that updates a repository webhook represents the different parameters
+/// that GitHub's API accepts.
+///
+/// https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateRepositoryWebhookArgs {
+    /// The unique identifier of the webhook.
+    #[serde(skip)]
+    pub hook_id: HookId,
+
+    /// The configuration of the webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<HookConfigArgs>,
+
+    /// The events that trigger the webhook. This replaces the existing events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+
+    /// The events that are added to the existing events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_events: Option<Vec<String>>,
+
+    /// The events that are removed from the existing events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_events: Option<Vec<String>>,
+
+    /// Whether the webhook is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+impl<'a> UpdateRepositoryWebhook<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        webhook_args: &'a UpdateRepositoryWebhookArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            webhook_args,
+        }
+    }
+
+    /// Update a repository webhook
+    pub async fn execute(&self) -> Result<Hook, Error> {
+        let url = format!(
+            "/repos/{}/{}/hooks/{}",
+            self.owner.get(),
+            self.repository.get(),
+            self.webhook_args.hook_id
+        );
+
+        let hook = self
+            .github_client
+            .patch(&url, Some(self.webhook_args))
+            .await
+            .context("failed to update repository webhook")?;
+
+        Ok(hook)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{HookId, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::hook::mock_update_repository_webhook;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{UpdateRepositoryWebhook, UpdateRepositoryWebhookArgs};
+
+    #[tokio::test]
+    async fn task_returns_hook() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_update_repository_webhook();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let webhook_args = UpdateRepositoryWebhookArgs {
+            hook_id: HookId::new(12345678),
+            config: None,
+            events: None,
+            add_events: Some(vec!["pull_request".into()]),
+            remove_events: None,
+            active: Some(true),
+        };
+
+        let task = UpdateRepositoryWebhook::new(&github_client, &login, &repository, &webhook_args);
+
+        let hook = task.execute().await.unwrap();
+
+        assert!(hook.active());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpdateRepositoryWebhook>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpdateRepositoryWebhook>();
+    }
+}
@@ -0,0 +1,191 @@
+use std::io::Write;
+
+use anyhow::Context;
+use base64::encode;
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use url::Url;
+
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{GitRef, GitSha, Login, RepositoryName, SarifUpload};
+
+/// Upload an analysis as SARIF data
+///
+/// Uploads the results of a static analysis in the SARIF format to code scanning, which creates
+/// code scanning alerts for the problems that were found. GitHub Apps must have the
+/// `security_events:write` permission to upload SARIF data.
+///
+/// GitHub requires the SARIF data to be compressed with gzip and encoded with Base64. The task
+/// takes care of this, and expects the raw SARIF document as its input.
+///
+/// https://docs.github.com/en/rest/code-scanning#upload-an-analysis-as-sarif-data
+#[derive(Copy, Clone, Debug)]
+pub struct UploadSarif<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    sarif_args: &'a UploadSarifArgs,
+}
+
+/// Input for upload SARIF task
+///
+/// The input for the task that uploads SARIF data represents the different parameters that GitHub's
+/// API accepts.
+///
+/// https://docs.github.com/en/rest/code-scanning#upload-an-analysis-as-sarif-data
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct UploadSarifArgs {
+    /// The SHA of the commit to which the analysis belongs.
+    pub commit_sha: GitSha,
+
+    /// The full Git reference, for example `refs/heads/main`.
+    pub git_ref: GitRef,
+
+    /// The uncompressed SARIF document.
+    pub sarif: Vec<u8>,
+
+    /// The base directory used in the analysis, as it appears in the SARIF file.
+    pub checkout_uri: Option<Url>,
+
+    /// The time that the analysis run began.
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// The name of the tool used to generate the code scanning analysis.
+    pub tool_name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct UploadSarifPayload<'a> {
+    commit_sha: &'a GitSha,
+    #[serde(rename = "ref")]
+    git_ref: &'a GitRef,
+    sarif: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checkout_uri: &'a Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: &'a Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_name: &'a Option<String>,
+}
+
+impl<'a> UploadSarif<'a> {
+    /// Initializes the task
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        sarif_args: &'a UploadSarifArgs,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            sarif_args,
+        }
+    }
+
+    /// Upload an analysis as SARIF data
+    pub async fn execute(&self) -> Result<SarifUpload, Error> {
+        let url = format!(
+            "/repos/{}/{}/code-scanning/sarifs",
+            self.owner.get(),
+            self.repository.get(),
+        );
+
+        let payload = UploadSarifPayload {
+            commit_sha: &self.sarif_args.commit_sha,
+            git_ref: &self.sarif_args.git_ref,
+            sarif: compress_and_encode(&self.sarif_args.sarif)?,
+            checkout_uri: &self.sarif_args.checkout_uri,
+            started_at: &self.sarif_args.started_at,
+            tool_name: &self.sarif_args.tool_name,
+        };
+
+        let upload = self
+            .github_client
+            .post(&url, Some(payload))
+            .await
+            .context("failed to upload sarif data")?;
+
+        Ok(upload)
+    }
+}
+
+fn compress_and_encode(sarif: &[u8]) -> Result<String, Error> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+
+    encoder
+        .write_all(sarif)
+        .context("failed to compress sarif data")?;
+    let compressed = encoder.finish().context("failed to compress sarif data")?;
+
+    Ok(encode(compressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+
+    use crate::resource::{GitRef, GitSha, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::code_scanning::mock_upload_sarif;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::{compress_and_encode, UploadSarif, UploadSarifArgs};
+
+    const SARIF: &str = r#"{"version":"2.1.0","runs":[]}"#;
+
+    #[tokio::test]
+    async fn task_returns_sarif_upload() {
+        let _token_mock = mock_installation_access_tokens();
+        let _content_mock = mock_upload_sarif();
+
+        let github_client = github_client();
+        let login = Login::new("github");
+        let repository = RepositoryName::new("hello-world");
+        let input = UploadSarifArgs {
+            commit_sha: GitSha::new("4b6472266afd7b471e86085a6659e8c7f2b119da"),
+            git_ref: GitRef::new("refs/heads/main"),
+            sarif: SARIF.as_bytes().to_vec(),
+            checkout_uri: None,
+            started_at: None,
+            tool_name: Some("clippy".into()),
+        };
+
+        let task = UploadSarif::new(&github_client, &login, &repository, &input);
+
+        let upload = task.execute().await.unwrap();
+
+        assert_eq!("47177e22-5596-11eb-80a1-c1e54ef945c6", upload.id().get());
+    }
+
+    #[test]
+    fn compress_and_encode_roundtrips() {
+        let encoded = compress_and_encode(SARIF.as_bytes()).unwrap();
+
+        let compressed = base64::decode(encoded).unwrap();
+        let mut decoder = GzDecoder::new(compressed.as_slice());
+        let mut sarif = String::new();
+        decoder.read_to_string(&mut sarif).unwrap();
+
+        assert_eq!(SARIF, sarif);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UploadSarif>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UploadSarif>();
+    }
+}
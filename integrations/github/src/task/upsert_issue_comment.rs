@@ -0,0 +1,301 @@
+use automatons::Error;
+
+use crate::client::GitHubClient;
+use crate::resource::{AccountType, IssueComment, IssueNumber, Login, RepositoryName};
+use crate::task::{CreateIssueComment, ListIssueComments, UpdateIssueComment};
+
+/// Create or update a comment on an issue
+///
+/// Automatons often report their results as a comment on a pull request, and update the report
+/// whenever they run again. Instead of posting a new comment every time, the task marks its comment
+/// with a hidden HTML comment, e.g. `<!-- automatons:coverage -->`, and edits the existing comment
+/// with the same marker if there is one. The marker is added to the body of the comment.
+///
+/// Only comments by a bot are updated, so that the task does not edit a comment in which a user
+/// quoted the marker. Set the [`author`](UpsertIssueComment::author) to only update comments by the
+/// app's own bot account.
+///
+/// GitHub Apps must have the `issues:write` permission to comment on an issue, and the
+/// `pull_requests:write` permission to comment on a pull request.
+#[derive(Copy, Clone, Debug)]
+pub struct UpsertIssueComment<'a> {
+    github_client: &'a GitHubClient,
+    owner: &'a Login,
+    repository: &'a RepositoryName,
+    issue_number: IssueNumber,
+    key: &'a str,
+    body: &'a str,
+    author: Option<&'a Login>,
+}
+
+impl<'a> UpsertIssueComment<'a> {
+    /// Initializes the task
+    ///
+    /// The `key` identifies the comment on the issue, and is used to build its marker.
+    pub fn new(
+        github_client: &'a GitHubClient,
+        owner: &'a Login,
+        repository: &'a RepositoryName,
+        issue_number: IssueNumber,
+        key: &'a str,
+        body: &'a str,
+    ) -> Self {
+        Self {
+            github_client,
+            owner,
+            repository,
+            issue_number,
+            key,
+            body,
+            author: None,
+        }
+    }
+
+    /// Only updates comments by the given account, e.g. `my-app[bot]`.
+    pub fn author(mut self, author: &'a Login) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Returns the hidden marker that identifies the comment.
+    pub fn marker(&self) -> String {
+        format!("<!-- automatons:{} -->", self.key)
+    }
+
+    /// Create or update a comment on an issue
+    pub async fn execute(&self) -> Result<IssueComment, Error> {
+        let marker = self.marker();
+        let body = format!("{}\n{}", marker, self.body);
+
+        let comments = ListIssueComments::new(
+            self.github_client,
+            self.owner,
+            self.repository,
+            self.issue_number,
+        )
+        .execute()
+        .await?;
+
+        let existing_comment = comments.iter().find(|comment| {
+            let user = comment.user();
+
+            user.account_type() == AccountType::Bot
+                && self.author.is_none_or(|author| user.login() == author)
+                && comment
+                    .body()
+                    .as_ref()
+                    .map(|body| body.contains(&marker))
+                    .unwrap_or(false)
+        });
+
+        match existing_comment {
+            Some(comment) => {
+                UpdateIssueComment::new(
+                    self.github_client,
+                    self.owner,
+                    self.repository,
+                    comment.id(),
+                    &body,
+                )
+                .execute()
+                .await
+            }
+            None => {
+                CreateIssueComment::new(
+                    self.github_client,
+                    self.owner,
+                    self.repository,
+                    self.issue_number,
+                    &body,
+                )
+                .execute()
+                .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher, Mock};
+    use serde_json::Value;
+
+    use crate::resource::{IssueNumber, Login, RepositoryName};
+    use crate::testing::client::github_client;
+    use crate::testing::token::mock_installation_access_tokens;
+
+    use super::UpsertIssueComment;
+
+    fn mock_comments(repository: &str, account_type: &str, login: &str) -> Mock {
+        let mut comment: Value = serde_json::from_str(include_str!(
+            "../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .unwrap();
+        comment["user"]["type"] = account_type.into();
+        comment["user"]["login"] = login.into();
+
+        mock(
+            "GET",
+            &*format!("/repos/devxbots/{}/issues/1/comments", repository),
+        )
+        .with_status(200)
+        .with_body(format!("[{}]", comment))
+        .create()
+    }
+
+    fn mock_create_comment(repository: &str) -> Mock {
+        mock(
+            "POST",
+            &*format!("/repos/devxbots/{}/issues/1/comments", repository),
+        )
+        .with_status(201)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .create()
+    }
+
+    #[tokio::test]
+    async fn task_updates_existing_comment() {
+        let _token_mock = mock_installation_access_tokens();
+        let _list_mock = mock_comments("sticky-update", "Bot", "automatons[bot]");
+        let update_mock = mock("PATCH", "/repos/devxbots/sticky-update/issues/comments/1")
+            .match_body(Matcher::JsonString(
+                r#"{ "body": "<!-- automatons:coverage -->\nCoverage: 90%" }"#.into(),
+            ))
+            .with_status(200)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/issue_comment.json"
+            ))
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("sticky-update");
+
+        let task = UpsertIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1),
+            "coverage",
+            "Coverage: 90%",
+        );
+
+        task.execute().await.unwrap();
+
+        update_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_creates_new_comment() {
+        let _token_mock = mock_installation_access_tokens();
+        let _list_mock = mock_comments("sticky-create", "Bot", "automatons[bot]");
+        let create_mock = mock("POST", "/repos/devxbots/sticky-create/issues/1/comments")
+            .match_body(Matcher::JsonString(
+                r#"{ "body": "<!-- automatons:lint -->\nNo warnings" }"#.into(),
+            ))
+            .with_status(201)
+            .with_body(include_str!(
+                "../../tests/fixtures/resource/issue_comment.json"
+            ))
+            .create();
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("sticky-create");
+
+        let task = UpsertIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1),
+            "lint",
+            "No warnings",
+        );
+
+        task.execute().await.unwrap();
+
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_ignores_comment_by_user() {
+        let _token_mock = mock_installation_access_tokens();
+        let _list_mock = mock_comments("sticky-user", "User", "octocat");
+        let create_mock = mock_create_comment("sticky-user");
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("sticky-user");
+
+        let task = UpsertIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1),
+            "coverage",
+            "Coverage: 90%",
+        );
+
+        task.execute().await.unwrap();
+
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn task_ignores_comment_by_other_bot() {
+        let _token_mock = mock_installation_access_tokens();
+        let _list_mock = mock_comments("sticky-bot", "Bot", "dependabot[bot]");
+        let create_mock = mock_create_comment("sticky-bot");
+
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("sticky-bot");
+        let author = Login::new("automatons[bot]");
+
+        let task = UpsertIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1),
+            "coverage",
+            "Coverage: 90%",
+        )
+        .author(&author);
+
+        task.execute().await.unwrap();
+
+        create_mock.assert();
+    }
+
+    #[test]
+    fn marker_contains_key() {
+        let github_client = github_client();
+        let login = Login::new("devxbots");
+        let repository = RepositoryName::new("automatons");
+
+        let task = UpsertIssueComment::new(
+            &github_client,
+            &login,
+            &repository,
+            IssueNumber::new(1),
+            "coverage",
+            "",
+        );
+
+        assert_eq!("<!-- automatons:coverage -->", task.marker());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<UpsertIssueComment>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<UpsertIssueComment>();
+    }
+}
@@ -0,0 +1,17 @@
+use mockito::{mock, Mock};
+
+pub fn mock_get_organization() -> Mock {
+    mock("GET", "/orgs/devxbots")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/organization.json"
+        ))
+        .create()
+}
+
+pub fn mock_get_user() -> Mock {
+    mock("GET", "/users/octocat")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/user.json"))
+        .create()
+}
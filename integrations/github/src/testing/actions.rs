@@ -0,0 +1,73 @@
+use mockito::{mock, Mock};
+
+pub fn mock_get_repository_public_key() -> Mock {
+    mock(
+        "GET",
+        "/repos/github/hello-world/actions/secrets/public-key",
+    )
+    .with_status(200)
+    .with_body(include_str!(
+        "../../tests/fixtures/resource/actions_public_key.json"
+    ))
+    .create()
+}
+
+pub fn mock_list_environments() -> Mock {
+    mock("GET", "/repos/github/hello-world/environments")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "environments": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/environment.json")
+        ))
+        .create()
+}
+
+pub fn mock_list_repository_variables() -> Mock {
+    mock("GET", "/repos/github/hello-world/actions/variables")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "variables": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/actions_variable.json")
+        ))
+        .create()
+}
+
+pub fn mock_cancel_workflow_run() -> Mock {
+    mock(
+        "POST",
+        "/repos/github/hello-world/actions/runs/30433642/cancel",
+    )
+    .with_status(202)
+    .with_body("{}")
+    .create()
+}
+
+pub fn mock_list_workflow_runs() -> Mock {
+    mock("GET", "/repos/github/hello-world/actions/runs")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "workflow_runs": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/workflow_run.json")
+        ))
+        .create()
+}
+
+pub fn mock_rerun_failed_jobs() -> Mock {
+    mock(
+        "POST",
+        "/repos/github/hello-world/actions/runs/30433642/rerun-failed-jobs",
+    )
+    .with_status(201)
+    .with_body("{}")
+    .create()
+}
+
+pub fn mock_rerun_workflow_run() -> Mock {
+    mock(
+        "POST",
+        "/repos/github/hello-world/actions/runs/30433642/rerun",
+    )
+    .with_status(201)
+    .with_body("{}")
+    .create()
+}
@@ -0,0 +1,8 @@
+use mockito::{mock, Mock};
+
+pub fn mock_get_app() -> Mock {
+    mock("GET", "/app")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/app.json"))
+        .create()
+}
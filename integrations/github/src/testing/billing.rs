@@ -0,0 +1,28 @@
+use mockito::{mock, Mock};
+
+pub fn mock_get_organization_actions_billing() -> Mock {
+    mock("GET", "/orgs/github/settings/billing/actions")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/actions_billing.json"
+        ))
+        .create()
+}
+
+pub fn mock_get_rate_limit() -> Mock {
+    mock("GET", "/rate_limit")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/rate_limit.json"
+        ))
+        .create()
+}
+
+pub fn mock_get_user_actions_billing() -> Mock {
+    mock("GET", "/users/octocat/settings/billing/actions")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/actions_billing.json"
+        ))
+        .create()
+}
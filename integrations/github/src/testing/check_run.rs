@@ -298,3 +298,17 @@ pub fn mock_update_check_run() -> Mock {
         )
         .create()
 }
+
+pub fn mock_get_check_run() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/check-runs/7669942377")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/check_run.json"))
+        .create()
+}
+
+pub fn mock_update_completed_check_run() -> Mock {
+    mock("PATCH", "/repos/devxbots/automatons/check-runs/7669942377")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/check_run.json"))
+        .create()
+}
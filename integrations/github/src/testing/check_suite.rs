@@ -189,3 +189,32 @@ pub fn mock_list_check_suites() -> Mock {
             }
         "#).create()
 }
+
+pub fn mock_create_check_suite() -> Mock {
+    mock("POST", "/repos/devxbots/automatons/check-suites")
+        .match_body(r#"{"head_sha":"7fb3254b029acb55db7f8134d1526a080cd63c48"}"#)
+        .with_status(201)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/check_suite.json"
+        ))
+        .create()
+}
+
+pub fn mock_update_check_suite_preferences() -> Mock {
+    mock(
+        "PATCH",
+        "/repos/devxbots/automatons/check-suites/preferences",
+    )
+    .match_body(r#"{"auto_trigger_checks":[{"app_id":202198,"setting":false}]}"#)
+    .with_status(200)
+    .with_body(format!(
+        r#"{{
+              "preferences": {{
+                "auto_trigger_checks": [{{ "app_id": 202198, "setting": false }}]
+              }},
+              "repository": {}
+            }}"#,
+        include_str!("../../tests/fixtures/resource/repository.json")
+    ))
+    .create()
+}
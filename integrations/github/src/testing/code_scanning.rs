@@ -0,0 +1,13 @@
+use mockito::{mock, Mock};
+
+pub fn mock_upload_sarif() -> Mock {
+    mock("POST", "/repos/github/hello-world/code-scanning/sarifs")
+        .with_status(202)
+        .with_body(r#"
+            {
+              "id": "47177e22-5596-11eb-80a1-c1e54ef945c6",
+              "url": "https://api.github.com/repos/github/hello-world/code-scanning/sarifs/47177e22-5596-11eb-80a1-c1e54ef945c6"
+            }
+        "#)
+        .create()
+}
@@ -1,29 +1,49 @@
-use mockito::{mock, Mock};
+use mockito::{mock, Matcher, Mock};
+
+const FILE: &str = r#"
+    {
+      "type": "file",
+      "encoding": "base64",
+      "size": 5362,
+      "name": "README.md",
+      "path": "README.md",
+      "content": "ZW5jb2RlZCBjb250ZW50IC4uLg==",
+      "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+      "url": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
+      "git_url": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+      "html_url": "https://github.com/octokit/octokit.rb/blob/master/README.md",
+      "download_url": "https://raw.githubusercontent.com/octokit/octokit.rb/master/README.md",
+      "_links": {
+        "git": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+        "self": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
+        "html": "https://github.com/octokit/octokit.rb/blob/master/README.md"
+      }
+    }
+"#;
 
 pub fn mock_get_contents_file() -> Mock {
     mock("GET", "/repos/octokit/octokit.rb/contents/README.md")
         .with_status(200)
-        .with_body(r#"
-            {
-              "type": "file",
-              "encoding": "base64",
-              "size": 5362,
-              "name": "README.md",
-              "path": "README.md",
-              "content": "ZW5jb2RlZCBjb250ZW50IC4uLg==",
-              "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
-              "url": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
-              "git_url": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
-              "html_url": "https://github.com/octokit/octokit.rb/blob/master/README.md",
-              "download_url": "https://raw.githubusercontent.com/octokit/octokit.rb/master/README.md",
-              "_links": {
-                "git": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
-                "self": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
-                "html": "https://github.com/octokit/octokit.rb/blob/master/README.md"
-              }
-            }
-        "#
+        .with_header("etag", r#""3d21ec53a331a6f037a91c368710b99387d012c1""#)
+        .with_body(FILE)
+        .create()
+}
+
+pub fn mock_get_contents_file_at(git_ref: &str) -> Mock {
+    mock("GET", "/repos/octokit/octokit.rb/contents/README.md")
+        .match_query(Matcher::UrlEncoded("ref".into(), git_ref.into()))
+        .with_status(200)
+        .with_body(FILE)
+        .create()
+}
+
+pub fn mock_get_contents_file_not_modified() -> Mock {
+    mock("GET", "/repos/octokit/octokit.rb/contents/README.md")
+        .match_header(
+            "if-none-match",
+            r#""3d21ec53a331a6f037a91c368710b99387d012c1""#,
         )
+        .with_status(304)
         .create()
 }
 
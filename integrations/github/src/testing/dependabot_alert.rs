@@ -0,0 +1,23 @@
+use mockito::{mock, Mock};
+
+pub fn mock_list_dependabot_alerts() -> Mock {
+    mock(
+        "GET",
+        "/repos/github/hello-world/dependabot/alerts?state=dismissed",
+    )
+    .with_status(200)
+    .with_body(format!(
+        "[{}]",
+        include_str!("../../tests/fixtures/resource/dependabot_alert.json")
+    ))
+    .create()
+}
+
+pub fn mock_update_dependabot_alert() -> Mock {
+    mock("PATCH", "/repos/github/hello-world/dependabot/alerts/2")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/dependabot_alert.json"
+        ))
+        .create()
+}
@@ -0,0 +1,134 @@
+use mockito::{mock, Matcher, Mock};
+
+const REFERENCE: &str = r#"{
+  "ref": "refs/heads/main",
+  "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+  "url": "https://api.github.com/repos/octocat/Hello-World/git/refs/heads/main",
+  "object": {
+    "type": "commit",
+    "sha": "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+    "url": "https://api.github.com/repos/octocat/Hello-World/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0"
+  }
+}"#;
+
+const BASE_TREE: &str = r#"{
+  "sha": "827efc6d56897b048c772eb4087f854f46256132",
+  "url": "https://api.github.com/repos/octocat/Hello-World/git/trees/827efc6d56897b048c772eb4087f854f46256132",
+  "tree": [
+    {
+      "path": "CHANGELOG.md",
+      "mode": "100644",
+      "type": "blob",
+      "size": 132,
+      "sha": "5716ca5987cbf97d6bb54920bea6adde242d87e6",
+      "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/5716ca5987cbf97d6bb54920bea6adde242d87e6"
+    },
+    {
+      "path": "README.md",
+      "mode": "100755",
+      "type": "blob",
+      "size": 13,
+      "sha": "980a0d5f19a64b4b30a87d4206aade58726b60e3",
+      "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/980a0d5f19a64b4b30a87d4206aade58726b60e3"
+    }
+  ],
+  "truncated": false
+}"#;
+
+pub fn mock_get_reference() -> Mock {
+    mock("GET", "/repos/octocat/Hello-World/git/ref/heads/main")
+        .with_status(200)
+        .with_body(REFERENCE)
+        .create()
+}
+
+pub fn mock_get_commit() -> Mock {
+    mock(
+        "GET",
+        "/repos/octocat/Hello-World/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+    )
+    .with_status(200)
+    .with_body(include_str!(
+        "../../tests/fixtures/resource/git_commit.json"
+    ))
+    .create()
+}
+
+pub fn mock_get_base_tree() -> Mock {
+    mock(
+        "GET",
+        "/repos/octocat/Hello-World/git/trees/827efc6d56897b048c772eb4087f854f46256132",
+    )
+    .match_query(Matcher::UrlEncoded("recursive".into(), "1".into()))
+    .with_status(200)
+    .with_body(BASE_TREE)
+    .create()
+}
+
+pub fn mock_create_blob() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/git/blobs")
+        .match_body(r#"{"content":"SGVsbG8sIFdvcmxkIQ==","encoding":"base64"}"#)
+        .with_status(201)
+        .with_body(
+            r#"{
+              "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15",
+              "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"
+            }"#,
+        )
+        .create()
+}
+
+pub fn mock_create_tree() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/git/trees")
+        .match_body(Matcher::Json(serde_json::json!({
+            "base_tree": "827efc6d56897b048c772eb4087f854f46256132",
+            "tree": [
+                {
+                    "path": "README.md",
+                    "mode": "100755",
+                    "type": "blob",
+                    "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"
+                },
+                {
+                    "path": "CHANGELOG.md",
+                    "mode": "100644",
+                    "type": "blob",
+                    "sha": null
+                },
+                {
+                    "path": "NOTICE",
+                    "mode": "100644",
+                    "type": "blob",
+                    "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"
+                }
+            ]
+        })))
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/tree.json"))
+        .create()
+}
+
+pub fn mock_create_changeset_commit() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/git/commits")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "message": "Fix README",
+            "tree": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312",
+            "parents": ["7d1b31e74ee336d15cbd21741bc88a537ed063a0"]
+        })))
+        .with_status(201)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/git_commit.json"
+        ))
+        .create()
+}
+
+pub fn mock_create_reference() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/git/refs")
+        .match_body(Matcher::Json(serde_json::json!({
+            "ref": "refs/heads/fix-readme",
+            "sha": "7638417db6d59f3c431d3e1f261cc637155684cd"
+        })))
+        .with_status(201)
+        .with_body(REFERENCE)
+        .create()
+}
@@ -0,0 +1,47 @@
+use mockito::{mock, Mock};
+
+pub fn mock_create_repository_webhook() -> Mock {
+    mock("POST", "/repos/github/hello-world/hooks")
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/hook.json"))
+        .create()
+}
+
+pub fn mock_list_repository_webhooks() -> Mock {
+    mock("GET", "/repos/github/hello-world/hooks")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/hook.json")
+        ))
+        .create()
+}
+
+pub fn mock_ping_webhook() -> Mock {
+    mock("POST", "/repos/github/hello-world/hooks/12345678/pings")
+        .with_status(204)
+        .create()
+}
+
+pub fn mock_update_repository_webhook() -> Mock {
+    mock("PATCH", "/repos/github/hello-world/hooks/12345678")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/hook.json"))
+        .create()
+}
+
+pub fn mock_list_webhook_deliveries() -> Mock {
+    mock("GET", "/app/hook/deliveries")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/hook_delivery.json")
+        ))
+        .create()
+}
+
+pub fn mock_redeliver_webhook_delivery() -> Mock {
+    mock("POST", "/app/hook/deliveries/12345678/attempts")
+        .with_status(202)
+        .create()
+}
@@ -0,0 +1,11 @@
+use mockito::{mock, Mock};
+
+pub fn mock_list_installation_repositories() -> Mock {
+    mock("GET", "/installation/repositories")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{ "total_count": 1, "repositories": [{}] }}"#,
+            include_str!("../../tests/fixtures/resource/repository.json")
+        ))
+        .create()
+}
@@ -0,0 +1,88 @@
+use mockito::{mock, Mock};
+
+pub fn mock_list_issue_events() -> Mock {
+    mock("GET", "/repos/github/hello-world/issues/1347/events")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/issue_event.json")
+        ))
+        .create()
+}
+
+pub fn mock_list_issue_timeline_events() -> Mock {
+    mock("GET", "/repos/github/hello-world/issues/1347/timeline")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/issue_timeline.json"
+        ))
+        .create()
+}
+
+pub fn mock_list_issue_comments() -> Mock {
+    mock("GET", "/repos/github/hello-world/issues/1347/comments")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/issue_comment.json")
+        ))
+        .create()
+}
+
+pub fn mock_create_issue_comment() -> Mock {
+    mock("POST", "/repos/github/hello-world/issues/1347/comments")
+        .with_status(201)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .create()
+}
+
+pub fn mock_update_issue_comment() -> Mock {
+    mock("PATCH", "/repos/github/hello-world/issues/comments/1")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/issue_comment.json"
+        ))
+        .create()
+}
+
+pub fn mock_create_issue() -> Mock {
+    mock("POST", "/repos/github/hello-world/issues")
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/issue.json"))
+        .create()
+}
+
+pub fn mock_update_issue() -> Mock {
+    mock("PATCH", "/repos/github/hello-world/issues/1347")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/issue.json"))
+        .create()
+}
+
+pub fn mock_lock_issue() -> Mock {
+    mock("PUT", "/repos/github/hello-world/issues/1347/lock")
+        .match_body(r#"{"lock_reason":"too heated"}"#)
+        .with_status(204)
+        .create()
+}
+
+pub fn mock_assign_issue() -> Mock {
+    mock("POST", "/repos/github/hello-world/issues/1347/assignees")
+        .match_body(r#"{"assignees":["octocat"]}"#)
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/issue.json"))
+        .create()
+}
+
+pub fn mock_add_labels() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/issues/27/labels")
+        .match_body(r#"{"labels":["bug"]}"#)
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/label.json")
+        ))
+        .create()
+}
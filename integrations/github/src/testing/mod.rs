@@ -1,5 +1,19 @@
+pub mod account;
+pub mod actions;
+pub mod app;
+pub mod billing;
 pub mod check_run;
 pub mod check_suite;
 pub mod client;
+pub mod code_scanning;
 pub mod contents;
+pub mod dependabot_alert;
+pub mod git;
+pub mod hook;
+pub mod installation;
+pub mod issue;
+pub mod pull_request;
+pub mod ruleset;
+pub mod statistics;
 pub mod token;
+pub mod traffic;
@@ -0,0 +1,52 @@
+use mockito::{mock, Matcher, Mock};
+
+pub fn mock_request_team_review() -> Mock {
+    mock(
+        "POST",
+        "/repos/devxbots/automatons/pulls/27/requested_reviewers",
+    )
+    .match_body(r#"{"team_reviewers":["maintainers"]}"#)
+    .with_status(201)
+    .with_body(include_str!(
+        "../../tests/fixtures/resource/pull_request.json"
+    ))
+    .create()
+}
+
+pub fn mock_create_pull_request() -> Mock {
+    mock("POST", "/repos/octocat/Hello-World/pulls")
+        .match_body(Matcher::PartialJsonString(
+            r#"{"head":"fix-readme","base":"main"}"#.into(),
+        ))
+        .with_status(201)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/pull_request.json"
+        ))
+        .create()
+}
+
+pub fn mock_request_review() -> Mock {
+    mock(
+        "POST",
+        "/repos/octocat/Hello-World/pulls/27/requested_reviewers",
+    )
+    .match_body(r#"{"reviewers":["hubot"]}"#)
+    .with_status(201)
+    .with_body(include_str!(
+        "../../tests/fixtures/resource/pull_request.json"
+    ))
+    .create()
+}
+
+pub fn mock_request_maintainers_review() -> Mock {
+    mock(
+        "POST",
+        "/repos/octocat/Hello-World/pulls/27/requested_reviewers",
+    )
+    .match_body(r#"{"team_reviewers":["maintainers"]}"#)
+    .with_status(201)
+    .with_body(include_str!(
+        "../../tests/fixtures/resource/pull_request.json"
+    ))
+    .create()
+}
@@ -0,0 +1,42 @@
+use mockito::{mock, Mock};
+
+pub fn mock_create_organization_ruleset() -> Mock {
+    mock("POST", "/orgs/devxbots/rulesets")
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/ruleset.json"))
+        .create()
+}
+
+pub fn mock_create_repository_ruleset() -> Mock {
+    mock("POST", "/repos/devxbots/automatons/rulesets")
+        .with_status(201)
+        .with_body(include_str!("../../tests/fixtures/resource/ruleset.json"))
+        .create()
+}
+
+pub fn mock_get_repository_ruleset() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/rulesets/42")
+        .with_status(200)
+        .with_body(include_str!("../../tests/fixtures/resource/ruleset.json"))
+        .create()
+}
+
+pub fn mock_list_organization_rulesets() -> Mock {
+    mock("GET", "/orgs/devxbots/rulesets")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/ruleset.json")
+        ))
+        .create()
+}
+
+pub fn mock_list_repository_rulesets() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/rulesets")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/ruleset.json")
+        ))
+        .create()
+}
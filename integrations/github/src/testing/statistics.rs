@@ -0,0 +1,40 @@
+use mockito::{mock, Mock};
+
+pub fn mock_list_contributors() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/contributors")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/contributor.json")
+        ))
+        .create()
+}
+
+pub fn mock_get_commit_activity() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/stats/commit_activity")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/commit_activity.json")
+        ))
+        .create()
+}
+
+pub fn mock_get_code_frequency() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/stats/code_frequency")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/code_frequency.json")
+        ))
+        .create()
+}
+
+pub fn mock_get_participation() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/stats/participation")
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/participation.json"
+        ))
+        .create()
+}
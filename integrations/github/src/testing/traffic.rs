@@ -0,0 +1,31 @@
+use mockito::{mock, Matcher, Mock};
+
+pub fn mock_get_repository_clones() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/traffic/clones")
+        .match_query(Matcher::UrlEncoded("per".into(), "day".into()))
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/clone_traffic.json"
+        ))
+        .create()
+}
+
+pub fn mock_get_repository_views() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/traffic/views")
+        .match_query(Matcher::UrlEncoded("per".into(), "week".into()))
+        .with_status(200)
+        .with_body(include_str!(
+            "../../tests/fixtures/resource/view_traffic.json"
+        ))
+        .create()
+}
+
+pub fn mock_list_popular_paths() -> Mock {
+    mock("GET", "/repos/devxbots/automatons/traffic/popular/paths")
+        .with_status(200)
+        .with_body(format!(
+            "[{}]",
+            include_str!("../../tests/fixtures/resource/popular_path.json")
+        ))
+        .create()
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use automatons::Error;
+
+use crate::resource::{AppId, AppSlug};
+use crate::webhook::{GitHubWebhookSecret, SecretResolver, WebhookTarget};
+
+/// Configuration of a GitHub App that sends webhooks
+///
+/// Every app that a deployment receives webhooks from has its own slug, id, and webhook secrets.
+/// Deliveries for the app are expected on the path returned by [`WebhookApp::path`], e.g.
+/// `/github/devxbots-checkbot`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct WebhookApp {
+    slug: AppSlug,
+    app_id: AppId,
+    secrets: Vec<GitHubWebhookSecret>,
+}
+
+/// Configuration of all GitHub Apps that send webhooks to a deployment
+///
+/// Organizations often run several GitHub Apps, e.g. for staging and production or for different
+/// products, and receive their webhooks in a single deployment. [`WebhookApps`] maps the slug in
+/// the path of a delivery to the configuration of its app, and resolves the secrets for each app
+/// so that a delivery is only accepted if it was signed with the secret of the app it claims to
+/// come from.
+///
+/// The configuration can be deserialized from a list of apps:
+///
+/// ```
+/// use automatons_github::resource::AppSlug;
+/// use automatons_github::webhook::WebhookApps;
+///
+/// let apps: WebhookApps = serde_json::from_str(r#"[
+///     { "slug": "checkbot-staging", "app_id": 1, "secrets": ["staging secret"] },
+///     { "slug": "checkbot", "app_id": 2, "secrets": ["production secret"] }
+/// ]"#).unwrap();
+///
+/// assert!(apps.get(&AppSlug::new("checkbot")).is_some());
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(from = "Vec<WebhookApp>")]
+pub struct WebhookApps {
+    apps: BTreeMap<AppSlug, WebhookApp>,
+}
+
+impl WebhookApp {
+    /// Initializes the configuration of an app with a single secret
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(slug: AppSlug, app_id: AppId, secret: GitHubWebhookSecret) -> Self {
+        Self::with_secrets(slug, app_id, vec![secret])
+    }
+
+    /// Initializes the configuration of an app with the given secrets
+    ///
+    /// The secrets are tried in order, so the current secret should come before the secrets that
+    /// are being rotated out.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn with_secrets(slug: AppSlug, app_id: AppId, secrets: Vec<GitHubWebhookSecret>) -> Self {
+        Self {
+            slug,
+            app_id,
+            secrets,
+        }
+    }
+
+    /// Returns the app's slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn slug(&self) -> &AppSlug {
+        &self.slug
+    }
+
+    /// Returns the app's id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app_id(&self) -> AppId {
+        self.app_id
+    }
+
+    /// Returns the app's webhook secrets.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn secrets(&self) -> &Vec<GitHubWebhookSecret> {
+        &self.secrets
+    }
+
+    /// Returns the path on which webhooks for the app are received.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn path(&self) -> String {
+        format!("/github/{}", self.slug)
+    }
+}
+
+impl WebhookApps {
+    /// Initializes an empty configuration
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an app to the configuration, and replaces any app with the same slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app(mut self, app: WebhookApp) -> Self {
+        self.apps.insert(app.slug.clone(), app);
+        self
+    }
+
+    /// Returns the app with the given slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn get(&self, slug: &AppSlug) -> Option<&WebhookApp> {
+        self.apps.get(slug)
+    }
+
+    /// Returns the app whose webhooks are received on the given path.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn for_path(&self, path: &str) -> Option<&WebhookApp> {
+        let slug = path.strip_prefix("/github/")?.trim_end_matches('/');
+
+        self.get(&AppSlug::new(slug))
+    }
+
+    /// Returns the app with the given id.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn for_app_id(&self, app_id: AppId) -> Option<&WebhookApp> {
+        self.apps.values().find(|app| app.app_id == app_id)
+    }
+
+    /// Returns an iterator over all apps, ordered by their slug.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn iter(&self) -> impl Iterator<Item = &WebhookApp> {
+        self.apps.values()
+    }
+}
+
+impl From<Vec<WebhookApp>> for WebhookApps {
+    fn from(apps: Vec<WebhookApp>) -> Self {
+        apps.into_iter().fold(Self::new(), WebhookApps::app)
+    }
+}
+
+#[async_trait]
+impl SecretResolver for WebhookApp {
+    async fn resolve(&self, target: &WebhookTarget) -> Result<Vec<GitHubWebhookSecret>, Error> {
+        match target.app_id() {
+            Some(app_id) if app_id != self.app_id => Ok(Vec::new()),
+            _ => Ok(self.secrets.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretResolver for WebhookApps {
+    async fn resolve(&self, target: &WebhookTarget) -> Result<Vec<GitHubWebhookSecret>, Error> {
+        let secrets = target
+            .app_id()
+            .and_then(|app_id| self.for_app_id(app_id))
+            .map(|app| app.secrets.clone())
+            .unwrap_or_default();
+
+        Ok(secrets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::{AppId, AppSlug, HookId};
+    use crate::webhook::{GitHubWebhookSecret, SecretResolver, WebhookTarget};
+
+    use super::{WebhookApp, WebhookApps};
+
+    fn apps() -> WebhookApps {
+        serde_json::from_str(
+            r#"[
+                { "slug": "checkbot-staging", "app_id": 1, "secrets": ["staging"] },
+                { "slug": "checkbot", "app_id": 2, "secrets": ["production", "old"] }
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn target(app_id: u64) -> WebhookTarget {
+        WebhookTarget::new(Some(AppId::new(app_id)), Some(HookId::new(1)))
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let apps = apps();
+
+        let app = apps.get(&AppSlug::new("checkbot")).unwrap();
+
+        assert_eq!(2, app.app_id().get());
+        assert_eq!(2, app.secrets().len());
+        assert_eq!(2, apps.iter().count());
+    }
+
+    #[test]
+    fn for_path() {
+        let apps = apps();
+
+        let app = apps.for_path("/github/checkbot-staging").unwrap();
+
+        assert_eq!("/github/checkbot-staging", app.path());
+        assert!(apps.for_path("/github/unknown").is_none());
+        assert!(apps.for_path("/webhook").is_none());
+    }
+
+    #[test]
+    fn app_replaces_app_with_same_slug() {
+        let apps = apps().app(WebhookApp::new(
+            AppSlug::new("checkbot"),
+            AppId::new(3),
+            GitHubWebhookSecret::new("new"),
+        ));
+
+        assert_eq!(
+            3,
+            apps.get(&AppSlug::new("checkbot")).unwrap().app_id().get()
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_returns_secrets_of_app() {
+        let secrets = apps().resolve(&target(1)).await.unwrap();
+
+        assert_eq!(
+            vec!["staging"],
+            secrets.iter().map(|s| s.expose()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_returns_nothing_for_unknown_app() {
+        assert!(apps().resolve(&target(4)).await.unwrap().is_empty());
+        assert!(apps()
+            .resolve(&WebhookTarget::default())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn app_rejects_other_app() {
+        let apps = apps();
+        let app = apps.for_path("/github/checkbot").unwrap();
+
+        assert_eq!(2, app.resolve(&target(2)).await.unwrap().len());
+        assert!(app.resolve(&target(1)).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WebhookApps>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<WebhookApps>();
+    }
+}
@@ -0,0 +1,154 @@
+//! Verification of GitHub webhooks
+//!
+//! GitHub signs the payload of every webhook delivery with a secret that is configured for the
+//! webhook. Receivers must verify the signature before they trust the payload. Platforms that host
+//! more than one GitHub App can look up the secret per delivery by implementing a
+//! [`SecretResolver`], e.g. the [`WebhookApps`] that map each app to its own path and secrets.
+//!
+//! Signatures do not protect against captured deliveries that are sent again. Receivers that are
+//! exposed to the public internet can reject these replays with a [`ReplayGuard`].
+//!
+//! Staging environments can be smoke-tested with a [`TestConsole`], which injects fixtures without
+//! a signature after checking a separate token.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use automatons::Error;
+
+use crate::secret;
+
+pub use self::apps::{WebhookApp, WebhookApps};
+pub use self::replay::ReplayGuard;
+pub use self::resolver::{SecretResolver, StaticSecretResolver, WebhookTarget};
+pub use self::test_console::{TestConsole, TestConsoleToken, TestInjection};
+
+mod apps;
+mod replay;
+mod resolver;
+mod test_console;
+
+secret!(
+    /// Webhook secret
+    ///
+    /// GitHub uses the webhook secret to sign the payloads of webhook deliveries. The signature is
+    /// sent in the `X-Hub-Signature-256` header.
+    GitHubWebhookSecret
+);
+
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// Verifies the signature of a webhook delivery.
+///
+/// The secrets for the delivery are looked up with the given [`SecretResolver`], and then used to
+/// verify the signature from the `X-Hub-Signature-256` header against the raw body of the request.
+/// The signature is accepted if it matches any of the secrets.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(resolver, body)))]
+pub async fn verify_signature(
+    resolver: &dyn SecretResolver,
+    target: &WebhookTarget,
+    signature: &str,
+    body: &[u8],
+) -> Result<(), Error> {
+    let secrets = resolver.resolve(target).await?;
+    if secrets.is_empty() {
+        return Err(Error::Unauthorized(format!(
+            "failed to find webhook secret for {}",
+            target
+        )));
+    }
+
+    let signature = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(|signature| hex::decode(signature).ok())
+        .ok_or_else(|| Error::Unauthorized("failed to parse webhook signature".into()))?;
+
+    for secret in &secrets {
+        if verify(secret, &signature, body)? {
+            return Ok(());
+        }
+    }
+
+    Err(Error::Unauthorized(
+        "webhook signature does not match payload".into(),
+    ))
+}
+
+fn verify(secret: &GitHubWebhookSecret, signature: &[u8], body: &[u8]) -> Result<bool, Error> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.expose().as_bytes())
+        .map_err(|_| Error::Configuration("failed to initialize webhook secret".into()))?;
+    mac.update(body);
+
+    Ok(mac.verify_slice(signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::resource::{AppId, HookId};
+
+    use super::{verify_signature, GitHubWebhookSecret, StaticSecretResolver, WebhookTarget};
+
+    const BODY: &[u8] = b"Hello, World!";
+
+    // Example from https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    fn resolver() -> StaticSecretResolver {
+        StaticSecretResolver::new(GitHubWebhookSecret::new("It's a Secret to Everybody"))
+    }
+
+    fn target() -> WebhookTarget {
+        WebhookTarget::new(Some(AppId::new(1)), Some(HookId::new(1)))
+    }
+
+    #[tokio::test]
+    async fn verify_signature_accepts_valid_signature() {
+        assert!(verify_signature(&resolver(), &target(), SIGNATURE, BODY)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_signature_rejects_invalid_signature() {
+        let error = verify_signature(&resolver(), &target(), SIGNATURE, b"Goodbye, World!")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn verify_signature_accepts_any_of_multiple_secrets() {
+        let resolver = StaticSecretResolver::with_secrets(vec![
+            GitHubWebhookSecret::new("new secret"),
+            GitHubWebhookSecret::new("It's a Secret to Everybody"),
+        ]);
+
+        assert!(verify_signature(&resolver, &target(), SIGNATURE, BODY)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_signature_rejects_without_secrets() {
+        let resolver = StaticSecretResolver::with_secrets(Vec::new());
+
+        let error = verify_signature(&resolver, &target(), SIGNATURE, BODY)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn verify_signature_rejects_malformed_signature() {
+        let error = verify_signature(&resolver(), &target(), "sha1=abc", BODY)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+use automatons::Error;
+
+use crate::resource::HookDeliveryGuid;
+
+/// Default window in which deliveries are accepted
+const DEFAULT_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Protection against replayed webhook deliveries
+///
+/// A valid signature proves that GitHub sent a payload, but not that it was sent only once. An
+/// attacker who captured a delivery can send it again, and its signature remains valid. The
+/// [`ReplayGuard`] rejects deliveries whose timestamp is outside of a window around the current
+/// time, and deliveries whose GUID it has already seen within that window.
+///
+/// GUIDs are only remembered for the length of the window, since older deliveries are rejected by
+/// their timestamp anyway. Redeliveries keep the GUID of the original delivery, so a delivery can
+/// only be redelivered once the window has passed.
+#[derive(Debug)]
+pub struct ReplayGuard {
+    window: Duration,
+    seen: Mutex<HashMap<HookDeliveryGuid, DateTime<Utc>>>,
+}
+
+impl ReplayGuard {
+    /// Initializes a guard that accepts deliveries within the window
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the window in which deliveries are accepted.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Checks that the delivery is recent and has not been seen before.
+    ///
+    /// The timestamp is the time when the delivery was sent, e.g. as recorded by the proxy or
+    /// queue in front of the application. Returns [`Error::Unauthorized`] if the delivery is
+    /// rejected, and records its GUID otherwise.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn check(&self, guid: &HookDeliveryGuid, timestamp: DateTime<Utc>) -> Result<(), Error> {
+        self.check_at(guid, timestamp, Utc::now())
+    }
+
+    fn check_at(
+        &self,
+        guid: &HookDeliveryGuid,
+        timestamp: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let window = chrono::Duration::from_std(self.window)
+            .map_err(|_| Error::Configuration("failed to convert replay window".into()))?;
+
+        if (now - timestamp).abs() > window {
+            return Err(Error::Unauthorized(format!(
+                "webhook delivery {} was sent outside of the replay window",
+                guid
+            )));
+        }
+
+        let mut seen = self.seen.lock();
+        seen.retain(|_, seen_at| now - *seen_at <= window);
+
+        if seen.contains_key(guid) {
+            return Err(Error::Unauthorized(format!(
+                "webhook delivery {} has already been received",
+                guid
+            )));
+        }
+
+        seen.insert(guid.clone(), timestamp);
+
+        Ok(())
+    }
+}
+
+impl Default for ReplayGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use automatons::Error;
+
+    use crate::resource::HookDeliveryGuid;
+
+    use super::ReplayGuard;
+
+    fn guid() -> HookDeliveryGuid {
+        HookDeliveryGuid::new("0b989ba4-242f-11e5-81e1-c7b6966d2516")
+    }
+
+    #[test]
+    fn check_accepts_recent_delivery() {
+        let guard = ReplayGuard::default();
+
+        assert!(guard.check(&guid(), Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn check_rejects_old_delivery() {
+        let guard = ReplayGuard::new(Duration::from_secs(60));
+        let timestamp = Utc::now() - chrono::Duration::minutes(2);
+
+        let error = guard.check(&guid(), timestamp).unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn check_rejects_future_delivery() {
+        let guard = ReplayGuard::new(Duration::from_secs(60));
+        let timestamp = Utc::now() + chrono::Duration::minutes(2);
+
+        let error = guard.check(&guid(), timestamp).unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn check_rejects_replayed_delivery() {
+        let guard = ReplayGuard::default();
+        let timestamp = Utc::now();
+
+        guard.check(&guid(), timestamp).unwrap();
+        let error = guard.check(&guid(), timestamp).unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+        assert!(guard
+            .check(&HookDeliveryGuid::new("other"), timestamp)
+            .is_ok());
+    }
+
+    #[test]
+    fn check_forgets_deliveries_outside_of_window() {
+        let guard = ReplayGuard::new(Duration::from_secs(60));
+        let timestamp = Utc::now();
+
+        guard.check_at(&guid(), timestamp, timestamp).unwrap();
+        guard
+            .check_at(
+                &HookDeliveryGuid::new("other"),
+                timestamp + chrono::Duration::minutes(2),
+                timestamp + chrono::Duration::minutes(2),
+            )
+            .unwrap();
+
+        assert!(guard.seen.lock().get(&guid()).is_none());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<ReplayGuard>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<ReplayGuard>();
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use automatons::Error;
+
+use crate::resource::{AppId, HookId};
+use crate::webhook::GitHubWebhookSecret;
+
+/// Target of a webhook delivery
+///
+/// GitHub identifies the webhook that sent a delivery and the app that it belongs to in the
+/// `X-GitHub-Hook-ID` and `X-GitHub-Hook-Installation-Target-ID` headers. Secret resolvers use
+/// these ids to look up the secret for the delivery.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct WebhookTarget {
+    app_id: Option<AppId>,
+    hook_id: Option<HookId>,
+}
+
+impl WebhookTarget {
+    /// Initializes a new webhook target.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(app_id: Option<AppId>, hook_id: Option<HookId>) -> Self {
+        Self { app_id, hook_id }
+    }
+
+    /// Returns the id of the app that the webhook belongs to.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn app_id(&self) -> Option<AppId> {
+        self.app_id
+    }
+
+    /// Returns the id of the webhook.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn hook_id(&self) -> Option<HookId> {
+        self.hook_id
+    }
+}
+
+impl Display for WebhookTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let app_id = self.app_id.map(|id| id.to_string());
+        let hook_id = self.hook_id.map(|id| id.to_string());
+
+        write!(
+            f,
+            "app {} and hook {}",
+            app_id.as_deref().unwrap_or("unknown"),
+            hook_id.as_deref().unwrap_or("unknown")
+        )
+    }
+}
+
+/// Resolver for webhook secrets
+///
+/// Signature verification looks up the secret for a delivery through a [`SecretResolver`]. This
+/// enables platforms to host several GitHub Apps, each with their own secret.
+///
+/// Resolvers return an ordered list of secrets, and a delivery is accepted if its signature
+/// matches any of them. During a rotation, both the new and the old secret can be returned so that
+/// no deliveries are dropped. An empty list means that no secret is known for the given target.
+#[async_trait]
+pub trait SecretResolver: Send + Sync {
+    /// Returns the secrets for the given webhook target.
+    async fn resolve(&self, target: &WebhookTarget) -> Result<Vec<GitHubWebhookSecret>, Error>;
+}
+
+/// Resolver with a single secret
+///
+/// The [`StaticSecretResolver`] returns the same secrets for every webhook target. It is meant for
+/// deployments that receive webhooks from a single GitHub App.
+#[derive(Clone, Debug)]
+pub struct StaticSecretResolver {
+    secrets: Vec<GitHubWebhookSecret>,
+}
+
+impl StaticSecretResolver {
+    /// Initializes a resolver that always returns the given secret.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new(secret: GitHubWebhookSecret) -> Self {
+        Self {
+            secrets: vec![secret],
+        }
+    }
+
+    /// Initializes a resolver that always returns the given secrets.
+    ///
+    /// The secrets are tried in order, so the current secret should come before the secrets that
+    /// are being rotated out.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn with_secrets(secrets: Vec<GitHubWebhookSecret>) -> Self {
+        Self { secrets }
+    }
+}
+
+#[async_trait]
+impl SecretResolver for StaticSecretResolver {
+    async fn resolve(&self, _target: &WebhookTarget) -> Result<Vec<GitHubWebhookSecret>, Error> {
+        Ok(self.secrets.clone())
+    }
+}
+
+impl From<GitHubWebhookSecret> for StaticSecretResolver {
+    fn from(secret: GitHubWebhookSecret) -> Self {
+        Self::new(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::AppId;
+
+    use super::{StaticSecretResolver, WebhookTarget};
+
+    #[test]
+    fn trait_display() {
+        let target = WebhookTarget::new(Some(AppId::new(1)), None);
+
+        assert_eq!("app 1 and hook unknown", target.to_string());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StaticSecretResolver>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<StaticSecretResolver>();
+    }
+}
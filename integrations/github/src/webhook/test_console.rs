@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+
+use automatons::Error;
+
+use crate::event::{deserialize_event, GitHubEvent, GitHubEventKind};
+use crate::secret;
+
+secret!(
+    /// Token for the webhook test console
+    ///
+    /// Requests to the [`TestConsole`] skip the verification of webhook signatures, so they must
+    /// authenticate with this token instead.
+    TestConsoleToken
+);
+
+/// Console for injecting test events
+///
+/// Staging environments are hard to smoke-test, since events only arrive when something happens on
+/// GitHub. The [`TestConsole`] lets receivers expose an endpoint, e.g. `/github/test`, that accepts
+/// either the name of a registered fixture or a raw payload, and turns it into an event through the
+/// same deserialization as real webhook deliveries. The event can then be published like any other.
+///
+/// The console skips the verification of webhook signatures, and is therefore disabled until it is
+/// given a [`TestConsoleToken`]. Every injection must present this token.
+///
+/// Fixtures are named after the event kind and action, e.g. `pull_request.opened`. The part before
+/// the first dot determines the kind of the event.
+///
+/// ```
+/// use automatons_github::webhook::{TestConsole, TestConsoleToken, TestInjection};
+///
+/// let console = TestConsole::new()
+///     .token(TestConsoleToken::new("staging-token"))
+///     .fixture("ping", br#"{ "zen": "Keep it logically awesome." }"#.to_vec());
+///
+/// let injection = TestInjection::Fixture("ping".into());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TestConsole {
+    token: Option<TestConsoleToken>,
+    fixtures: BTreeMap<String, Vec<u8>>,
+}
+
+/// Event that is injected through the test console
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum TestInjection {
+    /// Injects the fixture with the given name.
+    Fixture(String),
+
+    /// Injects the raw payload as an event of the given kind.
+    Payload {
+        /// The kind of the event, as GitHub sends it in the `X-GitHub-Event` header.
+        kind: GitHubEventKind,
+
+        /// The payload of the event.
+        payload: Vec<u8>,
+    },
+}
+
+impl TestConsole {
+    /// Initializes a disabled test console without fixtures
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the console for requests that present the token.
+    pub fn token(mut self, token: TestConsoleToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Registers the payload of a fixture under its name, e.g. `pull_request.opened`.
+    pub fn fixture(mut self, name: &str, payload: Vec<u8>) -> Self {
+        self.fixtures.insert(name.into(), payload);
+        self
+    }
+
+    /// Indicates whether the console has been enabled with a token.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some()
+    }
+
+    /// Returns the names of the registered fixtures.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn fixtures(&self) -> impl Iterator<Item = &String> {
+        self.fixtures.keys()
+    }
+
+    /// Deserializes the injected event after checking the token.
+    ///
+    /// Returns [`Error::Unauthorized`] if the console is disabled or the token does not match, and
+    /// [`Error::NotFound`] if there is no fixture with the given name.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, token, injection)))]
+    pub fn inject(
+        &self,
+        token: &TestConsoleToken,
+        injection: &TestInjection,
+    ) -> Result<GitHubEvent, Error> {
+        let expected = self
+            .token
+            .as_ref()
+            .ok_or_else(|| Error::Unauthorized("test console is disabled".into()))?;
+
+        // Comparing digests avoids leaking the token through the time that the comparison takes.
+        if Sha256::digest(expected.expose()) != Sha256::digest(token.expose()) {
+            return Err(Error::Unauthorized(
+                "test console token does not match".into(),
+            ));
+        }
+
+        match injection {
+            TestInjection::Fixture(name) => {
+                let payload = self
+                    .fixtures
+                    .get(name)
+                    .ok_or_else(|| Error::NotFound(format!("test fixture {}", name)))?;
+                let kind = name.split('.').next().unwrap_or_default();
+
+                deserialize_event(&GitHubEventKind::from(kind), payload)
+            }
+            TestInjection::Payload { kind, payload } => deserialize_event(kind, payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use automatons::Error;
+
+    use crate::event::{GitHubEvent, GitHubEventKind};
+
+    use super::{TestConsole, TestConsoleToken, TestInjection};
+
+    fn console() -> TestConsole {
+        TestConsole::new()
+            .token(TestConsoleToken::new("staging-token"))
+            .fixture(
+                "check_run.completed",
+                include_bytes!("../../tests/fixtures/event/check_run.completed.json").to_vec(),
+            )
+    }
+
+    #[test]
+    fn inject_fixture() {
+        let event = console()
+            .inject(
+                &TestConsoleToken::new("staging-token"),
+                &TestInjection::Fixture("check_run.completed".into()),
+            )
+            .unwrap();
+
+        assert!(matches!(event, GitHubEvent::CheckRun(_)));
+    }
+
+    #[test]
+    fn inject_payload() {
+        let event = console()
+            .inject(
+                &TestConsoleToken::new("staging-token"),
+                &TestInjection::Payload {
+                    kind: GitHubEventKind::Star,
+                    payload: include_bytes!("../../tests/fixtures/event/star.created.json")
+                        .to_vec(),
+                },
+            )
+            .unwrap();
+
+        assert!(matches!(event, GitHubEvent::Star(_)));
+    }
+
+    #[test]
+    fn inject_rejects_wrong_token() {
+        let error = console()
+            .inject(
+                &TestConsoleToken::new("wrong-token"),
+                &TestInjection::Fixture("check_run.completed".into()),
+            )
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn inject_is_disabled_by_default() {
+        let console = TestConsole::new();
+
+        let error = console
+            .inject(
+                &TestConsoleToken::new("staging-token"),
+                &TestInjection::Fixture("check_run.completed".into()),
+            )
+            .unwrap_err();
+
+        assert!(!console.is_enabled());
+        assert!(matches!(error, Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn inject_unknown_fixture() {
+        let error = console()
+            .inject(
+                &TestConsoleToken::new("staging-token"),
+                &TestInjection::Fixture("pull_request.opened".into()),
+            )
+            .unwrap_err();
+
+        assert!(matches!(error, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<TestConsole>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<TestConsole>();
+    }
+}